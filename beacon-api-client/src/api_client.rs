@@ -1,35 +1,51 @@
 use crate::{
+    to_canonical_json,
     types::{
         ApiResult, AttestationDuty, BalanceSummary, BeaconHeaderSummary,
         BeaconProposerRegistration, BlockId, BroadcastValidation, CommitteeDescriptor,
         CommitteeFilter, CommitteeSummary, ConnectionOrientation, CoordinateWithMetadata,
-        DepositContract, DepositSnapshot, FinalityCheckpoints, GenesisDetails, HealthStatus,
-        NetworkIdentity, PeerDescription, PeerState, PeerSummary, ProposerDuty, PublicKeyOrIndex,
-        RootData, StateId, SubmitSignedBeaconBlock, SyncCommitteeDescriptor, SyncCommitteeDuty,
-        SyncCommitteeSummary, SyncStatus, Topic, ValidatorLiveness, ValidatorStatus,
-        ValidatorSummary, Value, VersionData, VersionedValue,
+        DepositContract, DepositSnapshot, EventTopic, FinalityCheckpoints, ForkSchedule,
+        GenesisDetails, HealthStatus, NetworkIdentity, PeerDescription, PeerState, PeerSummary,
+        ProposerDuty, PublicKeyOrIndex, RandaoData, RootData, StateId, SubmitSignedBeaconBlock,
+        SyncCommitteeDescriptor, SyncCommitteeDuty, SyncCommitteeSummary, SyncStatus, Topic,
+        ValidatorIdentity, ValidatorLiveness, ValidatorStatus, ValidatorSummary, Value,
+        VersionData, VersionedValue,
     },
-    ApiError, Error,
+    ApiError, BeaconEventStream, DataStream, Error,
 };
 use ethereum_consensus::{
     altair::SyncCommitteeMessage,
     builder::SignedValidatorRegistration,
     capella::{SignedBlsToExecutionChange, Withdrawal},
+    electra::{PendingConsolidation, PendingDeposit, PendingPartialWithdrawal},
     networking::PeerId,
     phase0::{AttestationData, Fork, ProposerSlashing, SignedVoluntaryExit},
     primitives::{
-        BlobIndex, Bytes32, CommitteeIndex, Epoch, RandaoReveal, Root, Slot, ValidatorIndex,
+        BlobIndex, Bytes32, CommitteeIndex, Epoch, Graffiti, RandaoReveal, Root, Slot,
+        ValidatorIndex,
     },
     Fork as Version,
 };
 use http::StatusCode;
 use itertools::Itertools;
 use mev_share_sse::{client::EventStream, EventClient};
-use std::collections::HashMap;
+use std::{collections::HashMap, time::Duration};
 use url::Url;
 
 pub const CONSENSUS_VERSION_HEADER: &str = "eth-consensus-version";
 
+// Number of validators requested at a time by `get_validators_in_range`, as the Beacon API caps
+// the `id` list of a request at 64 entries.
+const VALIDATOR_RANGE_PAGE_SIZE: u64 = 64;
+
+// Parse the fork given in the `Eth-Consensus-Version` header of `response`, if present.
+pub fn consensus_version(response: &reqwest::Response) -> Result<Option<Version>, Error> {
+    let Some(value) = response.headers().get(CONSENSUS_VERSION_HEADER) else { return Ok(None) };
+    let value = value.to_str().map_err(|_| Error::UnknownConsensusVersion(format!("{value:?}")))?;
+    let version = value.parse().map_err(|_| Error::UnknownConsensusVersion(value.to_string()))?;
+    Ok(Some(version))
+}
+
 pub async fn api_error_or_ok(response: reqwest::Response) -> Result<(), Error> {
     match response.status() {
         reqwest::StatusCode::OK | reqwest::StatusCode::ACCEPTED => Ok(()),
@@ -78,12 +94,127 @@ pub trait ClientTypes: Clone {
 pub struct Client<C> {
     pub http: reqwest::Client,
     pub endpoint: Url,
+    retry_policy: Option<RetryPolicy>,
     _phantom: std::marker::PhantomData<C>,
 }
 
+// Retries requests that fail with a transient error (502, 503, 504, a timeout, or a failure
+// to connect) with exponential backoff.
+// NOTE: only idempotent methods are retried by default so that e.g. a block is never published
+// twice.
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    pub max_retries: u32,
+    pub initial_backoff: Duration,
+    pub max_backoff: Duration,
+    pub retry_non_idempotent: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(2),
+            retry_non_idempotent: false,
+        }
+    }
+}
+
+impl RetryPolicy {
+    pub fn allows(&self, method: &reqwest::Method) -> bool {
+        self.retry_non_idempotent || method.is_idempotent()
+    }
+
+    pub fn is_transient_status(status: StatusCode) -> bool {
+        matches!(
+            status,
+            StatusCode::BAD_GATEWAY | StatusCode::SERVICE_UNAVAILABLE | StatusCode::GATEWAY_TIMEOUT
+        )
+    }
+
+    pub fn backoff(&self, retry: u32) -> Duration {
+        self.initial_backoff.saturating_mul(2u32.saturating_pow(retry)).min(self.max_backoff)
+    }
+}
+
+// Configures the connection pool of the underlying HTTP client so that connections
+// are reused across the many small requests made each slot.
+pub struct ClientBuilder<C> {
+    endpoint: Url,
+    pool_max_idle_per_host: Option<usize>,
+    pool_idle_timeout: Option<Duration>,
+    tcp_keepalive: Option<Duration>,
+    retry_policy: Option<RetryPolicy>,
+    _phantom: std::marker::PhantomData<C>,
+}
+
+impl<C: ClientTypes> ClientBuilder<C> {
+    pub fn new<U: Into<Url>>(endpoint: U) -> Self {
+        Self {
+            endpoint: endpoint.into(),
+            pool_max_idle_per_host: None,
+            pool_idle_timeout: None,
+            tcp_keepalive: None,
+            retry_policy: None,
+            _phantom: std::marker::PhantomData,
+        }
+    }
+
+    pub fn pool_max_idle_per_host(mut self, max: usize) -> Self {
+        self.pool_max_idle_per_host = Some(max);
+        self
+    }
+
+    pub fn pool_idle_timeout(mut self, timeout: Duration) -> Self {
+        self.pool_idle_timeout = Some(timeout);
+        self
+    }
+
+    pub fn tcp_keepalive(mut self, interval: Duration) -> Self {
+        self.tcp_keepalive = Some(interval);
+        self
+    }
+
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(policy);
+        self
+    }
+
+    pub fn build(self) -> Result<Client<C>, Error> {
+        let mut builder = reqwest::Client::builder().tcp_keepalive(self.tcp_keepalive);
+        if let Some(max) = self.pool_max_idle_per_host {
+            builder = builder.pool_max_idle_per_host(max);
+        }
+        if let Some(timeout) = self.pool_idle_timeout {
+            builder = builder.pool_idle_timeout(timeout);
+        }
+        let client = builder.build()?;
+        let mut client = Client::new_with_client(client, self.endpoint);
+        client.retry_policy = self.retry_policy;
+        Ok(client)
+    }
+}
+
 impl<C: ClientTypes> Client<C> {
+    pub fn builder<U: Into<Url>>(endpoint: U) -> ClientBuilder<C> {
+        ClientBuilder::new(endpoint)
+    }
+
+    // NOTE: `reqwest::Client` is reference-counted so clones of this type share one connection
+    // pool.
     pub fn new_with_client<U: Into<Url>>(client: reqwest::Client, endpoint: U) -> Self {
-        Self { http: client, endpoint: endpoint.into(), _phantom: std::marker::PhantomData }
+        Self {
+            http: client,
+            endpoint: endpoint.into(),
+            retry_policy: None,
+            _phantom: std::marker::PhantomData,
+        }
+    }
+
+    pub fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(policy);
+        self
     }
 
     pub fn new<U: Into<Url>>(endpoint: U) -> Self {
@@ -102,9 +233,29 @@ impl<C: ClientTypes> Client<C> {
         }
     }
 
+    // Fetch a versioned value, preferring the fork named in the `Eth-Consensus-Version` header
+    // over the `version` field in the response body.
+    async fn get_versioned<T: serde::Serialize + serde::de::DeserializeOwned>(
+        &self,
+        request: reqwest::RequestBuilder,
+    ) -> Result<VersionedValue<T>, Error> {
+        let response = self.send(request).await?;
+        let version = consensus_version(&response)?;
+        let result: ApiResult<VersionedValue<T>> = response.json().await?;
+        match result {
+            ApiResult::Ok(mut result) => {
+                if let Some(version) = version {
+                    result.version = version;
+                }
+                Ok(result)
+            }
+            ApiResult::Err(err) => Err(err.into()),
+        }
+    }
+
     pub async fn http_get(&self, path: &str) -> Result<reqwest::Response, Error> {
         let target = self.endpoint.join(path)?;
-        let response = self.http.get(target).send().await?;
+        let response = self.send(self.http.get(target)).await?;
         Ok(response)
     }
 
@@ -123,10 +274,79 @@ impl<C: ClientTypes> Client<C> {
         argument: &T,
     ) -> Result<reqwest::Response, Error> {
         let target = self.endpoint.join(path)?;
-        let response = self.http.post(target).json(argument).send().await?;
+        let response = self.send(self.http.post(target).json(argument)).await?;
+        Ok(response)
+    }
+
+    // POST `argument` as canonical JSON, so that the body sent is exactly
+    // `to_canonical_json(argument)`, e.g. to match a signature computed over those bytes.
+    pub async fn http_post_canonical<T: serde::Serialize + ?Sized>(
+        &self,
+        path: &str,
+        argument: &T,
+    ) -> Result<reqwest::Response, Error> {
+        let target = self.endpoint.join(path)?;
+        let body = to_canonical_json(argument)?;
+        let request = self
+            .http
+            .post(target)
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .body(body);
+        let response = self.send(request).await?;
         Ok(response)
     }
 
+    async fn send(&self, request: reqwest::RequestBuilder) -> Result<reqwest::Response, Error> {
+        let mut request = request.build()?;
+        let mut retry = 0;
+        loop {
+            let policy = self
+                .retry_policy
+                .as_ref()
+                .filter(|policy| retry < policy.max_retries && policy.allows(request.method()));
+            // NOTE: requests with a streaming body cannot be cloned and so are never retried
+            let next_request = policy.and_then(|_| request.try_clone());
+            let result = self.execute(request).await;
+            let (Some(policy), Some(next_request)) = (policy, next_request) else {
+                return Ok(result?)
+            };
+            let is_transient = match &result {
+                Ok(response) => RetryPolicy::is_transient_status(response.status()),
+                Err(err) => err.is_timeout() || err.is_connect(),
+            };
+            if !is_transient {
+                return Ok(result?)
+            }
+            tokio::time::sleep(policy.backoff(retry)).await;
+            retry += 1;
+            request = next_request;
+        }
+    }
+
+    async fn execute(&self, request: reqwest::Request) -> reqwest::Result<reqwest::Response> {
+        #[cfg(feature = "tracing")]
+        {
+            use tracing::Instrument;
+
+            let span = tracing::debug_span!(
+                "beacon_api_request",
+                method = %request.method(),
+                path = request.url().path(),
+                status = tracing::field::Empty,
+                latency_ms = tracing::field::Empty,
+            );
+            let start = std::time::Instant::now();
+            let response = self.http.execute(request).instrument(span.clone()).await?;
+            span.record("status", response.status().as_u16());
+            span.record("latency_ms", start.elapsed().as_millis() as u64);
+            Ok(response)
+        }
+        #[cfg(not(feature = "tracing"))]
+        {
+            self.http.execute(request).await
+        }
+    }
+
     /* beacon namespace */
     pub async fn get_genesis_details(&self) -> Result<GenesisDetails, Error> {
         let details: Value<GenesisDetails> = self.get("eth/v1/beacon/genesis").await?;
@@ -160,6 +380,67 @@ impl<C: ClientTypes> Client<C> {
         validator_ids: &[PublicKeyOrIndex],
         filters: &[ValidatorStatus],
     ) -> Result<Vec<ValidatorSummary>, Error> {
+        let request = self.validators_request(state_id, validator_ids, filters)?;
+        let response = self.send(request).await?;
+
+        let result: ApiResult<Value<Vec<ValidatorSummary>>> = response.json().await?;
+        match result {
+            ApiResult::Ok(result) => Ok(result.data),
+            ApiResult::Err(err) => Err(err.into()),
+        }
+    }
+
+    // Fetch the validators with indices in `start..start + count`, one page of indices at a time
+    // so that no single response covers the whole range. Fewer than `count` validators are
+    // returned if the registry ends before the range does.
+    pub async fn get_validators_in_range(
+        &self,
+        state_id: StateId,
+        start: ValidatorIndex,
+        count: u64,
+    ) -> Result<Vec<ValidatorSummary>, Error> {
+        let end = (start as u64).saturating_add(count);
+        let mut validators = vec![];
+        let mut page_start = start as u64;
+        while page_start < end {
+            let page_end = u64::min(page_start + VALIDATOR_RANGE_PAGE_SIZE, end);
+            let indices = (page_start..page_end)
+                .map(|index| PublicKeyOrIndex::Index(index as ValidatorIndex))
+                .collect::<Vec<_>>();
+            let page = self.get_validators(state_id.clone(), &indices, &[]).await?;
+            let is_last_page = (page.len() as u64) < page_end - page_start;
+            validators.extend(page);
+            if is_last_page {
+                break
+            }
+            page_start = page_end;
+        }
+        Ok(validators)
+    }
+
+    // Like `get_validators` but yields each `ValidatorSummary` as it is parsed from the response,
+    // so the full validator set is never held in memory at once.
+    pub async fn get_validators_stream(
+        &self,
+        state_id: StateId,
+        validator_ids: &[PublicKeyOrIndex],
+        filters: &[ValidatorStatus],
+    ) -> Result<DataStream<ValidatorSummary>, Error> {
+        let request = self.validators_request(state_id, validator_ids, filters)?;
+        let response = self.send(request).await?;
+        if response.status() != reqwest::StatusCode::OK {
+            let api_err = response.json::<ApiError>().await?;
+            return Err(Error::Api(api_err))
+        }
+        Ok(DataStream::new(response))
+    }
+
+    fn validators_request(
+        &self,
+        state_id: StateId,
+        validator_ids: &[PublicKeyOrIndex],
+        filters: &[ValidatorStatus],
+    ) -> Result<reqwest::RequestBuilder, Error> {
         let path = format!("eth/v1/beacon/states/{state_id}/validators");
         let target = self.endpoint.join(&path)?;
         let mut request = self.http.get(target);
@@ -171,13 +452,7 @@ impl<C: ClientTypes> Client<C> {
             let filters = filters.iter().join(",");
             request = request.query(&[("status", filters)]);
         }
-        let response = request.send().await?;
-
-        let result: ApiResult<Value<Vec<ValidatorSummary>>> = response.json().await?;
-        match result {
-            ApiResult::Ok(result) => Ok(result.data),
-            ApiResult::Err(err) => Err(err.into()),
-        }
+        Ok(request)
     }
 
     pub async fn get_validator(
@@ -203,7 +478,7 @@ impl<C: ClientTypes> Client<C> {
             let filters = filters.iter().join(",");
             request = request.query(&[("id", filters)]);
         }
-        let response = request.send().await?;
+        let response = self.send(request).await?;
 
         let result: ApiResult<Value<Vec<BalanceSummary>>> = response.json().await?;
         match result {
@@ -212,6 +487,18 @@ impl<C: ClientTypes> Client<C> {
         }
     }
 
+    pub async fn get_validator_identities(
+        &self,
+        state_id: StateId,
+        indices: &[ValidatorIndex],
+    ) -> Result<Vec<ValidatorIdentity>, Error> {
+        let path = format!("eth/v1/beacon/states/{state_id}/validator_identities");
+        let indices = indices.iter().map(|index| index.to_string()).collect::<Vec<_>>();
+        let response = self.http_post(&path, &indices).await?;
+        let result: Value<Vec<ValidatorIdentity>> = api_error_or_value(response).await?;
+        Ok(result.data)
+    }
+
     pub async fn get_all_committees(&self, id: StateId) -> Result<Vec<CommitteeSummary>, Error> {
         self.get_committees(id, CommitteeFilter::default()).await
     }
@@ -223,17 +510,8 @@ impl<C: ClientTypes> Client<C> {
     ) -> Result<Vec<CommitteeSummary>, Error> {
         let path = format!("eth/v1/beacon/states/{id}/committees");
         let target = self.endpoint.join(&path)?;
-        let mut request = self.http.get(target);
-        if let Some(epoch) = filter.epoch {
-            request = request.query(&[("epoch", epoch)]);
-        }
-        if let Some(index) = filter.index {
-            request = request.query(&[("index", index)]);
-        }
-        if let Some(slot) = filter.slot {
-            request = request.query(&[("slot", slot)]);
-        }
-        let response = request.send().await?;
+        let request = self.http.get(target).query(&filter.to_query());
+        let response = self.send(request).await?;
         let result: ApiResult<Value<Vec<CommitteeSummary>>> = response.json().await?;
         match result {
             ApiResult::Ok(result) => Ok(result.data),
@@ -252,7 +530,7 @@ impl<C: ClientTypes> Client<C> {
         if let Some(epoch) = epoch {
             request = request.query(&[("epoch", epoch)]);
         }
-        let response = request.send().await?;
+        let response = self.send(request).await?;
         let result: ApiResult<Value<SyncCommitteeSummary>> = response.json().await?;
         match result {
             ApiResult::Ok(result) => Ok(result.data),
@@ -260,22 +538,54 @@ impl<C: ClientTypes> Client<C> {
         }
     }
 
-    pub async fn get_randao(&self, id: StateId, epoch: Option<Epoch>) -> Result<Bytes32, Error> {
+    pub async fn get_state_randao(
+        &self,
+        id: StateId,
+        epoch: Option<Epoch>,
+    ) -> Result<Bytes32, Error> {
         let path = format!("eth/v1/beacon/states/{id}/randao");
         let target = self.endpoint.join(&path)?;
         let mut request = self.http.get(target);
         if let Some(epoch) = epoch {
             request = request.query(&[("epoch", epoch)]);
         }
-        let response = request.send().await?;
+        let response = self.send(request).await?;
 
-        let result: ApiResult<Value<Bytes32>> = response.json().await?;
+        let result: ApiResult<Value<RandaoData>> = response.json().await?;
         match result {
-            ApiResult::Ok(result) => Ok(result.data),
+            ApiResult::Ok(result) => Ok(result.data.randao),
             ApiResult::Err(err) => Err(err.into()),
         }
     }
 
+    pub async fn get_randao(&self, id: StateId, epoch: Option<Epoch>) -> Result<Bytes32, Error> {
+        self.get_state_randao(id, epoch).await
+    }
+
+    pub async fn get_pending_deposits(&self, id: StateId) -> Result<Vec<PendingDeposit>, Error> {
+        let path = format!("eth/v1/beacon/states/{id}/pending_deposits");
+        let result: Value<Vec<PendingDeposit>> = self.get(&path).await?;
+        Ok(result.data)
+    }
+
+    pub async fn get_pending_partial_withdrawals(
+        &self,
+        id: StateId,
+    ) -> Result<Vec<PendingPartialWithdrawal>, Error> {
+        let path = format!("eth/v1/beacon/states/{id}/pending_partial_withdrawals");
+        let result: Value<Vec<PendingPartialWithdrawal>> = self.get(&path).await?;
+        Ok(result.data)
+    }
+
+    pub async fn get_pending_consolidations(
+        &self,
+        id: StateId,
+    ) -> Result<Vec<PendingConsolidation>, Error> {
+        let path = format!("eth/v1/beacon/states/{id}/pending_consolidations");
+        let result: Value<Vec<PendingConsolidation>> = self.get(&path).await?;
+        Ok(result.data)
+    }
+
     pub async fn get_beacon_header_at_head(&self) -> Result<BeaconHeaderSummary, Error> {
         let result: Value<BeaconHeaderSummary> = self.get("eth/v1/beacon/headers").await?;
         Ok(result.data)
@@ -288,7 +598,7 @@ impl<C: ClientTypes> Client<C> {
         let target = self.endpoint.join("eth/v1/beacon/headers")?;
         let mut request = self.http.get(target);
         request = request.query(&[("slot", slot)]);
-        let response = request.send().await?;
+        let response = self.send(request).await?;
         let result: ApiResult<Value<BeaconHeaderSummary>> = response.json().await?;
         match result {
             ApiResult::Ok(result) => Ok(result.data),
@@ -303,7 +613,7 @@ impl<C: ClientTypes> Client<C> {
         let target = self.endpoint.join("eth/v1/beacon/headers")?;
         let mut request = self.http.get(target);
         request = request.query(&[("parent_root", format!("{parent_root:?}"))]);
-        let response = request.send().await?;
+        let response = self.send(request).await?;
         let result: ApiResult<Value<BeaconHeaderSummary>> = response.json().await?;
         match result {
             ApiResult::Ok(result) => Ok(result.data),
@@ -339,7 +649,7 @@ impl<C: ClientTypes> Client<C> {
         if let Some(validation) = broadcast_validation {
             request = request.query(&[("broadcast_validation", validation)]);
         }
-        let response = request.send().await?;
+        let response = self.send(request).await?;
         api_error_or_ok(response).await
     }
 
@@ -367,14 +677,15 @@ impl<C: ClientTypes> Client<C> {
         if let Some(validation) = broadcast_validation {
             request = request.query(&[("broadcast_validation", validation)]);
         }
-        let response = request.send().await?;
+        let response = self.send(request).await?;
         api_error_or_ok(response).await
     }
 
     // v2 endpoint
     pub async fn get_beacon_block(&self, id: BlockId) -> Result<C::SignedBeaconBlock, Error> {
+        let target = self.endpoint.join(&format!("eth/v2/beacon/blocks/{id}"))?;
         let result: VersionedValue<C::SignedBeaconBlock> =
-            self.get(&format!("eth/v2/beacon/blocks/{id}")).await?;
+            self.get_versioned(self.http.get(target)).await?;
         Ok(result.data)
     }
 
@@ -403,7 +714,7 @@ impl<C: ClientTypes> Client<C> {
         if !indices.is_empty() {
             request = request.query(&[("indices", indices)]);
         }
-        let response = request.send().await?;
+        let response = self.send(request).await?;
         let result: ApiResult<Value<_>> = response.json().await?;
         match result {
             ApiResult::Ok(result) => Ok(result.data),
@@ -420,8 +731,9 @@ impl<C: ClientTypes> Client<C> {
         &self,
         id: BlockId,
     ) -> Result<C::SignedBlindedBeaconBlock, Error> {
+        let target = self.endpoint.join(&format!("eth/v1/beacon/blinded_blocks/{id}"))?;
         let result: VersionedValue<C::SignedBlindedBeaconBlock> =
-            self.get(&format!("eth/v1/beacon/blinded_blocks/{id}")).await?;
+            self.get_versioned(self.http.get(target)).await?;
         Ok(result.data)
     }
 
@@ -443,7 +755,7 @@ impl<C: ClientTypes> Client<C> {
         let mut request = self.http.get(target);
         request = request.query(&[("start_period", start), ("count", count)]);
 
-        let response = request.send().await?;
+        let response = self.send(request).await?;
         let result: ApiResult<Value<_>> = response.json().await?;
         match result {
             ApiResult::Ok(result) => Ok(result.data),
@@ -479,7 +791,7 @@ impl<C: ClientTypes> Client<C> {
         if let Some(committee_index) = committee_index {
             request = request.query(&[("committee_index", committee_index)]);
         }
-        let response = request.send().await?;
+        let response = self.send(request).await?;
         let result: ApiResult<Value<Vec<C::Attestation>>> = response.json().await?;
         match result {
             ApiResult::Ok(result) => Ok(result.data),
@@ -567,7 +879,7 @@ impl<C: ClientTypes> Client<C> {
             request = request.query(&[("proposal_slot", slot)]);
         }
 
-        let response = request.send().await?;
+        let response = self.send(request).await?;
         let result: ApiResult<Value<_>> = response.json().await?;
         match result {
             ApiResult::Ok(result) => Ok(result.data),
@@ -576,8 +888,8 @@ impl<C: ClientTypes> Client<C> {
     }
 
     /* config namespace */
-    pub async fn get_fork_schedule(&self) -> Result<Vec<Fork>, Error> {
-        let result: Value<Vec<Fork>> = self.get("eth/v1/config/fork_schedule").await?;
+    pub async fn get_fork_schedule(&self) -> Result<ForkSchedule, Error> {
+        let result: Value<ForkSchedule> = self.get("eth/v1/config/fork_schedule").await?;
         Ok(result.data)
     }
 
@@ -594,8 +906,9 @@ impl<C: ClientTypes> Client<C> {
     /* debug namespace */
     // v2 endpoint
     pub async fn get_state(&self, id: StateId) -> Result<C::BeaconState, Error> {
+        let target = self.endpoint.join(&format!("eth/v2/debug/beacon/states/{id}"))?;
         let result: VersionedValue<C::BeaconState> =
-            self.get(&format!("eth/v2/debug/beacon/states/{id}")).await?;
+            self.get_versioned(self.http.get(target)).await?;
         Ok(result.data)
     }
 
@@ -616,6 +929,20 @@ impl<C: ClientTypes> Client<C> {
         Ok(client.subscribe(&target).await?)
     }
 
+    // Subscribe to `topics` on a single connection, yielding events of any of the given kinds.
+    pub async fn get_beacon_events(
+        &self,
+        topics: &[EventTopic],
+    ) -> Result<BeaconEventStream<C::Attestation, C::SignedContributionAndProof>, Error>
+    where
+        C::SignedContributionAndProof: serde::de::DeserializeOwned,
+    {
+        let topics = topics.iter().join(",");
+        let path = format!("eth/v1/events?topics={topics}");
+        let target = self.endpoint.join(&path)?;
+        BeaconEventStream::connect(self.http.clone(), target).await
+    }
+
     /* node namespace */
     pub async fn get_node_identity(&self) -> Result<NetworkIdentity, Error> {
         let result: Value<NetworkIdentity> = self.get("eth/v1/node/identity").await?;
@@ -636,7 +963,7 @@ impl<C: ClientTypes> Client<C> {
         if !connection_orientations.is_empty() {
             request = request.query(&[("direction", connection_orientations.iter().join(","))]);
         }
-        let response = request.send().await?;
+        let response = self.send(request).await?;
         let result: ApiResult<Value<Vec<PeerDescription>>> = response.json().await?;
         match result {
             ApiResult::Ok(result) => Ok(result.data),
@@ -665,11 +992,34 @@ impl<C: ClientTypes> Client<C> {
         Ok(result.data)
     }
 
+    // Poll the sync status of the node until it is within `max_distance` slots of the head,
+    // returning the last status so callers can also check `is_optimistic`.
+    pub async fn wait_until_synced(
+        &self,
+        max_distance: u64,
+        timeout: Duration,
+    ) -> Result<SyncStatus, Error> {
+        let deadline = tokio::time::Instant::now() + timeout;
+        let mut backoff = Duration::from_millis(500);
+        loop {
+            let status = self.get_sync_status().await?;
+            if status.sync_distance as u64 <= max_distance {
+                return Ok(status)
+            }
+            let now = tokio::time::Instant::now();
+            if now >= deadline {
+                return Err(Error::SyncTimeout(timeout))
+            }
+            tokio::time::sleep(backoff.min(deadline - now)).await;
+            backoff = (backoff * 2).min(Duration::from_secs(12));
+        }
+    }
+
     pub async fn get_health(&self) -> Result<HealthStatus, Error> {
         let path = "eth/v1/node/health";
         let target = self.endpoint.join(path)?;
         let request = self.http.get(target);
-        let response = request.send().await?;
+        let response = self.send(request).await?;
         let result = match response.status() {
             StatusCode::OK => HealthStatus::Ready,
             StatusCode::PARTIAL_CONTENT => HealthStatus::Syncing,
@@ -727,7 +1077,7 @@ impl<C: ClientTypes> Client<C> {
         &self,
         slot: Slot,
         randao_reveal: RandaoReveal,
-        graffiti: Option<Bytes32>,
+        graffiti: Option<Graffiti>,
     ) -> Result<C::BeaconBlock, Error> {
         let path = format!("eth/v2/validator/blocks/{slot}");
         let target = self.endpoint.join(&path)?;
@@ -736,19 +1086,15 @@ impl<C: ClientTypes> Client<C> {
         if let Some(graffiti) = graffiti {
             request = request.query(&[("graffiti", format!("{graffiti:?}"))]);
         }
-        let response = request.send().await?;
-        let result: ApiResult<VersionedValue<C::BeaconBlock>> = response.json().await?;
-        match result {
-            ApiResult::Ok(result) => Ok(result.data),
-            ApiResult::Err(err) => Err(err.into()),
-        }
+        let result: VersionedValue<C::BeaconBlock> = self.get_versioned(request).await?;
+        Ok(result.data)
     }
 
     pub async fn get_blinded_block_proposal(
         &self,
         slot: Slot,
         randao_reveal: RandaoReveal,
-        graffiti: Option<Bytes32>,
+        graffiti: Option<Graffiti>,
     ) -> Result<C::BlindedBeaconBlock, Error> {
         let path = format!("eth/v1/validator/blinded_blocks/{slot}");
         let target = self.endpoint.join(&path)?;
@@ -757,12 +1103,8 @@ impl<C: ClientTypes> Client<C> {
         if let Some(graffiti) = graffiti {
             request = request.query(&[("graffiti", format!("{graffiti:?}"))]);
         }
-        let response = request.send().await?;
-        let result: ApiResult<VersionedValue<C::BlindedBeaconBlock>> = response.json().await?;
-        match result {
-            ApiResult::Ok(result) => Ok(result.data),
-            ApiResult::Err(err) => Err(err.into()),
-        }
+        let result: VersionedValue<C::BlindedBeaconBlock> = self.get_versioned(request).await?;
+        Ok(result.data)
     }
 
     pub async fn get_attestation_data(
@@ -774,7 +1116,7 @@ impl<C: ClientTypes> Client<C> {
         let mut request = self.http.get(target);
         request = request.query(&[("slot", slot)]);
         request = request.query(&[("committee_index", committee_index)]);
-        let response = request.send().await?;
+        let response = self.send(request).await?;
         let result: ApiResult<Value<AttestationData>> = response.json().await?;
         match result {
             ApiResult::Ok(result) => Ok(result.data),
@@ -791,7 +1133,7 @@ impl<C: ClientTypes> Client<C> {
         let mut request = self.http.get(target);
         request = request.query(&[("attestation_data_root", format!("{attestation_data_root:?}"))]);
         request = request.query(&[("slot", slot)]);
-        let response = request.send().await?;
+        let response = self.send(request).await?;
         let result: ApiResult<Value<C::Attestation>> = response.json().await?;
         match result {
             ApiResult::Ok(result) => Ok(result.data),
@@ -806,6 +1148,40 @@ impl<C: ClientTypes> Client<C> {
         self.post("eth/v1/validator/aggregate_and_proofs", aggregates_with_proofs).await
     }
 
+    // v2 endpoint
+    // NOTE: from `electra`, aggregates are fetched per committee as `AttestationData` no longer
+    // commits to the committee index.
+    pub async fn get_attestation_aggregate_v2(
+        &self,
+        attestation_data_root: Root,
+        slot: Slot,
+        committee_index: CommitteeIndex,
+    ) -> Result<C::Attestation, Error> {
+        let target = self.endpoint.join("eth/v2/validator/aggregate_attestation")?;
+        let mut request = self.http.get(target);
+        request = request.query(&[("attestation_data_root", format!("{attestation_data_root:?}"))]);
+        request = request.query(&[("slot", slot)]);
+        request = request.query(&[("committee_index", committee_index)]);
+        let result: VersionedValue<C::Attestation> = self.get_versioned(request).await?;
+        Ok(result.data)
+    }
+
+    // v2 endpoint
+    pub async fn post_aggregates_with_proofs_v2(
+        &self,
+        aggregates_with_proofs: &[C::SignedAggregateAndProof],
+        version: Version,
+    ) -> Result<(), Error> {
+        let target = self.endpoint.join("eth/v2/validator/aggregate_and_proofs")?;
+        let request = self
+            .http
+            .post(target)
+            .json(aggregates_with_proofs)
+            .header(CONSENSUS_VERSION_HEADER, version.to_string());
+        let response = self.send(request).await?;
+        api_error_or_ok(response).await
+    }
+
     pub async fn subscribe_subnets_for_attestation_committees(
         &self,
         committee_descriptors: &[CommitteeDescriptor],
@@ -831,7 +1207,7 @@ impl<C: ClientTypes> Client<C> {
         request = request.query(&[("slot", slot)]);
         request = request.query(&[("subcommittee_index", subcommittee_index)]);
         request = request.query(&[("beacon_block_root", format!("{beacon_block_root:?}"))]);
-        let response = request.send().await?;
+        let response = self.send(request).await?;
         let result: ApiResult<Value<C::SyncCommitteeContribution>> = response.json().await?;
         match result {
             ApiResult::Ok(result) => Ok(result.data),
@@ -866,12 +1242,740 @@ impl<C: ClientTypes> Client<C> {
         epoch: Epoch,
         indices: &[ValidatorIndex],
     ) -> Result<Vec<ValidatorLiveness>, Error> {
-        let response =
-            self.http_post(&format!("eth/v1/validator/liveness/{epoch}"), indices).await?;
-        let result: ApiResult<Value<_>> = response.json().await?;
-        match result {
-            ApiResult::Ok(result) => Ok(result.data),
-            ApiResult::Err(err) => Err(err.into()),
+        let endpoint = format!("eth/v1/validator/liveness/{epoch}");
+        let indices = indices.iter().map(|index| index.to_string()).collect::<Vec<_>>();
+        let response = self.http_post(&endpoint, &indices).await?;
+        let result: Value<Vec<ValidatorLiveness>> = api_error_or_value(response).await?;
+        Ok(result.data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::presets::mainnet::Client;
+    use ethereum_consensus::primitives::BlsSignature;
+    use serde_json::json;
+    use std::{
+        collections::VecDeque,
+        sync::{Arc, Mutex},
+    };
+    use tokio::{
+        io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader},
+        net::{TcpListener, TcpStream},
+    };
+
+    #[derive(Debug, Clone)]
+    struct Request {
+        method: String,
+        target: String,
+        headers: HashMap<String, String>,
+        body: String,
+        connection: usize,
+    }
+
+    impl Request {
+        fn query(&self) -> Vec<(String, String)> {
+            let url = Url::parse(&format!("http://localhost{}", self.target)).unwrap();
+            url.query_pairs().into_owned().collect()
         }
     }
+
+    type Response = (u16, Vec<(&'static str, &'static str)>, serde_json::Value);
+
+    // A stand-in for a beacon node which answers each request with the next of its
+    // `(status, body)` responses, recording every request it receives.
+    struct MockServer {
+        endpoint: Url,
+        requests: Arc<Mutex<Vec<Request>>>,
+    }
+
+    impl MockServer {
+        async fn start(responses: Vec<(u16, serde_json::Value)>) -> Self {
+            let responses =
+                responses.into_iter().map(|(status, body)| (status, vec![], body)).collect();
+            Self::start_with_headers(responses).await
+        }
+
+        // Like `start`, with extra headers to send along with each response.
+        async fn start_with_headers(responses: Vec<Response>) -> Self {
+            let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+            let endpoint = format!("http://{}/", listener.local_addr().unwrap());
+            let requests = Arc::new(Mutex::new(vec![]));
+            let responses = Arc::new(Mutex::new(VecDeque::from(responses)));
+            let log = requests.clone();
+            tokio::spawn(async move {
+                for connection in 0.. {
+                    let (stream, _) = listener.accept().await.unwrap();
+                    tokio::spawn(serve(stream, connection, responses.clone(), log.clone()));
+                }
+            });
+            Self { endpoint: endpoint.parse().unwrap(), requests }
+        }
+
+        fn client(&self) -> Client {
+            Client::new(self.endpoint.clone())
+        }
+
+        fn requests(&self) -> Vec<Request> {
+            self.requests.lock().unwrap().clone()
+        }
+    }
+
+    // Serve requests on `stream` until the client closes it, keeping the connection alive.
+    async fn serve(
+        stream: TcpStream,
+        connection: usize,
+        responses: Arc<Mutex<VecDeque<Response>>>,
+        requests: Arc<Mutex<Vec<Request>>>,
+    ) {
+        let (reader, mut writer) = stream.into_split();
+        let mut reader = BufReader::new(reader);
+        loop {
+            let mut request_line = String::new();
+            if reader.read_line(&mut request_line).await.unwrap_or(0) == 0 {
+                return
+            }
+            let mut headers = HashMap::new();
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).await.unwrap();
+                let line = line.trim_end();
+                if line.is_empty() {
+                    break
+                }
+                let (name, value) = line.split_once(':').unwrap_or((line, ""));
+                headers.insert(name.to_ascii_lowercase(), value.trim().to_string());
+            }
+            let content_length =
+                headers.get("content-length").map_or(0, |len| len.parse().unwrap());
+            let mut body = vec![0u8; content_length];
+            reader.read_exact(&mut body).await.unwrap();
+
+            let mut parts = request_line.split_whitespace();
+            requests.lock().unwrap().push(Request {
+                method: parts.next().unwrap().to_string(),
+                target: parts.next().unwrap().to_string(),
+                headers,
+                body: String::from_utf8(body).unwrap(),
+                connection,
+            });
+            let (status, extra_headers, body) =
+                responses.lock().unwrap().pop_front().unwrap_or_else(|| {
+                    (
+                        404,
+                        vec![],
+                        json!({ "code": 404, "message": "no response left in mock server" }),
+                    )
+                });
+            let body = body.to_string();
+            let extra_headers = extra_headers
+                .into_iter()
+                .map(|(name, value)| format!("{name}: {value}\r\n"))
+                .collect::<String>();
+            let response = format!(
+                "HTTP/1.1 {status} Mock\r\ncontent-type: application/json\r\n{extra_headers}content-length: {}\r\n\r\n{body}",
+                body.len()
+            );
+            writer.write_all(response.as_bytes()).await.unwrap();
+        }
+    }
+
+    fn unavailable() -> (u16, serde_json::Value) {
+        (503, json!({ "code": 503, "message": "beacon node is unavailable" }))
+    }
+
+    fn retry_policy() -> RetryPolicy {
+        RetryPolicy {
+            max_retries: 2,
+            initial_backoff: Duration::from_millis(1),
+            max_backoff: Duration::from_millis(4),
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_retry_policy_retries_idempotent_requests() {
+        let version = json!({ "data": { "version": "mock/v1.0.0" } });
+        let server = MockServer::start(vec![unavailable(), unavailable(), (200, version)]).await;
+        let client = server.client().with_retry_policy(retry_policy());
+        assert_eq!(client.get_node_version().await.unwrap(), "mock/v1.0.0");
+        let requests = server.requests();
+        assert_eq!(requests.len(), 3);
+        assert!(requests.iter().all(|request| request.target == "/eth/v1/node/version"));
+
+        // the last response is returned once the retries are exhausted
+        let server = MockServer::start(vec![unavailable(), unavailable(), unavailable()]).await;
+        let client = server.client().with_retry_policy(retry_policy());
+        let err = client.get_node_version().await.unwrap_err();
+        assert!(matches!(err, Error::Api(ApiError::ErrorMessage { code, .. }) if code == 503));
+        assert_eq!(server.requests().len(), 3);
+
+        // requests are never retried without a policy
+        let server = MockServer::start(vec![unavailable()]).await;
+        assert!(server.client().get_node_version().await.is_err());
+        assert_eq!(server.requests().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_retry_policy_does_not_retry_post() {
+        let liveness = json!({ "data": [{ "index": "1", "is_live": false }] });
+        let server = MockServer::start(vec![unavailable(), (200, liveness.clone())]).await;
+        let client = server.client().with_retry_policy(retry_policy());
+        let err = client.post_liveness(3, &[1]).await.unwrap_err();
+        assert!(matches!(err, Error::Api(ApiError::ErrorMessage { code, .. }) if code == 503));
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(requests[0].body, r#"["1"]"#);
+
+        // unless the policy opts in to retrying non-idempotent requests
+        let server = MockServer::start(vec![unavailable(), (200, liveness)]).await;
+        let policy = RetryPolicy { retry_non_idempotent: true, ..retry_policy() };
+        let client = server.client().with_retry_policy(policy);
+        let result = client.post_liveness(3, &[1]).await.unwrap();
+        assert!(!result[0].is_live);
+        assert_eq!(server.requests().len(), 2);
+    }
+
+    fn sync_status(head_slot: Slot, sync_distance: usize) -> (u16, serde_json::Value) {
+        let data = json!({
+            "head_slot": head_slot.to_string(),
+            "sync_distance": sync_distance.to_string(),
+            "is_syncing": sync_distance > 0,
+            "is_optimistic": false,
+        });
+        (200, json!({ "data": data }))
+    }
+
+    #[tokio::test]
+    async fn test_wait_until_synced() {
+        let server = MockServer::start(vec![sync_status(90, 10), sync_status(101, 1)]).await;
+        let status = server.client().wait_until_synced(2, Duration::from_secs(10)).await.unwrap();
+        assert_eq!(status.head_slot, 101);
+        assert_eq!(status.is_optimistic, Some(false));
+        let requests = server.requests();
+        assert_eq!(requests.len(), 2);
+        assert!(requests.iter().all(|request| request.target == "/eth/v1/node/syncing"));
+    }
+
+    #[tokio::test]
+    async fn test_wait_until_synced_times_out() {
+        let responses = (0..8).map(|i| sync_status(i, 100)).collect();
+        let server = MockServer::start(responses).await;
+        let timeout = Duration::from_millis(50);
+        let err = server.client().wait_until_synced(2, timeout).await.unwrap_err();
+        assert!(matches!(err, Error::SyncTimeout(t) if t == timeout));
+        // the backoff is cut short at the deadline, where the node is polled one last time
+        assert_eq!(server.requests().len(), 2);
+    }
+
+    #[cfg(feature = "tracing")]
+    #[tokio::test]
+    async fn test_request_span() {
+        use tracing::{
+            field::{Field, Visit},
+            span::{Attributes, Id, Record},
+            Subscriber,
+        };
+        use tracing_subscriber::{layer::Context, prelude::*, registry::LookupSpan, Layer};
+
+        // Collects the fields recorded on request spans, starting with the name of the span.
+        #[derive(Clone, Default)]
+        struct SpanRecorder(Arc<Mutex<Vec<String>>>);
+
+        impl Visit for SpanRecorder {
+            fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+                self.0.lock().unwrap().push(format!("{}={value:?}", field.name()));
+            }
+        }
+
+        impl<S: Subscriber + for<'a> LookupSpan<'a>> Layer<S> for SpanRecorder {
+            fn on_new_span(&self, attrs: &Attributes<'_>, _: &Id, _: Context<'_, S>) {
+                let name = attrs.metadata().name();
+                if name == "beacon_api_request" {
+                    self.0.lock().unwrap().push(name.to_string());
+                    attrs.record(&mut self.clone());
+                }
+            }
+
+            fn on_record(&self, id: &Id, values: &Record<'_>, ctx: Context<'_, S>) {
+                if ctx.span(id).is_some_and(|span| span.name() == "beacon_api_request") {
+                    values.record(&mut self.clone());
+                }
+            }
+        }
+
+        let recorder = SpanRecorder::default();
+        let _guard = tracing_subscriber::registry().with(recorder.clone()).set_default();
+
+        let version = json!({ "data": { "version": "mock/v1.0.0" } });
+        let server = MockServer::start(vec![(200, version)]).await;
+        server.client().get_node_version().await.unwrap();
+
+        let recorded = recorder.0.lock().unwrap().clone();
+        assert_eq!(
+            recorded[..4],
+            ["beacon_api_request", "method=GET", "path=\"/eth/v1/node/version\"", "status=200"]
+        );
+        assert!(recorded[4].starts_with("latency_ms="));
+        assert_eq!(recorded.len(), 5);
+    }
+
+    #[tokio::test]
+    async fn test_connection_pool() {
+        let version = json!({ "data": { "version": "mock/v1.0.0" } });
+        let connections = |server: &MockServer| {
+            server.requests().iter().map(|request| request.connection).collect::<Vec<_>>()
+        };
+
+        // idle connections are reused by later requests
+        let server = MockServer::start(vec![(200, version.clone()); 3]).await;
+        let client = Client::builder(server.endpoint.clone())
+            .pool_max_idle_per_host(1)
+            .pool_idle_timeout(Duration::from_secs(60))
+            .build()
+            .unwrap();
+        for _ in 0..3 {
+            client.get_node_version().await.unwrap();
+        }
+        assert_eq!(connections(&server), [0, 0, 0]);
+
+        // unless no connections may be kept idle
+        let server = MockServer::start(vec![(200, version.clone()); 3]).await;
+        let client =
+            Client::builder(server.endpoint.clone()).pool_max_idle_per_host(0).build().unwrap();
+        for _ in 0..3 {
+            client.get_node_version().await.unwrap();
+        }
+        assert_eq!(connections(&server), [0, 1, 2]);
+
+        // or the connection has been idle for too long
+        let server = MockServer::start(vec![(200, version); 3]).await;
+        let client = Client::builder(server.endpoint.clone())
+            .pool_idle_timeout(Duration::from_millis(20))
+            .build()
+            .unwrap();
+        client.get_node_version().await.unwrap();
+        client.get_node_version().await.unwrap();
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        client.get_node_version().await.unwrap();
+        assert_eq!(connections(&server), [0, 0, 1]);
+    }
+
+    #[tokio::test]
+    async fn test_http_post_canonical() {
+        let mut extra = HashMap::new();
+        for (i, key) in ["zeta", "alpha", "mu", "beta"].into_iter().enumerate() {
+            extra.insert(key.to_string(), json!({ "y": i, "x": [i] }));
+        }
+        let argument = json!({ "message": { "slot": "1", "extra": extra }, "signature": "0x00" });
+
+        let server = MockServer::start(vec![(200, json!({})); 2]).await;
+        let client = server.client();
+        client.http_post_canonical("eth/v1/validator/register_validator", &argument).await.unwrap();
+        client.http_post_canonical("eth/v1/validator/register_validator", &argument).await.unwrap();
+        let requests = server.requests();
+        assert_eq!(requests[0].body, to_canonical_json(&argument).unwrap());
+        assert_eq!(requests[0].body, requests[1].body);
+        assert!(requests[0].body.starts_with(r#"{"message":{"extra":{"alpha":{"x":[1],"y":1},"#));
+    }
+
+    #[tokio::test]
+    async fn test_get_validator_identities() {
+        let public_key = format!("0x{}", "ab".repeat(48));
+        let identities = json!({
+            "data": [
+                { "index": "1", "pubkey": public_key, "activation_epoch": "0" },
+                { "index": "42", "pubkey": public_key, "activation_epoch": "18446744073709551615" },
+            ]
+        });
+        let server = MockServer::start(vec![(200, identities)]).await;
+        let identities =
+            server.client().get_validator_identities(StateId::Head, &[1, 42]).await.unwrap();
+        assert_eq!(identities.len(), 2);
+        assert_eq!(identities[1].index, 42);
+        assert_eq!(format!("{:?}", identities[1].public_key), public_key);
+        assert_eq!(identities[1].activation_epoch, u64::MAX);
+
+        let requests = server.requests();
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(requests[0].target, "/eth/v1/beacon/states/head/validator_identities");
+        assert_eq!(requests[0].body, r#"["1","42"]"#);
+    }
+
+    #[tokio::test]
+    async fn test_get_attestation_data() {
+        let root = |byte: u8| format!("0x{}", hex::encode([byte; 32]));
+        let data = json!({
+            "data": {
+                "slot": "33",
+                "index": "0",
+                "beacon_block_root": root(1),
+                "source": { "epoch": "0", "root": root(2) },
+                "target": { "epoch": "1", "root": root(3) },
+            }
+        });
+        let server = MockServer::start(vec![(200, data)]).await;
+        let data = server.client().get_attestation_data(33, 2).await.unwrap();
+        assert_eq!(data.slot, 33);
+        assert_eq!(format!("{:?}", data.beacon_block_root), root(1));
+        assert_eq!(data.source.epoch, 0);
+        assert_eq!(format!("{:?}", data.source.root), root(2));
+        assert_eq!(data.target.epoch, 1);
+        assert_eq!(format!("{:?}", data.target.root), root(3));
+
+        let requests = server.requests();
+        assert!(requests[0].target.starts_with("/eth/v1/validator/attestation_data?"));
+        assert_eq!(
+            requests[0].query(),
+            [("slot".to_string(), "33".to_string()), ("committee_index".into(), "2".into())]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_aggregate_attestation_requests() {
+        type Attestation = ethereum_consensus::phase0::mainnet::Attestation;
+        type SignedAggregateAndProof = ethereum_consensus::phase0::mainnet::SignedAggregateAndProof;
+
+        let root = Root::try_from([3u8; 32].as_ref()).unwrap();
+        let attestation = serde_json::to_value(Attestation::default()).unwrap();
+        let server = MockServer::start(vec![
+            (200, json!({ "data": attestation })),
+            (200, json!({ "version": Version::Electra, "data": attestation })),
+            (200, json!({})),
+            (200, json!({})),
+        ])
+        .await;
+        let client = server.client();
+        client.get_attestation_aggregate(root, 17).await.unwrap();
+        client.get_attestation_aggregate_v2(root, 17, 2).await.unwrap();
+        let aggregates = [SignedAggregateAndProof::default()];
+        client.post_aggregates_with_proofs(&aggregates).await.unwrap();
+        client.post_aggregates_with_proofs_v2(&aggregates, Version::Electra).await.unwrap();
+
+        let requests = server.requests();
+        let root = format!("{root:?}");
+        assert!(requests[0].target.starts_with("/eth/v1/validator/aggregate_attestation?"));
+        assert_eq!(
+            requests[0].query(),
+            [("attestation_data_root".to_string(), root.clone()), ("slot".into(), "17".into())]
+        );
+        // from Electra, the committee index is also needed to identify the aggregate
+        assert!(requests[1].target.starts_with("/eth/v2/validator/aggregate_attestation?"));
+        assert_eq!(
+            requests[1].query(),
+            [
+                ("attestation_data_root".to_string(), root),
+                ("slot".into(), "17".into()),
+                ("committee_index".into(), "2".into()),
+            ]
+        );
+
+        let body = serde_json::to_string(&aggregates).unwrap();
+        assert_eq!(requests[2].method, "POST");
+        assert_eq!(requests[2].target, "/eth/v1/validator/aggregate_and_proofs");
+        assert_eq!(requests[2].body, body);
+        assert!(!requests[2].headers.contains_key(CONSENSUS_VERSION_HEADER));
+        assert_eq!(requests[3].target, "/eth/v2/validator/aggregate_and_proofs");
+        assert_eq!(requests[3].body, body);
+        assert_eq!(requests[3].headers[CONSENSUS_VERSION_HEADER], "electra");
+    }
+
+    #[tokio::test]
+    async fn test_sync_committee_contribution_requests() {
+        type SyncCommitteeContribution =
+            ethereum_consensus::altair::mainnet::SyncCommitteeContribution;
+        type SignedContributionAndProof =
+            ethereum_consensus::altair::mainnet::SignedContributionAndProof;
+
+        let root = Root::try_from([4u8; 32].as_ref()).unwrap();
+        let mut contribution = SyncCommitteeContribution {
+            slot: 33,
+            beacon_block_root: root,
+            subcommittee_index: 1,
+            ..Default::default()
+        };
+        contribution.aggregation_bits.set(5, true);
+        let data = serde_json::to_value(&contribution).unwrap();
+        let server =
+            MockServer::start(vec![(200, json!({ "data": data })), (200, json!({}))]).await;
+        let client = server.client();
+        let response = client.get_sync_committee_contribution(33, 1, root).await.unwrap();
+        assert_eq!(response.slot, 33);
+        assert_eq!(response.subcommittee_index, 1);
+        assert_eq!(response.beacon_block_root, root);
+        assert_eq!(response.aggregation_bits, contribution.aggregation_bits);
+
+        let mut signed = SignedContributionAndProof::default();
+        signed.message.aggregator_index = 12;
+        signed.message.contribution = contribution;
+        client.post_sync_committee_contributions_with_proofs(&[signed]).await.unwrap();
+
+        let requests = server.requests();
+        assert!(requests[0].target.starts_with("/eth/v1/validator/sync_committee_contribution?"));
+        assert_eq!(
+            requests[0].query(),
+            [
+                ("slot".to_string(), "33".to_string()),
+                ("subcommittee_index".into(), "1".into()),
+                ("beacon_block_root".into(), format!("{root:?}")),
+            ]
+        );
+
+        assert_eq!(requests[1].method, "POST");
+        assert_eq!(requests[1].target, "/eth/v1/validator/contribution_and_proofs");
+        let body: serde_json::Value = serde_json::from_str(&requests[1].body).unwrap();
+        let message = &body[0]["message"];
+        assert_eq!(message["aggregator_index"], "12");
+        assert_eq!(message["contribution"]["slot"], "33");
+        assert_eq!(message["contribution"]["subcommittee_index"], "1");
+        assert_eq!(message["contribution"]["beacon_block_root"], format!("{root:?}"));
+        assert_eq!(message["contribution"]["aggregation_bits"], data["aggregation_bits"]);
+        assert!(body[0]["signature"].as_str().unwrap().starts_with("0x"));
+    }
+
+    #[tokio::test]
+    async fn test_post_sync_committee_messages() {
+        let message = |slot, validator_index, byte: u8| SyncCommitteeMessage {
+            slot,
+            beacon_block_root: Root::try_from([byte; 32].as_ref()).unwrap(),
+            validator_index,
+            signature: BlsSignature::try_from([byte; 96].as_ref()).unwrap(),
+        };
+        let server = MockServer::start(vec![(200, json!({}))]).await;
+        let messages = [message(33, 7, 0xaa), message(34, 1024, 0xbb)];
+        server.client().post_sync_committee_messages(&messages).await.unwrap();
+
+        let requests = server.requests();
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(requests[0].target, "/eth/v1/beacon/pool/sync_committees");
+        let expected = format!(
+            r#"[{{"slot":"33","beacon_block_root":"0x{}","validator_index":"7","signature":"0x{}"}},{{"slot":"34","beacon_block_root":"0x{}","validator_index":"1024","signature":"0x{}"}}]"#,
+            "aa".repeat(32),
+            "aa".repeat(96),
+            "bb".repeat(32),
+            "bb".repeat(96),
+        );
+        assert_eq!(requests[0].body, expected);
+    }
+
+    #[tokio::test]
+    async fn test_post_liveness() {
+        let liveness = json!({
+            "data": [{ "index": "2", "is_live": true }, { "index": "1024", "is_live": false }]
+        });
+        let server = MockServer::start(vec![(200, liveness)]).await;
+        let liveness = server.client().post_liveness(77, &[2, 1024]).await.unwrap();
+        assert_eq!(liveness.len(), 2);
+        assert!(liveness[0].index == 2 && liveness[0].is_live);
+        assert!(liveness[1].index == 1024 && !liveness[1].is_live);
+
+        let requests = server.requests();
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(requests[0].target, "/eth/v1/validator/liveness/77");
+        assert_eq!(requests[0].body, r#"["2","1024"]"#);
+    }
+
+    #[tokio::test]
+    async fn test_get_fork_schedule() {
+        let schedule = json!({
+            "data": [
+                { "previous_version": "0x00000000", "current_version": "0x00000000", "epoch": "0" },
+                { "previous_version": "0x00000000", "current_version": "0x01000000", "epoch": "74240" },
+                { "previous_version": "0x01000000", "current_version": "0x02000000", "epoch": "144896" },
+                { "previous_version": "0x02000000", "current_version": "0x03000000", "epoch": "194048" },
+                { "previous_version": "0x03000000", "current_version": "0x04000000", "epoch": "269568" },
+            ]
+        });
+        let server = MockServer::start(vec![(200, schedule)]).await;
+        let schedule = server.client().get_fork_schedule().await.unwrap();
+        assert_eq!(server.requests()[0].target, "/eth/v1/config/fork_schedule");
+
+        let epochs = schedule.iter().map(|fork| fork.epoch).collect::<Vec<_>>();
+        assert_eq!(epochs, [0, 74240, 144896, 194048, 269568]);
+        for (i, pair) in schedule.windows(2).enumerate() {
+            assert_eq!(pair[0].current_version, pair[1].previous_version);
+            assert_eq!(pair[1].current_version, [i as u8 + 1, 0, 0, 0]);
+        }
+    }
+
+    fn validators(indices: std::ops::Range<ValidatorIndex>) -> (u16, serde_json::Value) {
+        let data = indices
+            .map(|index| ValidatorSummary {
+                index,
+                balance: 32_000_000_000,
+                status: ValidatorStatus::ActiveOngoing,
+                validator: Default::default(),
+            })
+            .collect::<Vec<_>>();
+        (200, json!({ "data": data }))
+    }
+
+    #[tokio::test]
+    async fn test_get_validators_in_range() {
+        let page_size = 64;
+        assert_eq!(VALIDATOR_RANGE_PAGE_SIZE, page_size as u64);
+        let requested_ids = |server: &MockServer| {
+            server
+                .requests()
+                .iter()
+                .map(|request| {
+                    assert!(request.target.starts_with("/eth/v1/beacon/states/head/validators?"));
+                    let (key, ids) = request.query().pop().unwrap();
+                    assert_eq!(key, "id");
+                    let ids = ids.split(',').map(|id| id.parse().unwrap()).collect::<Vec<_>>();
+                    assert!(ids.len() <= page_size);
+                    ids
+                })
+                .collect::<Vec<Vec<ValidatorIndex>>>()
+        };
+
+        // a range crossing a page boundary is fetched in full, one page at a time
+        let (start, end) = (1000, 1000 + page_size + 12);
+        let server = MockServer::start(vec![
+            validators(start..start + page_size),
+            validators(start + page_size..end),
+        ])
+        .await;
+        let client = server.client();
+        let summaries = client
+            .get_validators_in_range(StateId::Head, start, (end - start) as u64)
+            .await
+            .unwrap();
+        let indices = summaries.iter().map(|summary| summary.index).collect::<Vec<_>>();
+        assert_eq!(indices, (start..end).collect::<Vec<_>>());
+        let pages = requested_ids(&server);
+        assert_eq!(
+            pages,
+            [(start..start + page_size).collect(), (start + page_size..end).collect::<Vec<_>>()]
+        );
+
+        // the registry ends partway through the second page, so no third page is requested
+        let registry_end = start + page_size + 26;
+        let server = MockServer::start(vec![
+            validators(start..start + page_size),
+            validators(start + page_size..registry_end),
+        ])
+        .await;
+        let summaries =
+            server.client().get_validators_in_range(StateId::Head, start, 300).await.unwrap();
+        assert_eq!(summaries.len(), registry_end - start);
+        assert_eq!(summaries.last().unwrap().index, registry_end - 1);
+        let pages = requested_ids(&server);
+        assert_eq!(pages.len(), 2);
+        assert_eq!(pages[1], (start + page_size..start + 2 * page_size).collect::<Vec<_>>());
+    }
+
+    #[tokio::test]
+    async fn test_get_state_randao() {
+        let randao = format!("0x{}", "5a".repeat(32));
+        let response =
+            (200, json!({ "execution_optimistic": false, "data": { "randao": randao } }));
+        let server = MockServer::start(vec![response.clone(), response]).await;
+        let client = server.client();
+        let mix = client.get_state_randao(StateId::Finalized, Some(12)).await.unwrap();
+        assert_eq!(mix, Bytes32::try_from([0x5a; 32].as_ref()).unwrap());
+        client.get_randao(StateId::Slot(64), None).await.unwrap();
+
+        let requests = server.requests();
+        assert_eq!(requests[0].target, "/eth/v1/beacon/states/finalized/randao?epoch=12");
+        assert_eq!(requests[1].target, "/eth/v1/beacon/states/64/randao");
+    }
+
+    #[tokio::test]
+    async fn test_consensus_version_header() {
+        let body = json!({ "version": "capella", "data": { "slot": "1" } });
+        let server = MockServer::start_with_headers(vec![
+            (200, vec![("Eth-Consensus-Version", "deneb")], body.clone()),
+            (200, vec![], body.clone()),
+            (200, vec![("Eth-Consensus-Version", "fulu")], body.clone()),
+            (200, vec![("Eth-Consensus-Version", "deneb")], body),
+        ])
+        .await;
+        let client = server.client();
+        let target = client.endpoint.join("eth/v2/debug/beacon/states/head").unwrap();
+
+        // the header takes precedence over the `version` in the body
+        let result: VersionedValue<serde_json::Value> =
+            client.get_versioned(client.http.get(target.clone())).await.unwrap();
+        assert!(matches!(result.version, Version::Deneb));
+        assert_eq!(result.data, json!({ "slot": "1" }));
+
+        // without the header, the body is used
+        let result: VersionedValue<serde_json::Value> =
+            client.get_versioned(client.http.get(target.clone())).await.unwrap();
+        assert!(matches!(result.version, Version::Capella));
+
+        let err = client
+            .get_versioned::<serde_json::Value>(client.http.get(target.clone()))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::UnknownConsensusVersion(version) if version == "fulu"));
+
+        let response = client.http_get("eth/v2/debug/beacon/states/head").await.unwrap();
+        assert!(matches!(consensus_version(&response), Ok(Some(Version::Deneb))));
+    }
+
+    #[tokio::test]
+    async fn test_get_pending_queues() {
+        let deposits = json!({
+            "execution_optimistic": false,
+            "finalized": false,
+            "data": [{
+                "pubkey": format!("0x{}", "ab".repeat(48)),
+                "withdrawal_credentials": format!("0x{}", "01".repeat(32)),
+                "amount": "32000000000",
+                "signature": format!("0x{}", "cd".repeat(96)),
+                "slot": "7",
+            }]
+        });
+        let withdrawals = json!({
+            "data": [{ "index": "3", "amount": "1000000000", "withdrawable_epoch": "21" }]
+        });
+        let consolidations = json!({
+            "data": [
+                { "source_index": "4", "target_index": "5" },
+                { "source_index": "6", "target_index": "5" },
+            ]
+        });
+        let server =
+            MockServer::start(vec![(200, deposits), (200, withdrawals), (200, consolidations)])
+                .await;
+        let client = server.client();
+
+        let deposits = client.get_pending_deposits(StateId::Head).await.unwrap();
+        assert_eq!(deposits.len(), 1);
+        assert_eq!(deposits[0].amount, 32_000_000_000);
+        assert_eq!(deposits[0].slot, 7);
+        assert_eq!(format!("{:?}", deposits[0].public_key), format!("0x{}", "ab".repeat(48)));
+        assert_eq!(
+            deposits[0].withdrawal_credentials,
+            Bytes32::try_from([1u8; 32].as_ref()).unwrap()
+        );
+
+        let withdrawals = client.get_pending_partial_withdrawals(StateId::Finalized).await.unwrap();
+        assert_eq!(withdrawals.len(), 1);
+        assert_eq!(withdrawals[0].index, 3);
+        assert_eq!(withdrawals[0].amount, 1_000_000_000);
+        assert_eq!(withdrawals[0].withdrawable_epoch, 21);
+
+        let consolidations = client.get_pending_consolidations(StateId::Slot(64)).await.unwrap();
+        let pairs = consolidations
+            .iter()
+            .map(|consolidation| (consolidation.source_index, consolidation.target_index))
+            .collect::<Vec<_>>();
+        assert_eq!(pairs, [(4, 5), (6, 5)]);
+
+        let targets =
+            server.requests().into_iter().map(|request| request.target).collect::<Vec<_>>();
+        assert_eq!(
+            targets,
+            [
+                "/eth/v1/beacon/states/head/pending_deposits",
+                "/eth/v1/beacon/states/finalized/pending_partial_withdrawals",
+                "/eth/v1/beacon/states/64/pending_consolidations",
+            ]
+        );
+    }
 }
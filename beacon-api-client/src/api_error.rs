@@ -22,8 +22,8 @@ pub enum ApiError {
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct IndexedError {
-    index: usize,
-    message: String,
+    pub index: usize,
+    pub message: String,
 }
 
 impl fmt::Display for ApiError {
@@ -33,9 +33,10 @@ impl fmt::Display for ApiError {
                 write!(f, "{message}")
             }
             Self::IndexedError { message, failures, .. } => {
-                write!(f, "{message}: ")?;
-                for failure in failures {
-                    write!(f, "{failure:?}, ")?;
+                write!(f, "{message}")?;
+                for (i, failure) in failures.iter().enumerate() {
+                    let separator = if i == 0 { ": " } else { "; " };
+                    write!(f, "{separator}{failure}")?;
                 }
                 Ok(())
             }
@@ -43,6 +44,12 @@ impl fmt::Display for ApiError {
     }
 }
 
+impl fmt::Display for IndexedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "item {} failed: {}", self.index, self.message)
+    }
+}
+
 impl Error for ApiError {}
 
 impl<'a> TryFrom<(u16, &'a str)> for ApiError {
@@ -53,3 +60,48 @@ impl<'a> TryFrom<(u16, &'a str)> for ApiError {
         Ok(Self::ErrorMessage { code, message: message.to_string() })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display_indexed_error() {
+        let err: ApiError = serde_json::from_str(
+            r#"{
+                "code": 400,
+                "message": "some failures",
+                "failures": [
+                    { "index": 0, "message": "invalid signature" },
+                    { "index": 3, "message": "unknown validator" }
+                ]
+            }"#,
+        )
+        .unwrap();
+        assert!(matches!(err, ApiError::IndexedError { .. }));
+        assert_eq!(
+            err.to_string(),
+            "some failures: item 0 failed: invalid signature; item 3 failed: unknown validator"
+        );
+
+        let err = ApiError::IndexedError {
+            code: StatusCode::BAD_REQUEST,
+            message: "no failures".to_string(),
+            failures: vec![],
+        };
+        assert_eq!(err.to_string(), "no failures");
+    }
+
+    #[test]
+    fn test_display_error_message() {
+        let err: ApiError =
+            serde_json::from_str(r#"{ "code": 404, "message": "block not found" }"#).unwrap();
+        assert!(
+            matches!(err, ApiError::ErrorMessage { code, .. } if code == StatusCode::NOT_FOUND)
+        );
+        assert_eq!(err.to_string(), "block not found");
+
+        let err = ApiError::try_from((503, "beacon node is syncing")).unwrap();
+        assert_eq!(err.to_string(), "beacon node is syncing");
+    }
+}
@@ -0,0 +1,165 @@
+use crate::{Client, ClientTypes, Error, ValidatorLiveness};
+use ethereum_consensus::primitives::{Epoch, ValidatorIndex};
+use std::collections::HashSet;
+
+pub const DEFAULT_DOPPELGANGER_EPOCHS: u64 = 2;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DoppelgangerStatus {
+    // Liveness must still be observed for `remaining_epochs` epochs before signing is safe.
+    Pending { remaining_epochs: u64 },
+    // No monitored validator was live during the detection window.
+    Safe,
+    // These monitored validators were live elsewhere so signing must not start.
+    Detected(Vec<ValidatorIndex>),
+}
+
+// Watches a set of validators for activity on the network before this client
+// starts signing with their keys. Any liveness observed from `start_epoch` onwards
+// must come from another signer, as this client has not yet signed anything.
+pub struct DoppelgangerDetector {
+    indices: HashSet<ValidatorIndex>,
+    start_epoch: Epoch,
+    epochs_to_check: u64,
+    checked_epochs: HashSet<Epoch>,
+    detected: Vec<ValidatorIndex>,
+}
+
+impl DoppelgangerDetector {
+    pub fn new(indices: &[ValidatorIndex], start_epoch: Epoch) -> Self {
+        Self::with_epochs(indices, start_epoch, DEFAULT_DOPPELGANGER_EPOCHS)
+    }
+
+    pub fn with_epochs(
+        indices: &[ValidatorIndex],
+        start_epoch: Epoch,
+        epochs_to_check: u64,
+    ) -> Self {
+        Self {
+            indices: indices.iter().copied().collect(),
+            start_epoch,
+            epochs_to_check,
+            checked_epochs: Default::default(),
+            detected: Default::default(),
+        }
+    }
+
+    pub fn status(&self) -> DoppelgangerStatus {
+        if !self.detected.is_empty() {
+            return DoppelgangerStatus::Detected(self.detected.clone())
+        }
+        let checked = self.checked_epochs.len() as u64;
+        if checked >= self.epochs_to_check {
+            DoppelgangerStatus::Safe
+        } else {
+            DoppelgangerStatus::Pending { remaining_epochs: self.epochs_to_check - checked }
+        }
+    }
+
+    // Record the `liveness` reported for `epoch`.
+    // Epochs before `start_epoch` are ignored as any activity then may be this client's own.
+    // An epoch only counts towards the window once liveness is reported for every monitored
+    // validator, so an empty or partial response can not make signing look safe.
+    pub fn record(&mut self, epoch: Epoch, liveness: &[ValidatorLiveness]) -> DoppelgangerStatus {
+        if epoch < self.start_epoch {
+            return self.status()
+        }
+        let mut reported = HashSet::new();
+        for entry in liveness {
+            if !self.indices.contains(&entry.index) {
+                continue
+            }
+            reported.insert(entry.index);
+            if entry.is_live && !self.detected.contains(&entry.index) {
+                self.detected.push(entry.index);
+            }
+        }
+        if reported.len() == self.indices.len() {
+            self.checked_epochs.insert(epoch);
+        }
+        self.status()
+    }
+
+    // Fetch liveness for `epoch` from the beacon node and record it.
+    // NOTE: liveness for an epoch is only complete once the epoch has ended.
+    pub async fn poll<C: ClientTypes>(
+        &mut self,
+        client: &Client<C>,
+        epoch: Epoch,
+    ) -> Result<DoppelgangerStatus, Error> {
+        let indices = self.indices.iter().copied().collect::<Vec<_>>();
+        let liveness = client.post_liveness(epoch, &indices).await?;
+        Ok(self.record(epoch, &liveness))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn liveness(live: &[ValidatorIndex], quiet: &[ValidatorIndex]) -> Vec<ValidatorLiveness> {
+        let live = live.iter().map(|&index| ValidatorLiveness { index, is_live: true });
+        let quiet = quiet.iter().map(|&index| ValidatorLiveness { index, is_live: false });
+        live.chain(quiet).collect()
+    }
+
+    #[test]
+    fn test_quiet_validators_are_safe_after_window() {
+        let mut detector = DoppelgangerDetector::new(&[1, 2, 3], 10);
+        assert_eq!(detector.status(), DoppelgangerStatus::Pending { remaining_epochs: 2 });
+
+        // activity before the start epoch may be our own, and other validators are not watched
+        assert_eq!(
+            detector.record(9, &liveness(&[1, 2, 3], &[])),
+            DoppelgangerStatus::Pending { remaining_epochs: 2 }
+        );
+        assert_eq!(
+            detector.record(10, &liveness(&[4], &[1, 2, 3])),
+            DoppelgangerStatus::Pending { remaining_epochs: 1 }
+        );
+        // an epoch only counts once
+        assert_eq!(
+            detector.record(10, &liveness(&[], &[1, 2, 3])),
+            DoppelgangerStatus::Pending { remaining_epochs: 1 }
+        );
+        assert_eq!(detector.record(11, &liveness(&[], &[1, 2, 3])), DoppelgangerStatus::Safe);
+    }
+
+    #[test]
+    fn test_incomplete_liveness_does_not_count() {
+        let mut detector = DoppelgangerDetector::new(&[1, 2, 3], 10);
+        assert_eq!(detector.record(10, &[]), DoppelgangerStatus::Pending { remaining_epochs: 2 });
+        assert_eq!(
+            detector.record(11, &liveness(&[], &[1, 3, 4])),
+            DoppelgangerStatus::Pending { remaining_epochs: 2 }
+        );
+        assert_eq!(
+            detector.record(11, &liveness(&[], &[1, 2, 3])),
+            DoppelgangerStatus::Pending { remaining_epochs: 1 }
+        );
+
+        // live validators in a partial response are still detected
+        assert_eq!(
+            detector.record(12, &liveness(&[2], &[])),
+            DoppelgangerStatus::Detected(vec![2])
+        );
+    }
+
+    #[test]
+    fn test_live_validator_prevents_start() {
+        let mut detector = DoppelgangerDetector::with_epochs(&[1, 2, 3], 10, 3);
+        assert_eq!(
+            detector.record(10, &liveness(&[2], &[1, 3])),
+            DoppelgangerStatus::Detected(vec![2])
+        );
+        assert_eq!(
+            detector.record(11, &liveness(&[2, 3], &[1])),
+            DoppelgangerStatus::Detected(vec![2, 3])
+        );
+        // the detection is never cleared, even once the window has passed
+        assert_eq!(
+            detector.record(12, &liveness(&[], &[1, 2, 3])),
+            DoppelgangerStatus::Detected(vec![2, 3])
+        );
+    }
+}
@@ -0,0 +1,319 @@
+use crate::{
+    ApiError, BlockEvent, ChainReorgEvent, Error, EventTopic, FinalizedCheckpointEvent, HeadEvent,
+};
+use ethereum_consensus::phase0::SignedVoluntaryExit;
+use futures_util::{
+    stream::{self, BoxStream},
+    Stream, StreamExt,
+};
+use serde::de::DeserializeOwned;
+use std::{
+    marker::PhantomData,
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
+use url::Url;
+
+// Delay before reconnecting to the event stream, unless the server sets its own `retry`.
+const DEFAULT_RECONNECT_DELAY: Duration = Duration::from_secs(3);
+
+// An event from the `eth/v1/events` endpoint, generic over the attestation (`A`) and
+// contribution and proof (`C`) types of the client's preset.
+#[derive(Debug, Clone)]
+pub enum BeaconEvent<A, C> {
+    Head(HeadEvent),
+    Block(BlockEvent),
+    Attestation(A),
+    VoluntaryExit(SignedVoluntaryExit),
+    FinalizedCheckpoint(FinalizedCheckpointEvent),
+    ChainReorg(ChainReorgEvent),
+    ContributionAndProof(C),
+}
+
+impl<A: DeserializeOwned, C: DeserializeOwned> BeaconEvent<A, C> {
+    // Parse the `data` of an SSE frame according to its `event` name.
+    pub fn parse(event: &str, data: &str) -> Result<Self, Error> {
+        let topic = event.parse::<EventTopic>().map_err(Error::UnknownEvent)?;
+        let event = match topic {
+            EventTopic::Head => Self::Head(serde_json::from_str(data)?),
+            EventTopic::Block => Self::Block(serde_json::from_str(data)?),
+            EventTopic::Attestation => Self::Attestation(serde_json::from_str(data)?),
+            EventTopic::VoluntaryExit => Self::VoluntaryExit(serde_json::from_str(data)?),
+            EventTopic::FinalizedCheckpoint => {
+                Self::FinalizedCheckpoint(serde_json::from_str(data)?)
+            }
+            EventTopic::ChainReorg => Self::ChainReorg(serde_json::from_str(data)?),
+            EventTopic::ContributionAndProof => {
+                Self::ContributionAndProof(serde_json::from_str(data)?)
+            }
+        };
+        Ok(event)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SseFrame {
+    pub event: String,
+    pub data: String,
+}
+
+// Incrementally splits a `text/event-stream` body into frames.
+#[derive(Debug, Default)]
+pub struct SseParser {
+    buffer: Vec<u8>,
+    event: Option<String>,
+    data: Option<String>,
+    retry: Option<Duration>,
+}
+
+impl SseParser {
+    pub fn push(&mut self, chunk: &[u8]) {
+        self.buffer.extend_from_slice(chunk);
+    }
+
+    // Return the next complete frame, or `None` if more input is required.
+    pub fn next_frame(&mut self) -> Option<SseFrame> {
+        while let Some(end) = self.buffer.iter().position(|&b| b == b'\n') {
+            let line = self.buffer.drain(..=end).collect::<Vec<_>>();
+            let line = String::from_utf8_lossy(&line);
+            let line = line.trim_end_matches(['\n', '\r']);
+            if line.is_empty() {
+                // a blank line dispatches the pending frame
+                let event = self.event.take();
+                if let Some(data) = self.data.take() {
+                    let event = event.unwrap_or_else(|| "message".to_string());
+                    return Some(SseFrame { event, data })
+                }
+                continue
+            }
+            if line.starts_with(':') {
+                continue
+            }
+            let (field, value) = line.split_once(':').unwrap_or((line, ""));
+            let value = value.strip_prefix(' ').unwrap_or(value);
+            match field {
+                "event" => self.event = Some(value.to_string()),
+                "data" => match self.data.as_mut() {
+                    Some(data) => {
+                        data.push('\n');
+                        data.push_str(value);
+                    }
+                    None => self.data = Some(value.to_string()),
+                },
+                "retry" => {
+                    if let Ok(millis) = value.parse() {
+                        self.retry = Some(Duration::from_millis(millis));
+                    }
+                }
+                _ => {}
+            }
+        }
+        None
+    }
+
+    pub fn retry(&self) -> Option<Duration> {
+        self.retry
+    }
+
+    // Drop any partially received frame, e.g. when the connection is lost.
+    pub fn reset(&mut self) {
+        self.buffer.clear();
+        self.event = None;
+        self.data = None;
+    }
+}
+
+// Yields events from the beacon node's event stream, reconnecting whenever the connection
+// is lost. Transport and parsing failures are yielded as errors without ending the stream.
+pub struct BeaconEventStream<A, C> {
+    frames: BoxStream<'static, Result<SseFrame, Error>>,
+    _phantom: PhantomData<fn() -> (A, C)>,
+}
+
+impl<A: DeserializeOwned, C: DeserializeOwned> BeaconEventStream<A, C> {
+    pub(crate) async fn connect(http: reqwest::Client, target: Url) -> Result<Self, Error> {
+        let connection = Connection::open(http, target).await?;
+        let frames = stream::unfold(connection, |mut connection| async move {
+            let frame = connection.next_frame().await;
+            Some((frame, connection))
+        });
+        Ok(Self { frames: frames.boxed(), _phantom: PhantomData })
+    }
+}
+
+impl<A: DeserializeOwned, C: DeserializeOwned> Stream for BeaconEventStream<A, C> {
+    type Item = Result<BeaconEvent<A, C>, Error>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.frames.poll_next_unpin(cx).map(|frame| {
+            frame.map(|frame| frame.and_then(|frame| BeaconEvent::parse(&frame.event, &frame.data)))
+        })
+    }
+}
+
+struct Connection {
+    http: reqwest::Client,
+    target: Url,
+    response: Option<reqwest::Response>,
+    parser: SseParser,
+}
+
+impl Connection {
+    async fn open(http: reqwest::Client, target: Url) -> Result<Self, Error> {
+        let response = open(&http, &target).await?;
+        Ok(Self { http, target, response: Some(response), parser: SseParser::default() })
+    }
+
+    async fn next_frame(&mut self) -> Result<SseFrame, Error> {
+        loop {
+            if let Some(frame) = self.parser.next_frame() {
+                return Ok(frame)
+            }
+            if self.response.is_none() {
+                tokio::time::sleep(self.parser.retry().unwrap_or(DEFAULT_RECONNECT_DELAY)).await;
+                self.response = Some(open(&self.http, &self.target).await?);
+            }
+            let Some(response) = self.response.as_mut() else { continue };
+            match response.chunk().await {
+                Ok(Some(chunk)) => self.parser.push(&chunk),
+                Ok(None) => self.disconnect(),
+                Err(err) => {
+                    self.disconnect();
+                    return Err(err.into())
+                }
+            }
+        }
+    }
+
+    fn disconnect(&mut self) {
+        self.response = None;
+        self.parser.reset();
+    }
+}
+
+async fn open(http: &reqwest::Client, target: &Url) -> Result<reqwest::Response, Error> {
+    let response = http
+        .get(target.clone())
+        .header(reqwest::header::ACCEPT, "text/event-stream")
+        .send()
+        .await?;
+    if response.status().is_success() {
+        Ok(response)
+    } else {
+        let api_err = response.json::<ApiError>().await?;
+        Err(Error::Api(api_err))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ROOT: &str = "0x0101010101010101010101010101010101010101010101010101010101010101";
+
+    fn frames(body: &str, chunk_size: usize) -> (Vec<SseFrame>, SseParser) {
+        let mut parser = SseParser::default();
+        let mut frames = vec![];
+        for chunk in body.as_bytes().chunks(chunk_size) {
+            parser.push(chunk);
+            while let Some(frame) = parser.next_frame() {
+                frames.push(frame);
+            }
+        }
+        (frames, parser)
+    }
+
+    #[test]
+    fn test_sse_parser() {
+        let body = format!(
+            ": keep-alive\n\n\
+             event: block\r\n\
+             data: {{\"slot\": \"1\",\n\
+             data: \"block\": \"{ROOT}\"}}\r\n\
+             \r\n\
+             :\n\
+             event: blob_sidecar\n\
+             data: {{}}\n\
+             \n\
+             retry: 5000\n\
+             event: finalized_checkpoint\n\
+             data: {{\"block\": \"{ROOT}\", \"state\": \"{ROOT}\", \"epoch\": \"2\"}}\n\
+             \n\
+             event: head\n\
+             data: {{"
+        );
+        for chunk_size in [1, 2, 3, 7, body.len()] {
+            let (frames, parser) = frames(&body, chunk_size);
+            assert_eq!(frames.len(), 3);
+            assert_eq!(frames[0].event, "block");
+            assert_eq!(frames[0].data, format!("{{\"slot\": \"1\",\n\"block\": \"{ROOT}\"}}"));
+            assert_eq!(frames[1], SseFrame { event: "blob_sidecar".into(), data: "{}".into() });
+            assert_eq!(frames[2].event, "finalized_checkpoint");
+            assert_eq!(parser.retry(), Some(Duration::from_millis(5000)));
+
+            type Event = BeaconEvent<serde_json::Value, serde_json::Value>;
+            let Ok(Event::Block(block)) = Event::parse(&frames[0].event, &frames[0].data) else {
+                panic!("expected a block event")
+            };
+            assert_eq!(block.slot, 1);
+            assert!(matches!(
+                Event::parse(&frames[1].event, &frames[1].data),
+                Err(Error::UnknownEvent(event)) if event == "blob_sidecar"
+            ));
+            assert!(matches!(
+                Event::parse(&frames[2].event, &frames[2].data),
+                Ok(Event::FinalizedCheckpoint(FinalizedCheckpointEvent { epoch: 2, .. }))
+            ));
+        }
+
+        // the unterminated frame is dropped on reconnect
+        let (_, mut parser) = frames(&body, 4);
+        parser.reset();
+        parser.push(b"\n");
+        assert_eq!(parser.next_frame(), None);
+    }
+
+    #[tokio::test]
+    async fn test_beacon_event_stream() {
+        use tokio::{
+            io::{AsyncBufReadExt, AsyncWriteExt, BufReader},
+            net::TcpListener,
+        };
+
+        let body = format!(
+            "event: head\n\
+             data: {{\"slot\": \n\
+             \n\
+             event: finalized_checkpoint\n\
+             data: {{\"block\": \"{ROOT}\", \"state\": \"{ROOT}\", \"epoch\": \"2\"}}\n\
+             \n"
+        );
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let target = format!("http://{}/eth/v1/events", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut stream = BufReader::new(stream);
+            let mut line = String::new();
+            while stream.read_line(&mut line).await.unwrap() > 2 {
+                line.clear();
+            }
+            let response =
+                format!("HTTP/1.1 200 OK\r\ncontent-type: text/event-stream\r\n\r\n{body}");
+            stream.get_mut().write_all(response.as_bytes()).await.unwrap();
+            // hold the connection open so the client does not reconnect
+            std::future::pending::<()>().await;
+        });
+
+        type Events = BeaconEventStream<serde_json::Value, serde_json::Value>;
+        let events =
+            Events::connect(reqwest::Client::new(), target.parse().unwrap()).await.unwrap();
+        let events = events.take(2).collect::<Vec<_>>().await;
+        // a malformed frame is yielded as an error and the stream carries on
+        assert!(matches!(events[0], Err(Error::Json(..))));
+        assert!(matches!(
+            events[1],
+            Ok(BeaconEvent::FinalizedCheckpoint(FinalizedCheckpointEvent { epoch: 2, .. }))
+        ));
+    }
+}
@@ -1,14 +1,21 @@
 mod api_client;
 mod api_error;
 mod cli;
+mod doppelganger;
+mod events;
 mod serde;
+mod stream;
 mod types;
 
+pub use crate::serde::to_canonical_json;
 pub use api_client::*;
 pub use api_error::*;
 pub use cli::*;
+pub use doppelganger::*;
 pub use error::*;
+pub use events::*;
 pub use presets::*;
+pub use stream::*;
 pub use types::*;
 
 pub const ETH_CONSENSUS_VERSION_HEADER: &str = "Eth-Consensus-Version";
@@ -28,6 +35,12 @@ mod error {
         Api(#[from] ApiError),
         #[error("missing expected data in response: {0}")]
         MissingExpectedData(String),
+        #[error("unknown consensus version in response: {0}")]
+        UnknownConsensusVersion(String),
+        #[error("unknown event in event stream: {0}")]
+        UnknownEvent(String),
+        #[error("beacon node was not synced within {0:?}")]
+        SyncTimeout(std::time::Duration),
         #[error("json error: {0}")]
         Json(#[from] serde_json::Error),
     }
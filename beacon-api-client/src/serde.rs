@@ -1,4 +1,4 @@
-pub(crate) use ethereum_consensus::serde::{as_hex, as_str, seq_of_str};
+pub(crate) use ethereum_consensus::serde::{as_hex, as_str, lenient_bool, seq_of_str};
 
 pub(crate) mod as_u16 {
     use http::StatusCode;
@@ -19,3 +19,88 @@ pub(crate) mod as_u16 {
         StatusCode::from_u16(value).map_err(serde::de::Error::custom)
     }
 }
+
+// Serialize `value` to JSON with object keys in sorted order and no insignificant whitespace,
+// so that the same value always produces the same bytes (e.g. for signing a request body).
+pub fn to_canonical_json<T: serde::Serialize + ?Sized>(
+    value: &T,
+) -> Result<String, serde_json::Error> {
+    let value = serde_json::to_value(value)?;
+    let mut output = String::new();
+    write_canonical_json(&value, &mut output)?;
+    Ok(output)
+}
+
+fn write_canonical_json(
+    value: &serde_json::Value,
+    output: &mut String,
+) -> Result<(), serde_json::Error> {
+    match value {
+        serde_json::Value::Array(elements) => {
+            output.push('[');
+            for (i, element) in elements.iter().enumerate() {
+                if i > 0 {
+                    output.push(',');
+                }
+                write_canonical_json(element, output)?;
+            }
+            output.push(']');
+        }
+        serde_json::Value::Object(fields) => {
+            // NOTE: sort explicitly as the map type may preserve insertion order
+            let mut fields = fields.iter().collect::<Vec<_>>();
+            fields.sort_by(|(a, _), (b, _)| a.cmp(b));
+            output.push('{');
+            for (i, (key, value)) in fields.into_iter().enumerate() {
+                if i > 0 {
+                    output.push(',');
+                }
+                output.push_str(&serde_json::to_string(key)?);
+                output.push(':');
+                write_canonical_json(value, output)?;
+            }
+            output.push('}');
+        }
+        value => output.push_str(&serde_json::to_string(value)?),
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[derive(serde::Serialize)]
+    struct Message {
+        slot: String,
+        #[serde(flatten)]
+        meta: HashMap<String, serde_json::Value>,
+    }
+
+    fn message(keys: &[&str]) -> Message {
+        let meta = keys
+            .iter()
+            .map(|key| (key.to_string(), serde_json::json!({ "b": [1, 2], "a": key })))
+            .collect();
+        Message { slot: "12".to_string(), meta }
+    }
+
+    #[test]
+    fn test_to_canonical_json() {
+        let keys = ["zeta", "alpha", "mu", "beta", "gamma", "delta"];
+        let mut reversed = keys;
+        reversed.reverse();
+        let canonical = to_canonical_json(&message(&keys)).unwrap();
+        assert_eq!(canonical, to_canonical_json(&message(&keys)).unwrap());
+        assert_eq!(canonical, to_canonical_json(&message(&reversed)).unwrap());
+        assert!(canonical.starts_with(r#"{"alpha":{"a":"alpha","b":[1,2]},"beta":"#));
+        assert!(canonical.ends_with(r#""slot":"12","zeta":{"a":"zeta","b":[1,2]}}"#));
+
+        let value = serde_json::json!({ "b": "line\nbreak", "a": [{ "d": null, "c": 1.5 }] });
+        assert_eq!(
+            to_canonical_json(&value).unwrap(),
+            r#"{"a":[{"c":1.5,"d":null}],"b":"line\nbreak"}"#
+        );
+    }
+}
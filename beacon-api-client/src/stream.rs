@@ -0,0 +1,279 @@
+use crate::Error;
+use serde::de::DeserializeOwned;
+use std::marker::PhantomData;
+
+// Yields the elements of the `data` array in a beacon API response as they arrive,
+// without buffering the entire response body in memory.
+pub struct DataStream<T> {
+    response: reqwest::Response,
+    scanner: Scanner,
+    _phantom: PhantomData<T>,
+}
+
+impl<T: DeserializeOwned> DataStream<T> {
+    pub fn new(response: reqwest::Response) -> Self {
+        Self { response, scanner: Scanner::default(), _phantom: PhantomData }
+    }
+
+    pub async fn next(&mut self) -> Option<Result<T, Error>> {
+        loop {
+            if let Some(result) = self.scanner.next_value() {
+                return Some(result)
+            }
+            if self.scanner.is_done() {
+                return None
+            }
+            match self.response.chunk().await {
+                Ok(Some(chunk)) => self.scanner.push(&chunk),
+                Ok(None) => return self.scanner.finish().map(Err),
+                Err(err) => {
+                    self.scanner.finish();
+                    return Some(Err(err.into()))
+                }
+            }
+        }
+    }
+}
+
+#[derive(Default, PartialEq, Eq)]
+enum Phase {
+    #[default]
+    Preamble,
+    Items,
+    Done,
+}
+
+// Tracks just enough of the JSON structure to find the top-level `data` array and the
+// boundaries of each element inside of it; the elements themselves are left to `serde_json`.
+#[derive(Default)]
+struct Scanner {
+    buffer: Vec<u8>,
+    position: usize,
+    depth: usize,
+    in_string: bool,
+    escaped: bool,
+    string_start: usize,
+    key: Vec<u8>,
+    item_start: Option<usize>,
+    phase: Phase,
+}
+
+impl Scanner {
+    fn push(&mut self, chunk: &[u8]) {
+        self.buffer.extend_from_slice(chunk);
+    }
+
+    fn is_done(&self) -> bool {
+        self.phase == Phase::Done
+    }
+
+    // Signal the end of the input, returning an error if the `data` array was not complete.
+    fn finish(&mut self) -> Option<Error> {
+        if self.is_done() {
+            return None
+        }
+        self.phase = Phase::Done;
+        Some(Error::MissingExpectedData(
+            "response ended before the `data` array was complete".to_string(),
+        ))
+    }
+
+    // Decode the next element if it has been buffered in full. Returns `None` if more input is
+    // needed or the array has ended; scanning stops after the first error.
+    fn next_value<T: DeserializeOwned>(&mut self) -> Option<Result<T, Error>> {
+        let result = match self.next_item() {
+            Ok(item) => serde_json::from_slice(&item?).map_err(Error::from),
+            Err(err) => Err(err),
+        };
+        if result.is_err() {
+            self.phase = Phase::Done;
+        }
+        Some(result)
+    }
+
+    fn next_item(&mut self) -> Result<Option<Vec<u8>>, Error> {
+        while self.position < self.buffer.len() && self.phase != Phase::Done {
+            let i = self.position;
+            let byte = self.buffer[i];
+            self.position += 1;
+
+            if self.in_string {
+                if self.escaped {
+                    self.escaped = false;
+                } else if byte == b'\\' {
+                    self.escaped = true;
+                } else if byte == b'"' {
+                    self.in_string = false;
+                    if self.depth == 1 && self.phase == Phase::Preamble {
+                        // NOTE: the last string seen before an opening bracket is its key
+                        self.key = self.buffer[self.string_start + 1..i].to_vec();
+                    }
+                }
+                continue
+            }
+
+            // NOTE: every element, scalar or not, ends at the next `,` or `]` of the array
+            let in_array = self.phase == Phase::Items && self.depth == 2;
+            match byte {
+                b',' if in_array => {
+                    let start = self.item_start.take().ok_or_else(|| {
+                        Error::MissingExpectedData("empty element in `data` array".to_string())
+                    })?;
+                    let item = self.buffer[start..i].to_vec();
+                    self.compact();
+                    return Ok(Some(item))
+                }
+                b']' if in_array => {
+                    self.depth -= 1;
+                    self.phase = Phase::Done;
+                    if let Some(start) = self.item_start.take() {
+                        return Ok(Some(self.buffer[start..i].to_vec()))
+                    }
+                }
+                b'{' | b'[' => {
+                    if in_array {
+                        self.item_start.get_or_insert(i);
+                    }
+                    self.depth += 1;
+                    if self.phase == Phase::Preamble &&
+                        byte == b'[' &&
+                        self.depth == 2 &&
+                        self.key == b"data"
+                    {
+                        self.phase = Phase::Items;
+                    }
+                }
+                b'}' | b']' => {
+                    self.depth = self.depth.checked_sub(1).ok_or_else(|| {
+                        Error::MissingExpectedData("unbalanced brackets in response".to_string())
+                    })?;
+                }
+                b'"' => {
+                    if in_array {
+                        self.item_start.get_or_insert(i);
+                    }
+                    self.in_string = true;
+                    self.string_start = i;
+                }
+                byte if in_array && !byte.is_ascii_whitespace() => {
+                    self.item_start.get_or_insert(i);
+                }
+                _ => {}
+            }
+        }
+        self.compact();
+        Ok(None)
+    }
+
+    // Drop bytes that have been fully scanned so memory use is bounded by the largest element.
+    fn compact(&mut self) {
+        if self.item_start.is_none() && !self.in_string {
+            self.buffer.drain(..self.position);
+            self.position = 0;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq, Eq, serde::Deserialize)]
+    struct Item {
+        index: String,
+        name: String,
+    }
+
+    fn item(index: usize, name: &str) -> Item {
+        Item { index: index.to_string(), name: name.to_string() }
+    }
+
+    // Feed `body` to a scanner in chunks of the given sizes, cycling through them, as
+    // `DataStream::next` does with the chunks of a response.
+    fn decode<T: DeserializeOwned>(body: &[u8], chunk_sizes: &[usize]) -> Vec<Result<T, Error>> {
+        let mut scanner = Scanner::default();
+        let mut chunks = chunk_sizes.iter().cycle();
+        let mut remaining = body;
+        let mut results = vec![];
+        loop {
+            if let Some(result) = scanner.next_value() {
+                results.push(result);
+                continue
+            }
+            if scanner.is_done() {
+                return results
+            }
+            if remaining.is_empty() {
+                results.extend(scanner.finish().map(Err));
+                return results
+            }
+            let (chunk, rest) = remaining.split_at(remaining.len().min(*chunks.next().unwrap()));
+            scanner.push(chunk);
+            remaining = rest;
+        }
+    }
+
+    #[test]
+    fn test_decode_large_array_in_chunks() {
+        let items = (0..500)
+            .map(|i| format!(r#"{{"index":"{i}","name":"validator-{i}","extra":{{"a":[1,[2]]}}}}"#))
+            .collect::<Vec<_>>();
+        let body = format!(
+            r#"{{"execution_optimistic":false,"finalized":true,"data":[{}]}}"#,
+            items.join(",\n  ")
+        );
+        let expected = (0..500).map(|i| item(i, &format!("validator-{i}"))).collect::<Vec<_>>();
+
+        for chunk_sizes in [&[1][..], &[2], &[7], &[4096], &[1, 13, 2, 97, 5, 31], &[body.len()]] {
+            let decoded = decode::<Item>(body.as_bytes(), chunk_sizes)
+                .into_iter()
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap();
+            assert_eq!(decoded, expected);
+        }
+    }
+
+    #[test]
+    fn test_decode_strings_with_escapes_and_brackets() {
+        let body = r#"{
+            "meta": {"data": [{"index": "9", "name": "nested"}]},
+            "note": "data\"[{",
+            "data": [
+                {"index": "0", "name": "quote \" and ]}"},
+                {"name": "[{\\\"}]", "index": "1"},
+                {"index": "2", "name": "\\"}
+            ]
+        }"#;
+        let expected = vec![item(0, "quote \" and ]}"), item(1, "[{\\\"}]"), item(2, "\\")];
+        for chunk_size in 1..=8 {
+            let decoded = decode::<Item>(body.as_bytes(), &[chunk_size])
+                .into_iter()
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap();
+            assert_eq!(decoded, expected);
+        }
+
+        let decoded = decode::<String>(br#"{"data": ["a,b", "]", "c\"]"]}"#, &[3])
+            .into_iter()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(decoded, vec!["a,b", "]", "c\"]"]);
+    }
+
+    #[test]
+    fn test_decode_malformed_element() {
+        let body = br#"{"data": [{"index": "0", "name": "a"}, {"index": 1, "name": }, {"index": "2", "name": "c"}]}"#;
+        for chunk_size in [1, 5, body.len()] {
+            let results = decode::<Item>(body, &[chunk_size]);
+            assert_eq!(results.len(), 2);
+            assert_eq!(results[0].as_ref().unwrap(), &item(0, "a"));
+            assert!(matches!(results[1], Err(Error::Json(..))));
+        }
+
+        let results = decode::<Item>(br#"{"data": [{"index": "0", "name": "a"},, ]}"#, &[4]);
+        assert!(matches!(results[..], [Ok(..), Err(Error::MissingExpectedData(..))]));
+
+        let results = decode::<Item>(br#"{"data": [{"index": "0", "name": "a"}, {"ind"#, &[4]);
+        assert!(matches!(results[..], [Ok(..), Err(Error::MissingExpectedData(..))]));
+    }
+}
@@ -28,6 +28,9 @@ pub struct CoordinateWithMetadata {
     pub meta: HashMap<String, serde_json::Value>,
 }
 
+// The forks of a chain, as reported by `/eth/v1/config/fork_schedule`, in order of activation.
+pub type ForkSchedule = Vec<ethereum_consensus::phase0::Fork>;
+
 #[derive(Serialize, Deserialize)]
 pub struct DepositContract {
     #[serde(with = "crate::serde::as_str")]
@@ -110,6 +113,11 @@ pub struct RootData {
     pub root: Root,
 }
 
+#[derive(Serialize, Deserialize)]
+pub struct RandaoData {
+    pub randao: Hash32,
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub enum BlockId {
     Head,
@@ -132,6 +140,32 @@ impl fmt::Display for BlockId {
     }
 }
 
+impl FromStr for BlockId {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "finalized" => Ok(BlockId::Finalized),
+            "head" => Ok(BlockId::Head),
+            "genesis" => Ok(BlockId::Genesis),
+            "justified" => Err(format!("{s} is not a supported block identifier")),
+            _ => match s.parse::<Slot>() {
+                Ok(slot) => Ok(Self::Slot(slot)),
+                Err(_) => match try_bytes_from_hex_str(s) {
+                    Ok(root_data) => {
+                        let root = Root::try_from(root_data.as_slice()).map_err(|err| format!("could not parse block identifier by root from the provided argument {s}: {err}"))?;
+                        Ok(Self::Root(root))
+                    }
+                    Err(err) => {
+                        let err = format!("could not parse block identifier by root from the provided argument {s}: {err}");
+                        Err(err)
+                    }
+                },
+            },
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize)]
 enum ExecutionStatus {
     Default,
@@ -184,7 +218,7 @@ impl fmt::Display for ValidatorStatus {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum PublicKeyOrIndex {
     PublicKey(BlsPublicKey),
     Index(ValidatorIndex),
@@ -211,6 +245,41 @@ impl From<BlsPublicKey> for PublicKeyOrIndex {
     }
 }
 
+impl FromStr for PublicKeyOrIndex {
+    type Err = String;
+
+    // A `0x`-prefixed argument is a public key, anything else must be a decimal index.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.starts_with("0x") {
+            let public_key = try_bytes_from_hex_str(s)
+                .map_err(|err| err.to_string())
+                .and_then(|data| {
+                    BlsPublicKey::try_from(data.as_slice()).map_err(|err| err.to_string())
+                })
+                .map_err(|err| format!("could not parse public key from {s}: {err}"))?;
+            Ok(Self::PublicKey(public_key))
+        } else {
+            let index = s
+                .parse::<ValidatorIndex>()
+                .map_err(|err| format!("could not parse validator index from {s}: {err}"))?;
+            Ok(Self::Index(index))
+        }
+    }
+}
+
+impl Serialize for PublicKeyOrIndex {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for PublicKeyOrIndex {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct ValidatorSummary {
     #[serde(with = "crate::serde::as_str")]
@@ -221,6 +290,16 @@ pub struct ValidatorSummary {
     pub validator: Validator,
 }
 
+#[derive(Serialize, Deserialize)]
+pub struct ValidatorIdentity {
+    #[serde(with = "crate::serde::as_str")]
+    pub index: ValidatorIndex,
+    #[serde(rename = "pubkey")]
+    pub public_key: BlsPublicKey,
+    #[serde(with = "crate::serde::as_str")]
+    pub activation_epoch: Epoch,
+}
+
 #[derive(Serialize, Deserialize)]
 pub struct BalanceSummary {
     #[serde(with = "crate::serde::as_str")]
@@ -236,6 +315,23 @@ pub struct CommitteeFilter {
     pub slot: Option<Slot>,
 }
 
+impl CommitteeFilter {
+    // Query parameters for the fields which are set; unset fields are omitted entirely.
+    pub fn to_query(&self) -> Vec<(String, String)> {
+        let mut query = vec![];
+        if let Some(epoch) = self.epoch {
+            query.push(("epoch".to_string(), epoch.to_string()));
+        }
+        if let Some(index) = self.index {
+            query.push(("index".to_string(), index.to_string()));
+        }
+        if let Some(slot) = self.slot {
+            query.push(("slot".to_string(), slot.to_string()));
+        }
+        query
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug)]
 pub struct Committee(#[serde(with = "crate::serde::seq_of_str")] pub Vec<ValidatorIndex>);
 
@@ -281,6 +377,97 @@ impl fmt::Display for BroadcastValidation {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EventTopic {
+    Head,
+    Block,
+    Attestation,
+    VoluntaryExit,
+    FinalizedCheckpoint,
+    ChainReorg,
+    ContributionAndProof,
+}
+
+impl fmt::Display for EventTopic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let printable = match self {
+            Self::Head => "head",
+            Self::Block => "block",
+            Self::Attestation => "attestation",
+            Self::VoluntaryExit => "voluntary_exit",
+            Self::FinalizedCheckpoint => "finalized_checkpoint",
+            Self::ChainReorg => "chain_reorg",
+            Self::ContributionAndProof => "contribution_and_proof",
+        };
+        write!(f, "{printable}")
+    }
+}
+
+impl FromStr for EventTopic {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "head" => Ok(Self::Head),
+            "block" => Ok(Self::Block),
+            "attestation" => Ok(Self::Attestation),
+            "voluntary_exit" => Ok(Self::VoluntaryExit),
+            "finalized_checkpoint" => Ok(Self::FinalizedCheckpoint),
+            "chain_reorg" => Ok(Self::ChainReorg),
+            "contribution_and_proof" => Ok(Self::ContributionAndProof),
+            other => Err(other.to_string()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HeadEvent {
+    #[serde(with = "crate::serde::as_str")]
+    pub slot: Slot,
+    pub block: Root,
+    pub state: Root,
+    pub epoch_transition: bool,
+    pub previous_duty_dependent_root: Root,
+    pub current_duty_dependent_root: Root,
+    #[serde(default, with = "crate::serde::lenient_bool")]
+    pub execution_optimistic: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BlockEvent {
+    #[serde(with = "crate::serde::as_str")]
+    pub slot: Slot,
+    pub block: Root,
+    #[serde(default, with = "crate::serde::lenient_bool")]
+    pub execution_optimistic: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FinalizedCheckpointEvent {
+    pub block: Root,
+    pub state: Root,
+    #[serde(with = "crate::serde::as_str")]
+    pub epoch: Epoch,
+    #[serde(default, with = "crate::serde::lenient_bool")]
+    pub execution_optimistic: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChainReorgEvent {
+    #[serde(with = "crate::serde::as_str")]
+    pub slot: Slot,
+    #[serde(with = "crate::serde::as_str")]
+    pub depth: u64,
+    pub old_head_block: Root,
+    pub new_head_block: Root,
+    pub old_head_state: Root,
+    pub new_head_state: Root,
+    #[serde(with = "crate::serde::as_str")]
+    pub epoch: Epoch,
+    #[serde(default, with = "crate::serde::lenient_bool")]
+    pub execution_optimistic: bool,
+}
+
 pub trait Topic {
     const NAME: &'static str;
 
@@ -403,6 +590,8 @@ pub struct SyncStatus {
     #[serde(with = "crate::serde::as_str")]
     pub sync_distance: usize,
     pub is_syncing: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_optimistic: Option<bool>,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -495,8 +684,8 @@ pub struct BeaconProposerRegistration {
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ValidatorLiveness {
     #[serde(with = "crate::serde::as_str")]
-    index: ValidatorIndex,
-    is_live: bool,
+    pub index: ValidatorIndex,
+    pub is_live: bool,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -517,6 +706,32 @@ pub struct VersionedValue<T> {
     pub meta: HashMap<String, serde_json::Value>,
 }
 
+// Read a boolean flag such as `execution_optimistic` or `finalized` from response metadata,
+// tolerating nodes which quote it.
+fn meta_flag(meta: &HashMap<String, serde_json::Value>, key: &str) -> Option<bool> {
+    meta.get(key).and_then(|value| crate::serde::lenient_bool::deserialize(value).ok())
+}
+
+impl<T> Value<T> {
+    pub fn execution_optimistic(&self) -> Option<bool> {
+        meta_flag(&self.meta, "execution_optimistic")
+    }
+
+    pub fn finalized(&self) -> Option<bool> {
+        meta_flag(&self.meta, "finalized")
+    }
+}
+
+impl<T> VersionedValue<T> {
+    pub fn execution_optimistic(&self) -> Option<bool> {
+        meta_flag(&self.meta, "execution_optimistic")
+    }
+
+    pub fn finalized(&self) -> Option<bool> {
+        meta_flag(&self.meta, "finalized")
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug)]
 #[serde(bound = "T: Serialize + serde::de::DeserializeOwned")]
 #[serde(untagged)]
@@ -524,3 +739,77 @@ pub enum ApiResult<T> {
     Ok(T),
     Err(ApiError),
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // the compressed generator of G1, a valid public key
+    const PUBLIC_KEY: &str = "0x97f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb";
+
+    #[test]
+    fn test_block_id_round_trip() {
+        let root = Root::try_from([7u8; 32].as_ref()).unwrap();
+        let ids = [
+            (BlockId::Head, "head"),
+            (BlockId::Genesis, "genesis"),
+            (BlockId::Finalized, "finalized"),
+            (BlockId::Slot(0), "0"),
+            (BlockId::Slot(9_876_543), "9876543"),
+            (
+                BlockId::Root(root),
+                "0x0707070707070707070707070707070707070707070707070707070707070707",
+            ),
+        ];
+        for (id, expected) in ids {
+            let printed = id.to_string();
+            assert_eq!(printed, expected);
+            let parsed = printed.parse::<BlockId>().unwrap();
+            assert_eq!(parsed.to_string(), expected);
+            assert_eq!(std::mem::discriminant(&parsed), std::mem::discriminant(&id));
+        }
+        assert!("justified".parse::<BlockId>().is_err());
+        assert!("0x0707".parse::<BlockId>().is_err());
+        assert!("latest".parse::<BlockId>().is_err());
+    }
+
+    #[test]
+    fn test_public_key_or_index_from_str() {
+        let Ok(PublicKeyOrIndex::PublicKey(parsed)) = PUBLIC_KEY.parse() else {
+            panic!("expected a public key")
+        };
+        assert_eq!(format!("{parsed:?}"), PUBLIC_KEY);
+
+        assert!(matches!("42".parse(), Ok(PublicKeyOrIndex::Index(42))));
+        assert!(matches!("0".parse(), Ok(PublicKeyOrIndex::Index(0))));
+        // short inputs are only ever read as an index when they are decimal
+        assert!(matches!("1234".parse(), Ok(PublicKeyOrIndex::Index(1234))));
+        for input in ["0x1234", "0x", "1234abcd", "abcd", "-1", ""] {
+            assert!(input.parse::<PublicKeyOrIndex>().is_err(), "{input}");
+        }
+    }
+
+    #[test]
+    fn test_public_key_or_index_serde() {
+        let json = format!(r#"["{PUBLIC_KEY}","42"]"#);
+        let ids: Vec<PublicKeyOrIndex> = serde_json::from_str(&json).unwrap();
+        assert!(matches!(ids[..], [PublicKeyOrIndex::PublicKey(_), PublicKeyOrIndex::Index(42)]));
+        assert_eq!(serde_json::to_string(&ids).unwrap(), json);
+
+        assert!(serde_json::from_str::<PublicKeyOrIndex>("42").is_err());
+        assert!(serde_json::from_str::<PublicKeyOrIndex>(r#""0x1234""#).is_err());
+    }
+
+    #[test]
+    fn test_committee_filter_to_query() {
+        assert!(CommitteeFilter::default().to_query().is_empty());
+
+        let filter = CommitteeFilter { epoch: Some(3), index: Some(0), slot: Some(100) };
+        let expected = [("epoch", "3"), ("index", "0"), ("slot", "100")]
+            .map(|(key, value)| (key.to_string(), value.to_string()));
+        assert_eq!(filter.to_query(), expected);
+
+        let filter = CommitteeFilter { slot: Some(7), ..Default::default() };
+        assert_eq!(filter.to_query(), vec![("slot".to_string(), "7".to_string())]);
+    }
+}
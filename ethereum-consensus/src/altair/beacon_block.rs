@@ -3,6 +3,7 @@ use crate::{
         sync::SyncAggregate, Attestation, AttesterSlashing, Deposit, Eth1Data, ProposerSlashing,
         SignedVoluntaryExit,
     },
+    block_body::BlockBody,
     primitives::{BlsSignature, Bytes32, Root, Slot, ValidatorIndex},
     ssz::prelude::*,
 };
@@ -31,6 +32,55 @@ pub struct BeaconBlockBody<
     pub sync_aggregate: SyncAggregate<SYNC_COMMITTEE_SIZE>,
 }
 
+impl<
+        const MAX_PROPOSER_SLASHINGS: usize,
+        const MAX_VALIDATORS_PER_COMMITTEE: usize,
+        const MAX_ATTESTER_SLASHINGS: usize,
+        const MAX_ATTESTATIONS: usize,
+        const MAX_DEPOSITS: usize,
+        const MAX_VOLUNTARY_EXITS: usize,
+        const SYNC_COMMITTEE_SIZE: usize,
+    >
+    BeaconBlockBody<
+        MAX_PROPOSER_SLASHINGS,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        MAX_ATTESTER_SLASHINGS,
+        MAX_ATTESTATIONS,
+        MAX_DEPOSITS,
+        MAX_VOLUNTARY_EXITS,
+        SYNC_COMMITTEE_SIZE,
+    >
+{
+    pub const MAX_PROPOSER_SLASHINGS: usize = MAX_PROPOSER_SLASHINGS;
+    pub const MAX_VALIDATORS_PER_COMMITTEE: usize = MAX_VALIDATORS_PER_COMMITTEE;
+    pub const MAX_ATTESTER_SLASHINGS: usize = MAX_ATTESTER_SLASHINGS;
+    pub const MAX_ATTESTATIONS: usize = MAX_ATTESTATIONS;
+    pub const MAX_DEPOSITS: usize = MAX_DEPOSITS;
+    pub const MAX_VOLUNTARY_EXITS: usize = MAX_VOLUNTARY_EXITS;
+    pub const SYNC_COMMITTEE_SIZE: usize = SYNC_COMMITTEE_SIZE;
+}
+
+impl<
+        const MAX_PROPOSER_SLASHINGS: usize,
+        const MAX_VALIDATORS_PER_COMMITTEE: usize,
+        const MAX_ATTESTER_SLASHINGS: usize,
+        const MAX_ATTESTATIONS: usize,
+        const MAX_DEPOSITS: usize,
+        const MAX_VOLUNTARY_EXITS: usize,
+        const SYNC_COMMITTEE_SIZE: usize,
+    > BlockBody
+    for BeaconBlockBody<
+        MAX_PROPOSER_SLASHINGS,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        MAX_ATTESTER_SLASHINGS,
+        MAX_ATTESTATIONS,
+        MAX_DEPOSITS,
+        MAX_VOLUNTARY_EXITS,
+        SYNC_COMMITTEE_SIZE,
+    >
+{
+}
+
 #[derive(
     Default, Debug, Clone, SimpleSerialize, PartialEq, Eq, serde::Serialize, serde::Deserialize,
 )]
@@ -60,6 +110,34 @@ pub struct BeaconBlock<
     >,
 }
 
+impl<
+        const MAX_PROPOSER_SLASHINGS: usize,
+        const MAX_VALIDATORS_PER_COMMITTEE: usize,
+        const MAX_ATTESTER_SLASHINGS: usize,
+        const MAX_ATTESTATIONS: usize,
+        const MAX_DEPOSITS: usize,
+        const MAX_VOLUNTARY_EXITS: usize,
+        const SYNC_COMMITTEE_SIZE: usize,
+    >
+    BeaconBlock<
+        MAX_PROPOSER_SLASHINGS,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        MAX_ATTESTER_SLASHINGS,
+        MAX_ATTESTATIONS,
+        MAX_DEPOSITS,
+        MAX_VOLUNTARY_EXITS,
+        SYNC_COMMITTEE_SIZE,
+    >
+{
+    pub const MAX_PROPOSER_SLASHINGS: usize = MAX_PROPOSER_SLASHINGS;
+    pub const MAX_VALIDATORS_PER_COMMITTEE: usize = MAX_VALIDATORS_PER_COMMITTEE;
+    pub const MAX_ATTESTER_SLASHINGS: usize = MAX_ATTESTER_SLASHINGS;
+    pub const MAX_ATTESTATIONS: usize = MAX_ATTESTATIONS;
+    pub const MAX_DEPOSITS: usize = MAX_DEPOSITS;
+    pub const MAX_VOLUNTARY_EXITS: usize = MAX_VOLUNTARY_EXITS;
+    pub const SYNC_COMMITTEE_SIZE: usize = SYNC_COMMITTEE_SIZE;
+}
+
 #[derive(
     Default, Debug, Clone, SimpleSerialize, PartialEq, Eq, serde::Serialize, serde::Deserialize,
 )]
@@ -53,3 +53,92 @@ pub struct BeaconState<
     pub current_sync_committee: SyncCommittee<SYNC_COMMITTEE_SIZE>,
     pub next_sync_committee: SyncCommittee<SYNC_COMMITTEE_SIZE>,
 }
+
+impl<
+        const SLOTS_PER_HISTORICAL_ROOT: usize,
+        const HISTORICAL_ROOTS_LIMIT: usize,
+        const ETH1_DATA_VOTES_BOUND: usize,
+        const VALIDATOR_REGISTRY_LIMIT: usize,
+        const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+        const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+        const MAX_VALIDATORS_PER_COMMITTEE: usize,
+        const SYNC_COMMITTEE_SIZE: usize,
+    >
+    BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+    >
+{
+    // Same as `phase0::BeaconState::hash_tree_root_with_cache`.
+    pub fn hash_tree_root_with_cache(
+        &self,
+        cache: &mut ListRootCache<Validator>,
+    ) -> Result<Node, MerkleizationError> {
+        let field_roots = [
+            self.genesis_time.hash_tree_root()?,
+            self.genesis_validators_root.hash_tree_root()?,
+            self.slot.hash_tree_root()?,
+            self.fork.hash_tree_root()?,
+            self.latest_block_header.hash_tree_root()?,
+            self.block_roots.hash_tree_root()?,
+            self.state_roots.hash_tree_root()?,
+            self.historical_roots.hash_tree_root()?,
+            self.eth1_data.hash_tree_root()?,
+            self.eth1_data_votes.hash_tree_root()?,
+            self.eth1_deposit_index.hash_tree_root()?,
+            cache.hash_tree_root(&self.validators)?,
+            self.balances.hash_tree_root()?,
+            self.randao_mixes.hash_tree_root()?,
+            self.slashings.hash_tree_root()?,
+            self.previous_epoch_participation.hash_tree_root()?,
+            self.current_epoch_participation.hash_tree_root()?,
+            self.justification_bits.hash_tree_root()?,
+            self.previous_justified_checkpoint.hash_tree_root()?,
+            self.current_justified_checkpoint.hash_tree_root()?,
+            self.finalized_checkpoint.hash_tree_root()?,
+            self.inactivity_scores.hash_tree_root()?,
+            self.current_sync_committee.hash_tree_root()?,
+            self.next_sync_committee.hash_tree_root()?,
+        ];
+        Ok(merkleize_chunks(&field_roots))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::altair::minimal::BeaconState;
+
+    #[test]
+    fn test_hash_tree_root_with_cache() {
+        let mut state = BeaconState::default();
+        let mut cache = ListRootCache::new();
+        assert_eq!(
+            state.hash_tree_root_with_cache(&mut cache).unwrap(),
+            state.hash_tree_root().unwrap()
+        );
+
+        for i in 0..10 {
+            state.validators.push(Validator { effective_balance: i, ..Default::default() });
+            state.balances.push(i);
+        }
+        assert_eq!(
+            state.hash_tree_root_with_cache(&mut cache).unwrap(),
+            state.hash_tree_root().unwrap()
+        );
+
+        state.slot = 2;
+        state.validators[7].slashed = true;
+        state.inactivity_scores.push(3);
+        assert_eq!(
+            state.hash_tree_root_with_cache(&mut cache).unwrap(),
+            state.hash_tree_root().unwrap()
+        );
+    }
+}
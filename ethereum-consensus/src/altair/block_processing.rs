@@ -9,13 +9,14 @@ use crate::{
         decrease_balance, get_attesting_indices, get_base_reward, get_beacon_committee,
         get_beacon_proposer_index, get_block_root_at_slot, get_committee_count_per_slot,
         get_current_epoch, get_domain, get_indexed_attestation, get_previous_epoch,
-        get_total_active_balance, get_validator_from_deposit,
+        get_total_active_balance, get_validator_from_deposit, get_whistleblower_reward,
         helpers::{
             add_flag, get_attestation_participation_flag_indices, get_base_reward_per_increment,
             has_flag,
         },
-        increase_balance, is_valid_indexed_attestation, process_block_header, process_eth1_data,
-        process_operations, process_randao,
+        increase_balance, is_valid_indexed_attestation, process_attester_slashing,
+        process_block_header, process_eth1_data, process_operations, process_proposer_slashing,
+        process_randao,
         sync::SyncAggregate,
         Attestation, Bytes32, Gwei,
     },
@@ -26,7 +27,10 @@ use crate::{
     signing::compute_signing_root,
     state_transition::{Context, Result},
 };
-use std::{collections::HashMap, iter::zip};
+use std::{
+    collections::{HashMap, HashSet},
+    iter::zip,
+};
 
 pub fn process_attestation<
     const SLOTS_PER_HISTORICAL_ROOT: usize,
@@ -189,6 +193,42 @@ pub fn add_validator_to_registry<
     state.inactivity_scores.push(0);
 }
 
+// Return the reward for each participant in the sync aggregate of a block, and the reward its
+// proposer earns per participant.
+pub fn get_sync_aggregate_rewards<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+>(
+    state: &BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+    >,
+    context: &Context,
+) -> Result<(Gwei, Gwei)> {
+    let total_active_increments =
+        get_total_active_balance(state, context)? / context.effective_balance_increment;
+    let total_base_rewards =
+        get_base_reward_per_increment(state, context)? * total_active_increments;
+    let max_participant_rewards =
+        total_base_rewards * SYNC_REWARD_WEIGHT / WEIGHT_DENOMINATOR / context.slots_per_epoch;
+    let participant_reward = max_participant_rewards / context.sync_committee_size as u64;
+    let proposer_reward =
+        participant_reward * PROPOSER_WEIGHT / (WEIGHT_DENOMINATOR - PROPOSER_WEIGHT);
+    Ok((participant_reward, proposer_reward))
+}
+
 pub fn process_sync_aggregate<
     const SLOTS_PER_HISTORICAL_ROOT: usize,
     const HISTORICAL_ROOTS_LIMIT: usize,
@@ -243,15 +283,7 @@ pub fn process_sync_aggregate<
     }
 
     // Compute participant and proposer rewards
-    let total_active_increments =
-        get_total_active_balance(state, context)? / context.effective_balance_increment;
-    let total_base_rewards =
-        get_base_reward_per_increment(state, context)? * total_active_increments;
-    let max_participant_rewards =
-        total_base_rewards * SYNC_REWARD_WEIGHT / WEIGHT_DENOMINATOR / context.slots_per_epoch;
-    let participant_reward = max_participant_rewards / context.sync_committee_size as u64;
-    let proposer_reward =
-        participant_reward * PROPOSER_WEIGHT / (WEIGHT_DENOMINATOR - PROPOSER_WEIGHT);
+    let (participant_reward, proposer_reward) = get_sync_aggregate_rewards(state, context)?;
 
     // Apply participant and proposer rewards
     let all_public_keys = state
@@ -322,3 +354,198 @@ pub fn process_block<
     process_sync_aggregate(state, &block.body.sync_aggregate, context)?;
     Ok(())
 }
+
+// Compute the reward `block` earns its proposer from the slashings, attestations and sync
+// aggregate it includes. Rewards and penalties the proposer receives in any other role, e.g. as
+// a member of the sync committee, are not counted. `state` should already be advanced to the
+// block's slot; it is not modified.
+pub fn compute_block_reward<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const MAX_PROPOSER_SLASHINGS: usize,
+    const MAX_ATTESTER_SLASHINGS: usize,
+    const MAX_ATTESTATIONS: usize,
+    const MAX_DEPOSITS: usize,
+    const MAX_VOLUNTARY_EXITS: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+>(
+    state: &BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+    >,
+    block: &BeaconBlock<
+        MAX_PROPOSER_SLASHINGS,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        MAX_ATTESTER_SLASHINGS,
+        MAX_ATTESTATIONS,
+        MAX_DEPOSITS,
+        MAX_VOLUNTARY_EXITS,
+        SYNC_COMMITTEE_SIZE,
+    >,
+    context: &Context,
+) -> Result<Gwei> {
+    let mut state = state.clone();
+    let proposer_index = get_beacon_proposer_index(&state, context)?;
+    let mut reward = 0;
+
+    // NOTE: the proposer is the whistleblower for the slashings included in its block
+    for proposer_slashing in block.body.proposer_slashings.iter() {
+        process_proposer_slashing(&mut state, proposer_slashing, context)?;
+        let slashed_index = proposer_slashing.signed_header_1.message.proposer_index;
+        reward += get_whistleblower_reward(&state, slashed_index, context);
+    }
+    for attester_slashing in block.body.attester_slashings.iter() {
+        let indices_2 =
+            attester_slashing.attestation_2.attesting_indices.iter().collect::<HashSet<_>>();
+        let unslashed_indices = attester_slashing
+            .attestation_1
+            .attesting_indices
+            .iter()
+            .filter(|&index| {
+                indices_2.contains(index) &&
+                    state.validators.get(*index).is_some_and(|validator| !validator.slashed)
+            })
+            .copied()
+            .collect::<Vec<_>>();
+        process_attester_slashing(&mut state, attester_slashing, context)?;
+        for index in unslashed_indices {
+            if state.validators[index].slashed {
+                reward += get_whistleblower_reward(&state, index, context);
+            }
+        }
+    }
+
+    // NOTE: the proposer reward is the only balance change made by `process_attestation`
+    for attestation in block.body.attestations.iter() {
+        let balance = state.balances[proposer_index];
+        process_attestation(&mut state, attestation, context)?;
+        reward += state.balances[proposer_index] - balance;
+    }
+
+    let (_, proposer_reward) = get_sync_aggregate_rewards(&state, context)?;
+    process_sync_aggregate(&mut state, &block.body.sync_aggregate, context)?;
+    let participant_count =
+        block.body.sync_aggregate.sync_committee_bits.iter().filter(|bit| **bit).count();
+    reward += proposer_reward * participant_count as u64;
+
+    Ok(reward)
+}
+
+#[cfg(all(test, feature = "test-utils"))]
+mod tests {
+    use super::*;
+    use crate::{
+        altair::{minimal, process_slots},
+        crypto::{aggregate, test_keys},
+        phase0::{BeaconBlockHeader, ProposerSlashing, SignedBeaconBlockHeader},
+        primitives::Root,
+        signing::sign_with_domain,
+        ssz::prelude::*,
+        state_transition::minimal::GenesisBuilder,
+        types, Fork,
+    };
+
+    #[test]
+    fn test_compute_block_reward() {
+        let context = Context::for_minimal();
+        let validator_count = context.min_genesis_active_validator_count;
+        let keys = test_keys(validator_count);
+        let public_keys = keys.iter().map(|key| key.public_key()).collect::<Vec<_>>();
+        let builder = GenesisBuilder::new(validator_count, context).with_fork(Fork::Altair);
+        let types::BeaconState::Altair(mut state) = builder.build().unwrap() else {
+            panic!("expected an altair state")
+        };
+        let context = builder.context;
+        process_slots(&mut state, 1, &context).unwrap();
+        let proposer_index = get_beacon_proposer_index(&state, &context).unwrap();
+
+        let slashed_index = (proposer_index + 1) % validator_count;
+        let domain = get_domain(&state, DomainType::BeaconProposer, None, &context).unwrap();
+        let signed_header = |byte: u8| {
+            let message = BeaconBlockHeader {
+                slot: 1,
+                proposer_index: slashed_index,
+                body_root: Root::try_from([byte; 32].as_ref()).unwrap(),
+                ..Default::default()
+            };
+            let signature = sign_with_domain(&message, &keys[slashed_index], domain).unwrap();
+            SignedBeaconBlockHeader { message, signature }
+        };
+        let proposer_slashing = ProposerSlashing {
+            signed_header_1: signed_header(1),
+            signed_header_2: signed_header(2),
+        };
+
+        // seat the proposer in the sync committee so its own sync rewards can be told apart
+        state.current_sync_committee.public_keys[0] = public_keys[proposer_index].clone();
+        let seats = state
+            .current_sync_committee
+            .public_keys
+            .iter()
+            .filter(|&public_key| public_key == &public_keys[proposer_index])
+            .count() as u64;
+
+        let epoch = get_current_epoch(&state, &context);
+        let domain = get_domain(&state, DomainType::Randao, Some(epoch), &context).unwrap();
+        let randao_reveal = sign_with_domain(&epoch, &keys[proposer_index], domain).unwrap();
+        let domain = get_domain(&state, DomainType::SyncCommittee, Some(0), &context).unwrap();
+        let signing_root =
+            compute_signing_root(get_block_root_at_slot(&state, 0).unwrap(), domain).unwrap();
+        let whistleblower_reward = get_whistleblower_reward(&state, slashed_index, &context);
+        let (participant_reward, proposer_reward) =
+            get_sync_aggregate_rewards(&state, &context).unwrap();
+
+        for proposer_participates in [true, false] {
+            let mut sync_committee_bits = Bitvector::default();
+            let mut signatures = vec![];
+            for (i, public_key) in state.current_sync_committee.public_keys.iter().enumerate() {
+                if !proposer_participates && public_key == &public_keys[proposer_index] {
+                    continue
+                }
+                let index = public_keys.iter().position(|key| key == public_key).unwrap();
+                signatures.push(keys[index].sign(signing_root.as_ref()));
+                sync_committee_bits.set(i, true);
+            }
+            let participant_count = signatures.len() as u64;
+
+            let mut block = minimal::BeaconBlock {
+                slot: 1,
+                proposer_index,
+                parent_root: state.latest_block_header.hash_tree_root().unwrap(),
+                ..Default::default()
+            };
+            block.body.randao_reveal = randao_reveal.clone();
+            block.body.proposer_slashings.push(proposer_slashing.clone());
+            block.body.sync_aggregate = minimal::SyncAggregate {
+                sync_committee_bits,
+                sync_committee_signature: aggregate(&signatures).unwrap(),
+            };
+
+            let reward = compute_block_reward(&state, &block, &context).unwrap();
+            assert_eq!(reward, whistleblower_reward + proposer_reward * participant_count);
+
+            // the proposer's balance also moves with its own participation in the sync committee
+            let mut post_state = state.clone();
+            let balance = post_state.balances[proposer_index];
+            process_block(&mut post_state, &block, &context).unwrap();
+            let balance_change = post_state.balances[proposer_index] as i64 - balance as i64;
+            let own_sync_reward = (seats * participant_reward) as i64;
+            if proposer_participates {
+                assert_eq!(balance_change, reward as i64 + own_sync_reward);
+            } else {
+                assert_eq!(balance_change, reward as i64 - own_sync_reward);
+            }
+        }
+    }
+}
@@ -338,3 +338,59 @@ pub fn process_epoch<
     process_sync_committee_updates(state, context)?;
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        altair::minimal::{BeaconState, SyncCommittee},
+        crypto::SecretKey,
+        ssz::prelude::*,
+        test_utils::push_active_validators,
+    };
+
+    const VALIDATOR_COUNT: usize = 8;
+
+    fn state_with_validators(context: &Context) -> BeaconState {
+        let mut rng = rand::thread_rng();
+        let mut state = BeaconState::default();
+        push_active_validators(
+            &mut state.validators,
+            &mut state.balances,
+            VALIDATOR_COUNT,
+            context,
+        );
+        for validator in state.validators.iter_mut() {
+            validator.public_key = SecretKey::random(&mut rng).unwrap().public_key();
+        }
+        state
+    }
+
+    #[test]
+    fn test_sync_committee_rotation_at_period_boundary() {
+        let context = Context::for_minimal();
+        let mut state = state_with_validators(&context);
+        let marker = SyncCommittee {
+            public_keys: Vector::try_from(vec![
+                state.validators[0].public_key.clone();
+                context.sync_committee_size
+            ])
+            .unwrap(),
+            aggregate_public_key: state.validators[0].public_key.clone(),
+        };
+        state.next_sync_committee = marker.clone();
+
+        // the epoch before the last one in the period must not rotate
+        let boundary_epoch = context.epochs_per_sync_committee_period - 1;
+        state.slot = (boundary_epoch - 1) * context.slots_per_epoch;
+        process_sync_committee_updates(&mut state, &context).unwrap();
+        assert_eq!(state.current_sync_committee, SyncCommittee::default());
+        assert_eq!(state.next_sync_committee, marker);
+
+        state.slot = boundary_epoch * context.slots_per_epoch;
+        let expected_next_sync_committee = get_next_sync_committee(&state, &context).unwrap();
+        process_sync_committee_updates(&mut state, &context).unwrap();
+        assert_eq!(state.current_sync_committee, marker);
+        assert_eq!(state.next_sync_committee, expected_next_sync_committee);
+    }
+}
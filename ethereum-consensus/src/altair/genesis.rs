@@ -4,6 +4,7 @@ use crate::{
         process_deposit, BeaconBlockHeader, Deposit, DepositData, Eth1Data, Fork,
         DEPOSIT_DATA_LIST_BOUND,
     },
+    block_body::BlockBody,
     primitives::{Gwei, Hash32, GENESIS_EPOCH},
     ssz::prelude::*,
     state_transition::{Context, Result},
@@ -59,7 +60,7 @@ pub fn initialize_beacon_state_from_eth1<
         MAX_VOLUNTARY_EXITS,
         SYNC_COMMITTEE_SIZE,
     >::default();
-    let body_root = latest_block_body.hash_tree_root()?;
+    let body_root = latest_block_body.body_root()?;
     let latest_block_header = BeaconBlockHeader { body_root, ..Default::default() };
     let randao_mixes = Vector::try_from(
         std::iter::repeat_n(eth1_block_hash, context.epochs_per_historical_vector as usize)
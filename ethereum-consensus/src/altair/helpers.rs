@@ -148,6 +148,37 @@ pub fn get_base_reward_per_increment<
         get_total_active_balance(state, context)?.integer_sqrt())
 }
 
+// Estimate the per-epoch attestation reward for a validator with `effective_balance` that
+// attests timely to source, target and head, given the total active balance of the network
+// and the fraction of that balance (in `[0, 1]`) that also participates.
+// `total_active_balance` is taken as an argument because the base reward scales with its
+// inverse square root, so no single default fits both mainnet and a devnet; callers can pass
+// `get_total_active_balance` of a recent state.
+// NOTE: this is a planning utility and not part of the consensus specs.
+pub fn simulate_attestation_rewards(
+    effective_balance: Gwei,
+    total_active_balance: Gwei,
+    participation_rate: f64,
+    context: &Context,
+) -> Gwei {
+    let increment = context.effective_balance_increment;
+    let total_active_balance = Gwei::max(increment, total_active_balance);
+    let base_reward_per_increment =
+        increment * context.base_reward_factor / total_active_balance.integer_sqrt();
+    let base_reward = effective_balance / increment * base_reward_per_increment;
+
+    let active_increments = total_active_balance / increment;
+    let participation_rate = participation_rate.clamp(0.0, 1.0);
+    let participating_increments = (active_increments as f64 * participation_rate) as u64;
+    PARTICIPATION_FLAG_WEIGHTS
+        .iter()
+        .map(|weight| {
+            base_reward * weight * participating_increments /
+                (active_increments * WEIGHT_DENOMINATOR)
+        })
+        .sum()
+}
+
 // Return the set of validator indices that are both active and unslashed for the given
 // ``flag_index`` and ``epoch``
 pub fn get_unslashed_participating_indices<
@@ -261,6 +292,37 @@ pub fn get_attestation_participation_flag_indices<
     Ok(participation_flag_indices)
 }
 
+// Return the ``ParticipationFlags`` earned by an attestation with ``data`` included
+// ``inclusion_delay`` slots after its slot.
+pub fn get_attestation_participation_flags<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+>(
+    state: &BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+    >,
+    data: &AttestationData,
+    inclusion_delay: u64,
+    context: &Context,
+) -> Result<ParticipationFlags> {
+    let flag_indices =
+        get_attestation_participation_flag_indices(state, data, inclusion_delay, context)?;
+    Ok(flag_indices.into_iter().fold(0, add_flag))
+}
+
 // Return the deltas for a given ``flag_index`` by scanning through the participation flags.
 pub fn get_flag_index_deltas<
     const SLOTS_PER_HISTORICAL_ROOT: usize,
@@ -357,6 +419,33 @@ pub fn get_inactivity_penalty_deltas<
     Ok((rewards, penalties))
 }
 
+// Return the reward for reporting the slashing of ``slashed_index``
+pub fn get_whistleblower_reward<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+>(
+    state: &BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+    >,
+    slashed_index: ValidatorIndex,
+    context: &Context,
+) -> Gwei {
+    state.validators[slashed_index].effective_balance / context.whistleblower_reward_quotient
+}
+
 pub fn slash_validator<
     const SLOTS_PER_HISTORICAL_ROOT: usize,
     const HISTORICAL_ROOTS_LIMIT: usize,
@@ -401,11 +490,95 @@ pub fn slash_validator<
 
     let whistleblower_index = whistleblower_index.unwrap_or(proposer_index);
 
-    let whistleblower_reward =
-        state.validators[slashed_index].effective_balance / context.whistleblower_reward_quotient;
+    let whistleblower_reward = get_whistleblower_reward(state, slashed_index, context);
     let proposer_reward_scaling_factor = PROPOSER_WEIGHT / WEIGHT_DENOMINATOR;
     let proposer_reward = whistleblower_reward * proposer_reward_scaling_factor;
     increase_balance(state, proposer_index, proposer_reward);
     increase_balance(state, whistleblower_index, whistleblower_reward - proposer_reward);
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        altair::minimal::BeaconState, crypto::SecretKey, test_utils::push_active_validators,
+    };
+
+    #[test]
+    fn test_next_sync_committee_indices_match_committee() {
+        let context = Context::for_minimal();
+        let mut rng = rand::thread_rng();
+        let mut state = BeaconState::default();
+        push_active_validators(&mut state.validators, &mut state.balances, 16, &context);
+        for validator in state.validators.iter_mut() {
+            validator.public_key = SecretKey::random(&mut rng).unwrap().public_key();
+        }
+        // the last validator has exited and must never be selected
+        state.validators[15].exit_epoch = 0;
+
+        let indices = get_next_sync_committee_indices(&state, &context).unwrap();
+        assert_eq!(indices.len(), context.sync_committee_size);
+        assert!(!indices.contains(&15));
+
+        let sync_committee = get_next_sync_committee(&state, &context).unwrap();
+        for (index, public_key) in indices.iter().zip(sync_committee.public_keys.iter()) {
+            assert_eq!(&state.validators[*index].public_key, public_key);
+        }
+    }
+
+    #[test]
+    fn test_attestation_participation_flags_timing() {
+        let context = Context::for_minimal();
+        let data = AttestationData::default();
+        let flags_at = |inclusion_delay: u64| {
+            let mut state = BeaconState::default();
+            state.slot = data.slot + inclusion_delay;
+            get_attestation_participation_flags(&state, &data, inclusion_delay, &context).unwrap()
+        };
+        let source = add_flag(0, TIMELY_SOURCE_FLAG_INDEX);
+        let target = add_flag(0, TIMELY_TARGET_FLAG_INDEX);
+        let head = add_flag(0, TIMELY_HEAD_FLAG_INDEX);
+
+        assert_eq!(context.min_attestation_inclusion_delay, 1);
+        assert_eq!(flags_at(1), source | target | head);
+        let sqrt_slots_per_epoch = context.slots_per_epoch.integer_sqrt();
+        assert_eq!(flags_at(sqrt_slots_per_epoch), source | target);
+        assert_eq!(flags_at(sqrt_slots_per_epoch + 1), target);
+        assert_eq!(flags_at(context.slots_per_epoch), target);
+        assert_eq!(flags_at(context.slots_per_epoch + 1), 0);
+    }
+
+    #[test]
+    fn test_simulate_attestation_rewards() {
+        let context = Context::for_minimal();
+        let mut state = BeaconState::default();
+        state.slot = 2 * context.slots_per_epoch;
+        let all_flags =
+            [TIMELY_SOURCE_FLAG_INDEX, TIMELY_TARGET_FLAG_INDEX, TIMELY_HEAD_FLAG_INDEX]
+                .into_iter()
+                .fold(0, add_flag);
+        // 6 of 8 equal validators participate
+        push_active_validators(&mut state.validators, &mut state.balances, 8, &context);
+        for i in 0..8 {
+            let flags = if i < 6 { all_flags } else { 0 };
+            state.previous_epoch_participation.push(flags);
+        }
+
+        let mut actual_reward = 0;
+        for flag_index in 0..PARTICIPATION_FLAG_WEIGHTS.len() {
+            let (rewards, _) = get_flag_index_deltas(&state, flag_index, &context).unwrap();
+            actual_reward += rewards[0];
+        }
+
+        let total_active_balance = get_total_active_balance(&state, &context).unwrap();
+        let estimate = simulate_attestation_rewards(
+            context.max_effective_balance,
+            total_active_balance,
+            0.75,
+            &context,
+        );
+        assert!(estimate > 0);
+        assert_eq!(estimate, actual_reward);
+    }
+}
@@ -4,7 +4,8 @@ pub use crate::{
         beacon_block::{BeaconBlock, BeaconBlockBody, SignedBeaconBlock},
         beacon_state::BeaconState,
         block_processing::{
-            add_validator_to_registry, process_attestation, process_block, process_sync_aggregate,
+            add_validator_to_registry, compute_block_reward, get_sync_aggregate_rewards,
+            process_attestation, process_block, process_sync_aggregate,
         },
         constants::{
             PARTICIPATION_FLAG_WEIGHTS, PROPOSER_WEIGHT, SYNC_COMMITTEE_SUBNET_COUNT,
@@ -20,10 +21,11 @@ pub use crate::{
         fork::upgrade_to_altair,
         genesis::initialize_beacon_state_from_eth1,
         helpers::{
-            add_flag, get_attestation_participation_flag_indices, get_base_reward_per_increment,
+            add_flag, get_attestation_participation_flag_indices,
+            get_attestation_participation_flags, get_base_reward_per_increment,
             get_flag_index_deltas, get_inactivity_penalty_deltas, get_next_sync_committee,
-            get_next_sync_committee_indices, get_unslashed_participating_indices, has_flag,
-            slash_validator,
+            get_next_sync_committee_indices, get_unslashed_participating_indices,
+            get_whistleblower_reward, has_flag, simulate_attestation_rewards, slash_validator,
         },
         light_client::{
             LightClientBootstrap, LightClientFinalityUpdate, LightClientHeader,
@@ -45,21 +47,24 @@ pub use crate::{
         block_processing::{get_validator_from_deposit, xor},
         constants::{
             BASE_REWARDS_PER_EPOCH, DEPOSIT_CONTRACT_TREE_DEPTH, DEPOSIT_DATA_LIST_BOUND,
-            JUSTIFICATION_BITS_LENGTH,
+            JUSTIFICATION_BITS_LENGTH, TARGET_AGGREGATORS_PER_COMMITTEE,
         },
         helpers::{
-            compute_activation_exit_epoch, compute_committee, compute_domain,
-            compute_epoch_at_slot, compute_fork_data_root, compute_fork_digest,
+            aggregate_attestations, compute_activation_exit_epoch, compute_committee,
+            compute_domain, compute_epoch_at_slot, compute_fork_data_root, compute_fork_digest,
             compute_shuffled_index, compute_shuffled_indices, compute_start_slot_at_epoch,
             is_active_validator, is_eligible_for_activation_queue, is_slashable_attestation_data,
-            is_slashable_validator,
+            is_slashable_validator, verify_merkle_branch,
         },
         operations::{
             Attestation, AttestationData, AttesterSlashing, Checkpoint, Deposit, DepositData,
             DepositMessage, Eth1Data, IndexedAttestation, PendingAttestation, ProposerSlashing,
             SignedVoluntaryExit, VoluntaryExit,
         },
-        validator::{AggregateAndProof, Eth1Block, SignedAggregateAndProof, Validator},
+        validator::{
+            get_eth1_data, is_candidate_block, AggregateAndProof, Eth1Block,
+            SignedAggregateAndProof, Validator,
+        },
     },
     primitives::*,
     signing::*,
@@ -69,7 +74,7 @@ use crate::{
     crypto::{fast_aggregate_verify, hash},
     ssz::prelude::*,
 };
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 pub fn process_proposer_slashing<
     const SLOTS_PER_HISTORICAL_ROOT: usize,
     const HISTORICAL_ROOTS_LIMIT: usize,
@@ -271,7 +276,7 @@ pub fn process_deposit<
     let depth = DEPOSIT_CONTRACT_TREE_DEPTH + 1;
     let index = state.eth1_deposit_index as usize;
     let root = state.eth1_data.deposit_root;
-    if is_valid_merkle_branch(leaf, branch, depth, index, root).is_err() {
+    if !verify_merkle_branch(leaf, branch, depth, index, root) {
         return Err(invalid_operation_error(InvalidOperation::Deposit(
             InvalidDeposit::InvalidProof { leaf, branch: branch.to_vec(), depth, index, root },
         )));
@@ -351,6 +356,51 @@ pub fn process_voluntary_exit<
         })?;
     initiate_validator_exit(state, voluntary_exit.validator_index, context)
 }
+pub fn verify_proposer_index<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+    const MAX_PROPOSER_SLASHINGS: usize,
+    const MAX_ATTESTER_SLASHINGS: usize,
+    const MAX_ATTESTATIONS: usize,
+    const MAX_DEPOSITS: usize,
+    const MAX_VOLUNTARY_EXITS: usize,
+>(
+    state: &BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+    >,
+    block: &BeaconBlock<
+        MAX_PROPOSER_SLASHINGS,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        MAX_ATTESTER_SLASHINGS,
+        MAX_ATTESTATIONS,
+        MAX_DEPOSITS,
+        MAX_VOLUNTARY_EXITS,
+        SYNC_COMMITTEE_SIZE,
+    >,
+    context: &Context,
+) -> Result<()> {
+    let proposer_index = get_beacon_proposer_index(state, context)?;
+    if block.proposer_index != proposer_index {
+        return Err(invalid_header_error(InvalidBeaconBlockHeader::ProposerIndexMismatch {
+            block_proposer_index: block.proposer_index,
+            proposer_index,
+        }));
+    }
+    Ok(())
+}
 pub fn process_block_header<
     const SLOTS_PER_HISTORICAL_ROOT: usize,
     const HISTORICAL_ROOTS_LIMIT: usize,
@@ -399,13 +449,7 @@ pub fn process_block_header<
             latest_block_header_slot: state.latest_block_header.slot,
         }));
     }
-    let proposer_index = get_beacon_proposer_index(state, context)?;
-    if block.proposer_index != proposer_index {
-        return Err(invalid_header_error(InvalidBeaconBlockHeader::ProposerIndexMismatch {
-            block_proposer_index: block.proposer_index,
-            proposer_index,
-        }));
-    }
+    verify_proposer_index(state, block, context)?;
     let expected_parent_root = state.latest_block_header.hash_tree_root()?;
     if block.parent_root != expected_parent_root {
         return Err(invalid_header_error(InvalidBeaconBlockHeader::ParentBlockRootMismatch {
@@ -422,7 +466,9 @@ pub fn process_block_header<
     };
     let proposer = &state.validators[block.proposer_index];
     if proposer.slashed {
-        return Err(invalid_header_error(InvalidBeaconBlockHeader::ProposerSlashed(proposer_index)));
+        return Err(invalid_header_error(InvalidBeaconBlockHeader::ProposerSlashed(
+            block.proposer_index,
+        )));
     }
     Ok(())
 }
@@ -1122,6 +1168,39 @@ pub fn verify_block_signature<
     let public_key = &proposer.public_key;
     verify_signed_data(&signed_block.message, &signed_block.signature, public_key, domain)
 }
+pub fn verify_block_header_signature<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+>(
+    state: &BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+    >,
+    signed_header: &SignedBeaconBlockHeader,
+    context: &Context,
+) -> Result<()> {
+    let proposer_index = signed_header.message.proposer_index;
+    let proposer = state
+        .validators
+        .get(proposer_index)
+        .ok_or(Error::OutOfBounds { requested: proposer_index, bound: state.validators.len() })?;
+    let epoch = compute_epoch_at_slot(signed_header.message.slot, context);
+    let domain = get_domain(state, DomainType::BeaconProposer, Some(epoch), context)?;
+    let public_key = &proposer.public_key;
+    verify_signed_data(&signed_header.message, &signed_header.signature, public_key, domain)
+}
 pub fn get_domain<
     const SLOTS_PER_HISTORICAL_ROOT: usize,
     const HISTORICAL_ROOTS_LIMIT: usize,
@@ -1828,6 +1907,108 @@ pub fn get_eligible_validator_indices<
         }
     })
 }
+pub fn build_pubkey_index<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+>(
+    state: &BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+    >,
+) -> HashMap<BlsPublicKey, ValidatorIndex> {
+    let mut index = HashMap::with_capacity(state.validators.len());
+    update_pubkey_index(&mut index, state);
+    index
+}
+pub fn update_pubkey_index<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+>(
+    index: &mut HashMap<BlsPublicKey, ValidatorIndex>,
+    state: &BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+    >,
+) {
+    for (i, validator) in state.validators.iter().enumerate().skip(index.len()) {
+        index.insert(validator.public_key.clone(), i);
+    }
+}
+pub fn build_validator_pubkeys<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+>(
+    state: &BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+    >,
+) -> HashMap<ValidatorIndex, BlsPublicKey> {
+    let mut pubkeys = HashMap::with_capacity(state.validators.len());
+    update_validator_pubkeys(&mut pubkeys, state);
+    pubkeys
+}
+pub fn update_validator_pubkeys<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+>(
+    pubkeys: &mut HashMap<ValidatorIndex, BlsPublicKey>,
+    state: &BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+    >,
+) {
+    for (i, validator) in state.validators.iter().enumerate().skip(pubkeys.len()) {
+        pubkeys.insert(i, validator.public_key.clone());
+    }
+}
 pub fn process_slots<
     const SLOTS_PER_HISTORICAL_ROOT: usize,
     const HISTORICAL_ROOTS_LIMIT: usize,
@@ -1896,6 +2077,117 @@ pub fn process_slot<
     state.block_roots[root_index as usize] = previous_block_root;
     Ok(())
 }
+pub fn process_slots_with_cache<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+>(
+    state: &mut BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+    >,
+    slot: Slot,
+    cache: &mut ListRootCache<Validator>,
+    context: &Context,
+) -> Result<()> {
+    if state.slot >= slot {
+        return Err(Error::TransitionToPreviousSlot { requested: slot, current: state.slot });
+    }
+    while state.slot < slot {
+        process_slot_with_cache(state, cache, context)?;
+        if (state.slot + 1) % context.slots_per_epoch == 0 {
+            process_epoch(state, context)?;
+        }
+        state.slot += 1;
+    }
+    Ok(())
+}
+pub fn process_slot_with_cache<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+>(
+    state: &mut BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+    >,
+    cache: &mut ListRootCache<Validator>,
+    context: &Context,
+) -> Result<()> {
+    let previous_state_root = state.hash_tree_root_with_cache(cache)?;
+    let root_index = state.slot % context.slots_per_historical_root;
+    state.state_roots[root_index as usize] = previous_state_root;
+    if state.latest_block_header.state_root == Root::default() {
+        state.latest_block_header.state_root = previous_state_root;
+    }
+    let previous_block_root = state.latest_block_header.hash_tree_root()?;
+    let root_index = state.slot % context.slots_per_historical_root;
+    state.block_roots[root_index as usize] = previous_block_root;
+    Ok(())
+}
+pub fn validate_state_root<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+    const MAX_PROPOSER_SLASHINGS: usize,
+    const MAX_ATTESTER_SLASHINGS: usize,
+    const MAX_ATTESTATIONS: usize,
+    const MAX_DEPOSITS: usize,
+    const MAX_VOLUNTARY_EXITS: usize,
+>(
+    state: &BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+    >,
+    block: &BeaconBlock<
+        MAX_PROPOSER_SLASHINGS,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        MAX_ATTESTER_SLASHINGS,
+        MAX_ATTESTATIONS,
+        MAX_DEPOSITS,
+        MAX_VOLUNTARY_EXITS,
+        SYNC_COMMITTEE_SIZE,
+    >,
+) -> Result<()> {
+    let root = state.hash_tree_root()?;
+    if block.state_root != root {
+        return Err(Error::StateRootMismatch { expected: block.state_root, got: root });
+    }
+    Ok(())
+}
 pub fn state_transition_block_in_slot<
     const SLOTS_PER_HISTORICAL_ROOT: usize,
     const HISTORICAL_ROOTS_LIMIT: usize,
@@ -1937,16 +2229,16 @@ pub fn state_transition_block_in_slot<
         Validation::Enabled => true,
         Validation::Disabled => false,
     };
+    let block = &signed_block.message;
     if validate_result {
+        verify_proposer_index(state, block, context)?;
         verify_block_signature(state, signed_block, context)?;
     }
-    let block = &signed_block.message;
     process_block(state, block, context)?;
-    if validate_result && block.state_root != state.hash_tree_root()? {
-        Err(Error::InvalidStateRoot)
-    } else {
-        Ok(())
+    if validate_result {
+        validate_state_root(state, block)?;
     }
+    Ok(())
 }
 pub fn state_transition<
     const SLOTS_PER_HISTORICAL_ROOT: usize,
@@ -1988,3 +2280,247 @@ pub fn state_transition<
     process_slots(state, signed_block.message.slot, context)?;
     state_transition_block_in_slot(state, signed_block, validation, context)
 }
+pub fn compute_post_state_root<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+    const MAX_PROPOSER_SLASHINGS: usize,
+    const MAX_ATTESTER_SLASHINGS: usize,
+    const MAX_ATTESTATIONS: usize,
+    const MAX_DEPOSITS: usize,
+    const MAX_VOLUNTARY_EXITS: usize,
+>(
+    pre_state: &BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+    >,
+    signed_block: &SignedBeaconBlock<
+        MAX_PROPOSER_SLASHINGS,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        MAX_ATTESTER_SLASHINGS,
+        MAX_ATTESTATIONS,
+        MAX_DEPOSITS,
+        MAX_VOLUNTARY_EXITS,
+        SYNC_COMMITTEE_SIZE,
+    >,
+    context: &Context,
+) -> Result<Root> {
+    let mut state = pre_state.clone();
+    let block = &signed_block.message;
+    process_slots(&mut state, block.slot, context)?;
+    verify_proposer_index(&state, block, context)?;
+    verify_block_signature(&state, signed_block, context)?;
+    process_block(&mut state, block, context)?;
+    Ok(state.hash_tree_root()?)
+}
+pub fn compute_time_at_slot<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+>(
+    state: &BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+    >,
+    slot: Slot,
+    context: &Context,
+) -> u64 {
+    state.genesis_time + slot * context.seconds_per_slot
+}
+pub fn voting_period_start_time<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+>(
+    state: &BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+    >,
+    context: &Context,
+) -> u64 {
+    let slots_per_voting_period = context.epochs_per_eth1_voting_period * context.slots_per_epoch;
+    let eth1_voting_period_start_slot = state.slot - state.slot % slots_per_voting_period;
+    compute_time_at_slot(state, eth1_voting_period_start_slot, context)
+}
+pub fn get_eth1_vote<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+>(
+    state: &BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+    >,
+    eth1_chain: &[Eth1Block],
+    context: &Context,
+) -> Result<Eth1Data> {
+    let period_start = voting_period_start_time(state, context);
+    let mut votes_to_consider = vec![];
+    for block in eth1_chain {
+        if !is_candidate_block(block, period_start, context) {
+            continue;
+        }
+        let eth1_data = get_eth1_data(block)?;
+        if eth1_data.deposit_count >= state.eth1_data.deposit_count {
+            votes_to_consider.push(eth1_data);
+        }
+    }
+    let valid_votes = state
+        .eth1_data_votes
+        .iter()
+        .filter(|vote| votes_to_consider.contains(vote))
+        .collect::<Vec<_>>();
+    let mut selected_vote: Option<(&Eth1Data, usize)> = None;
+    for &vote in &valid_votes {
+        let count = valid_votes.iter().filter(|&&other| other == vote).count();
+        if selected_vote.is_none_or(|(_, selected_count)| count > selected_count) {
+            selected_vote = Some((vote, count));
+        }
+    }
+    let vote = match selected_vote {
+        Some((vote, _)) => vote,
+        None => votes_to_consider.last().unwrap_or(&state.eth1_data),
+    };
+    Ok(vote.clone())
+}
+pub fn is_aggregator<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+>(
+    state: &BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+    >,
+    slot: Slot,
+    index: CommitteeIndex,
+    slot_signature: &BlsSignature,
+    context: &Context,
+) -> Result<bool> {
+    let committee = get_beacon_committee(state, slot, index, context)?;
+    let modulo = usize::max(1, committee.len() / TARGET_AGGREGATORS_PER_COMMITTEE) as u64;
+    let digest: [u8; 8] = hash(slot_signature.as_ref()).as_ref()[..8].try_into().unwrap();
+    Ok(u64::from_le_bytes(digest) % modulo == 0)
+}
+pub fn verify_aggregate_and_proof<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+>(
+    state: &BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+    >,
+    signed_aggregate_and_proof: &SignedAggregateAndProof<MAX_VALIDATORS_PER_COMMITTEE>,
+    context: &Context,
+) -> Result<()> {
+    let aggregate_and_proof = &signed_aggregate_and_proof.message;
+    let aggregator_index = aggregate_and_proof.aggregator_index;
+    let aggregate = &aggregate_and_proof.aggregate;
+    let data = &aggregate.data;
+    let committee = get_beacon_committee(state, data.slot, data.index, context)?;
+    if !committee.contains(&aggregator_index) {
+        return Err(InvalidAggregateAndProof::AggregatorNotInCommittee(aggregator_index).into());
+    }
+    let selection_proof = &aggregate_and_proof.selection_proof;
+    if !is_aggregator(state, data.slot, data.index, selection_proof, context)? {
+        return Err(InvalidAggregateAndProof::NotAggregator(aggregator_index).into());
+    }
+    let public_key = &state.validators[aggregator_index].public_key;
+    let epoch = compute_epoch_at_slot(data.slot, context);
+    let domain = get_domain(state, DomainType::SelectionProof, Some(epoch), context)?;
+    verify_signed_data(&data.slot, selection_proof, public_key, domain)
+        .map_err(|_| InvalidAggregateAndProof::InvalidSelectionProof(aggregator_index))?;
+    let domain = get_domain(state, DomainType::AggregateAndProof, Some(epoch), context)?;
+    verify_signed_data(
+        aggregate_and_proof,
+        &signed_aggregate_and_proof.signature,
+        public_key,
+        domain,
+    )
+    .map_err(|_| InvalidAggregateAndProof::InvalidSignature(aggregator_index))?;
+    if aggregate.aggregation_bits.len() != committee.len() {
+        return Err(InvalidAggregateAndProof::AggregationBitsLengthMismatch {
+            expected: committee.len(),
+            provided: aggregate.aggregation_bits.len(),
+        }
+        .into());
+    }
+    let mut public_keys = vec![];
+    for (i, &index) in committee.iter().enumerate() {
+        if aggregate.aggregation_bits[i] {
+            public_keys.push(&state.validators[index].public_key);
+        }
+    }
+    let domain = get_domain(state, DomainType::BeaconAttester, Some(data.target.epoch), context)?;
+    let signing_root = compute_signing_root(data, domain)?;
+    fast_aggregate_verify(&public_keys, signing_root.as_ref(), &aggregate.signature)
+        .map_err(|_| InvalidAggregateAndProof::InvalidAggregateSignature)?;
+    Ok(())
+}
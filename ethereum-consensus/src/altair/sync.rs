@@ -1,6 +1,10 @@
 use crate::{
+    altair::{constants::SYNC_COMMITTEE_SUBNET_COUNT, validator::SyncCommitteeContribution},
+    crypto::aggregate,
     primitives::{BlsPublicKey, BlsSignature},
     ssz::prelude::*,
+    state_transition::Context,
+    Error,
 };
 
 #[derive(
@@ -11,6 +15,60 @@ pub struct SyncAggregate<const SYNC_COMMITTEE_SIZE: usize> {
     pub sync_committee_signature: BlsSignature,
 }
 
+impl<const SYNC_COMMITTEE_SIZE: usize> SyncAggregate<SYNC_COMMITTEE_SIZE> {
+    // Combine the best contributions for each subcommittee into the aggregate for a block.
+    // All contributions must be for the same slot and block root.
+    pub fn from_contributions<const SYNC_SUBCOMMITTEE_SIZE: usize>(
+        contributions: &[SyncCommitteeContribution<SYNC_SUBCOMMITTEE_SIZE>],
+        context: &Context,
+    ) -> Result<Self, Error> {
+        if let Some(first) = contributions.first() {
+            if contributions.iter().any(|contribution| {
+                contribution.slot != first.slot ||
+                    contribution.beacon_block_root != first.beacon_block_root
+            }) {
+                return Err(Error::IncompatibleContributions)
+            }
+        }
+
+        let mut sync_committee_bits = Bitvector::<SYNC_COMMITTEE_SIZE>::default();
+        let mut signatures = vec![];
+        let subcommittee_size = context.sync_committee_size / SYNC_COMMITTEE_SUBNET_COUNT;
+        for contribution in contributions {
+            let subcommittee_index = contribution.subcommittee_index;
+            if subcommittee_index >= SYNC_COMMITTEE_SUBNET_COUNT as u64 {
+                return Err(Error::InvalidSubcommitteeIndex {
+                    index: subcommittee_index,
+                    count: SYNC_COMMITTEE_SUBNET_COUNT,
+                })
+            }
+            // bit `i` of a contribution is position `i` within its subcommittee
+            let offset = subcommittee_index as usize * subcommittee_size;
+            let mut participated = false;
+            for (i, bit) in contribution.aggregation_bits.iter().enumerate() {
+                if !*bit {
+                    continue
+                }
+                let index = offset + i;
+                if index >= SYNC_COMMITTEE_SIZE {
+                    return Err(Error::OutOfBounds { requested: index, bound: SYNC_COMMITTEE_SIZE })
+                }
+                if sync_committee_bits[index] {
+                    return Err(Error::OverlappingAggregationBits)
+                }
+                sync_committee_bits.set(index, true);
+                participated = true;
+            }
+            if participated {
+                signatures.push(contribution.signature.clone());
+            }
+        }
+        let sync_committee_signature =
+            if signatures.is_empty() { BlsSignature::infinity() } else { aggregate(&signatures)? };
+        Ok(Self { sync_committee_bits, sync_committee_signature })
+    }
+}
+
 #[derive(
     Default, Debug, SimpleSerialize, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize,
 )]
@@ -20,3 +78,56 @@ pub struct SyncCommittee<const SYNC_COMMITTEE_SIZE: usize> {
     #[serde(rename = "aggregate_pubkey")]
     pub aggregate_public_key: BlsPublicKey,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{altair::minimal, crypto::SecretKey, primitives::Root};
+
+    #[test]
+    fn test_sync_aggregate_from_contributions() {
+        let context = Context::for_minimal();
+        let mut rng = rand::thread_rng();
+        let beacon_block_root = Root::try_from([7u8; 32].as_ref()).unwrap();
+        let mut contributions = vec![];
+        let mut signatures = vec![];
+        for subcommittee_index in 0..SYNC_COMMITTEE_SUBNET_COUNT as u64 {
+            let secret_key = SecretKey::random(&mut rng).unwrap();
+            let signature = secret_key.sign(beacon_block_root.as_ref());
+            let mut aggregation_bits = Bitvector::default();
+            aggregation_bits.set(subcommittee_index as usize, true);
+            contributions.push(minimal::SyncCommitteeContribution {
+                slot: 9,
+                beacon_block_root,
+                subcommittee_index,
+                aggregation_bits,
+                signature: signature.clone(),
+            });
+            signatures.push(signature);
+        }
+
+        let sync_aggregate =
+            minimal::SyncAggregate::from_contributions(&contributions, &context).unwrap();
+        let subcommittee_size = context.sync_committee_size / SYNC_COMMITTEE_SUBNET_COUNT;
+        let participants = sync_aggregate
+            .sync_committee_bits
+            .iter()
+            .enumerate()
+            .filter_map(|(i, bit)| bit.then_some(i))
+            .collect::<Vec<_>>();
+        let expected = (0..SYNC_COMMITTEE_SUBNET_COUNT)
+            .map(|subcommittee_index| subcommittee_index * subcommittee_size + subcommittee_index)
+            .collect::<Vec<_>>();
+        assert_eq!(participants, expected);
+        assert_eq!(sync_aggregate.sync_committee_signature, aggregate(&signatures).unwrap());
+
+        let empty = minimal::SyncAggregate::from_contributions::<8>(&[], &context).unwrap();
+        assert!(empty.sync_committee_signature.is_infinity());
+
+        let duplicate = [contributions[1].clone(), contributions[1].clone()];
+        assert!(matches!(
+            minimal::SyncAggregate::from_contributions(&duplicate, &context),
+            Err(Error::OverlappingAggregationBits)
+        ));
+    }
+}
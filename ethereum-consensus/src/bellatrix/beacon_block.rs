@@ -3,6 +3,7 @@ use crate::{
         Attestation, AttesterSlashing, Deposit, Eth1Data, ExecutionPayload, ProposerSlashing,
         SignedVoluntaryExit, SyncAggregate,
     },
+    block_body::BlockBody,
     primitives::{BlsSignature, Bytes32, Root, Slot, ValidatorIndex},
     ssz::prelude::*,
 };
@@ -41,6 +42,75 @@ pub struct BeaconBlockBody<
     >,
 }
 
+impl<
+        const MAX_PROPOSER_SLASHINGS: usize,
+        const MAX_VALIDATORS_PER_COMMITTEE: usize,
+        const MAX_ATTESTER_SLASHINGS: usize,
+        const MAX_ATTESTATIONS: usize,
+        const MAX_DEPOSITS: usize,
+        const MAX_VOLUNTARY_EXITS: usize,
+        const SYNC_COMMITTEE_SIZE: usize,
+        const BYTES_PER_LOGS_BLOOM: usize,
+        const MAX_EXTRA_DATA_BYTES: usize,
+        const MAX_BYTES_PER_TRANSACTION: usize,
+        const MAX_TRANSACTIONS_PER_PAYLOAD: usize,
+    >
+    BeaconBlockBody<
+        MAX_PROPOSER_SLASHINGS,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        MAX_ATTESTER_SLASHINGS,
+        MAX_ATTESTATIONS,
+        MAX_DEPOSITS,
+        MAX_VOLUNTARY_EXITS,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        MAX_BYTES_PER_TRANSACTION,
+        MAX_TRANSACTIONS_PER_PAYLOAD,
+    >
+{
+    pub const MAX_PROPOSER_SLASHINGS: usize = MAX_PROPOSER_SLASHINGS;
+    pub const MAX_VALIDATORS_PER_COMMITTEE: usize = MAX_VALIDATORS_PER_COMMITTEE;
+    pub const MAX_ATTESTER_SLASHINGS: usize = MAX_ATTESTER_SLASHINGS;
+    pub const MAX_ATTESTATIONS: usize = MAX_ATTESTATIONS;
+    pub const MAX_DEPOSITS: usize = MAX_DEPOSITS;
+    pub const MAX_VOLUNTARY_EXITS: usize = MAX_VOLUNTARY_EXITS;
+    pub const SYNC_COMMITTEE_SIZE: usize = SYNC_COMMITTEE_SIZE;
+    pub const BYTES_PER_LOGS_BLOOM: usize = BYTES_PER_LOGS_BLOOM;
+    pub const MAX_EXTRA_DATA_BYTES: usize = MAX_EXTRA_DATA_BYTES;
+    pub const MAX_BYTES_PER_TRANSACTION: usize = MAX_BYTES_PER_TRANSACTION;
+    pub const MAX_TRANSACTIONS_PER_PAYLOAD: usize = MAX_TRANSACTIONS_PER_PAYLOAD;
+}
+
+impl<
+        const MAX_PROPOSER_SLASHINGS: usize,
+        const MAX_VALIDATORS_PER_COMMITTEE: usize,
+        const MAX_ATTESTER_SLASHINGS: usize,
+        const MAX_ATTESTATIONS: usize,
+        const MAX_DEPOSITS: usize,
+        const MAX_VOLUNTARY_EXITS: usize,
+        const SYNC_COMMITTEE_SIZE: usize,
+        const BYTES_PER_LOGS_BLOOM: usize,
+        const MAX_EXTRA_DATA_BYTES: usize,
+        const MAX_BYTES_PER_TRANSACTION: usize,
+        const MAX_TRANSACTIONS_PER_PAYLOAD: usize,
+    > BlockBody
+    for BeaconBlockBody<
+        MAX_PROPOSER_SLASHINGS,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        MAX_ATTESTER_SLASHINGS,
+        MAX_ATTESTATIONS,
+        MAX_DEPOSITS,
+        MAX_VOLUNTARY_EXITS,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        MAX_BYTES_PER_TRANSACTION,
+        MAX_TRANSACTIONS_PER_PAYLOAD,
+    >
+{
+}
+
 #[derive(
     Default, Debug, Clone, SimpleSerialize, PartialEq, Eq, serde::Serialize, serde::Deserialize,
 )]
@@ -78,6 +148,46 @@ pub struct BeaconBlock<
     >,
 }
 
+impl<
+        const MAX_PROPOSER_SLASHINGS: usize,
+        const MAX_VALIDATORS_PER_COMMITTEE: usize,
+        const MAX_ATTESTER_SLASHINGS: usize,
+        const MAX_ATTESTATIONS: usize,
+        const MAX_DEPOSITS: usize,
+        const MAX_VOLUNTARY_EXITS: usize,
+        const SYNC_COMMITTEE_SIZE: usize,
+        const BYTES_PER_LOGS_BLOOM: usize,
+        const MAX_EXTRA_DATA_BYTES: usize,
+        const MAX_BYTES_PER_TRANSACTION: usize,
+        const MAX_TRANSACTIONS_PER_PAYLOAD: usize,
+    >
+    BeaconBlock<
+        MAX_PROPOSER_SLASHINGS,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        MAX_ATTESTER_SLASHINGS,
+        MAX_ATTESTATIONS,
+        MAX_DEPOSITS,
+        MAX_VOLUNTARY_EXITS,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        MAX_BYTES_PER_TRANSACTION,
+        MAX_TRANSACTIONS_PER_PAYLOAD,
+    >
+{
+    pub const MAX_PROPOSER_SLASHINGS: usize = MAX_PROPOSER_SLASHINGS;
+    pub const MAX_VALIDATORS_PER_COMMITTEE: usize = MAX_VALIDATORS_PER_COMMITTEE;
+    pub const MAX_ATTESTER_SLASHINGS: usize = MAX_ATTESTER_SLASHINGS;
+    pub const MAX_ATTESTATIONS: usize = MAX_ATTESTATIONS;
+    pub const MAX_DEPOSITS: usize = MAX_DEPOSITS;
+    pub const MAX_VOLUNTARY_EXITS: usize = MAX_VOLUNTARY_EXITS;
+    pub const SYNC_COMMITTEE_SIZE: usize = SYNC_COMMITTEE_SIZE;
+    pub const BYTES_PER_LOGS_BLOOM: usize = BYTES_PER_LOGS_BLOOM;
+    pub const MAX_EXTRA_DATA_BYTES: usize = MAX_EXTRA_DATA_BYTES;
+    pub const MAX_BYTES_PER_TRANSACTION: usize = MAX_BYTES_PER_TRANSACTION;
+    pub const MAX_TRANSACTIONS_PER_PAYLOAD: usize = MAX_TRANSACTIONS_PER_PAYLOAD;
+}
+
 #[derive(
     Default, Debug, Clone, SimpleSerialize, PartialEq, Eq, serde::Serialize, serde::Deserialize,
 )]
@@ -5,7 +5,7 @@ use crate::{
         process_randao, process_sync_aggregate, BeaconBlock, BeaconBlockBody, BeaconState,
         ExecutionPayloadHeader,
     },
-    error::{invalid_operation_error, InvalidExecutionPayload},
+    error::{invalid_execution_payload_error, invalid_operation_error, InvalidExecutionPayload},
     execution_engine::ExecutionEngine,
     ssz::prelude::*,
     state_transition::{Context, Result},
@@ -95,7 +95,9 @@ pub fn process_execution_payload<
     }
 
     let execution_engine = context.execution_engine();
-    execution_engine.verify_and_notify_new_payload(&payload.clone())?;
+    execution_engine
+        .verify_and_notify_new_payload(&payload.clone())
+        .map_err(invalid_execution_payload_error)?;
 
     state.latest_execution_payload_header = ExecutionPayloadHeader {
         parent_hash: payload.parent_hash.clone(),
@@ -98,3 +98,73 @@ pub fn process_epoch<
     process_sync_committee_updates(state, context)?;
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        altair, bellatrix, phase0::Validator, primitives::FAR_FUTURE_EPOCH,
+        state_transition::Context,
+    };
+
+    const VALIDATOR_COUNT: usize = 10;
+    const SLASHED_INDEX: usize = 0;
+
+    fn validators(context: &Context) -> Vec<Validator> {
+        (0..VALIDATOR_COUNT)
+            .map(|i| {
+                let slashed = i == SLASHED_INDEX;
+                let withdrawable_epoch = if slashed {
+                    context.epochs_per_slashings_vector / 2
+                } else {
+                    FAR_FUTURE_EPOCH
+                };
+                Validator {
+                    effective_balance: context.max_effective_balance,
+                    slashed,
+                    exit_epoch: FAR_FUTURE_EPOCH,
+                    withdrawable_epoch,
+                    ..Default::default()
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_slashings_penalty_by_fork() {
+        let context = Context::for_minimal();
+        // one of ten equal validators slashed, i.e. 10% of the total active balance
+        let slashed_balance = context.max_effective_balance;
+
+        let mut state = altair::minimal::BeaconState::default();
+        for validator in validators(&context) {
+            state.validators.push(validator);
+            state.balances.push(context.max_effective_balance);
+        }
+        state.slashings[0] = slashed_balance;
+        altair::process_slashings(&mut state, &context).unwrap();
+        let altair_penalty = context.max_effective_balance - state.balances[SLASHED_INDEX];
+
+        let mut state = bellatrix::minimal::BeaconState::default();
+        for validator in validators(&context) {
+            state.validators.push(validator);
+            state.balances.push(context.max_effective_balance);
+        }
+        state.slashings[0] = slashed_balance;
+        bellatrix::process_slashings(&mut state, &context).unwrap();
+        let bellatrix_penalty = context.max_effective_balance - state.balances[SLASHED_INDEX];
+
+        // 32 increments * (10% * multiplier), rounded down to a whole increment
+        let increment = context.effective_balance_increment;
+        assert_eq!(context.proportional_slashing_multiplier_altair, 2);
+        assert_eq!(altair_penalty, 6 * increment);
+        assert_eq!(context.proportional_slashing_multiplier_bellatrix, 3);
+        assert_eq!(bellatrix_penalty, 9 * increment);
+
+        // unslashed validators are untouched
+        assert!(state
+            .balances
+            .iter()
+            .skip(1)
+            .all(|&balance| balance == context.max_effective_balance));
+    }
+}
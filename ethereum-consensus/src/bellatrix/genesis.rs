@@ -3,6 +3,7 @@ use crate::{
         get_next_sync_committee, process_deposit, BeaconBlockBody, BeaconBlockHeader, BeaconState,
         Deposit, DepositData, Eth1Data, ExecutionPayloadHeader, Fork, DEPOSIT_DATA_LIST_BOUND,
     },
+    block_body::BlockBody,
     primitives::{Gwei, Hash32, GENESIS_EPOCH},
     ssz::prelude::*,
     state_transition::{Context, Result},
@@ -71,7 +72,7 @@ pub fn initialize_beacon_state_from_eth1<
         MAX_BYTES_PER_TRANSACTION,
         MAX_TRANSACTIONS_PER_PAYLOAD,
     >::default();
-    let body_root = latest_block_body.hash_tree_root()?;
+    let body_root = latest_block_body.body_root()?;
     let latest_block_header = BeaconBlockHeader { body_root, ..Default::default() };
     let randao_mixes = Vector::try_from(
         std::iter::repeat_n(eth1_block_hash, context.epochs_per_historical_vector as usize)
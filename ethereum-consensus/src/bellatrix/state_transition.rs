@@ -1,12 +1,68 @@
 use crate::{
     bellatrix::{
-        process_block, process_slots, verify_block_signature, BeaconState, SignedBeaconBlock,
+        process_block, process_slots, verify_block_signature, verify_proposer_index, BeaconBlock,
+        BeaconState, SignedBeaconBlock,
     },
+    primitives::Root,
     ssz::prelude::HashTreeRoot,
     state_transition::{Context, Result, Validation},
     Error,
 };
 
+// The final step of a validated state transition: the root of the post-state must match
+// the `state_root` committed to in the block.
+pub fn validate_state_root<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+    const MAX_PROPOSER_SLASHINGS: usize,
+    const MAX_ATTESTER_SLASHINGS: usize,
+    const MAX_ATTESTATIONS: usize,
+    const MAX_DEPOSITS: usize,
+    const MAX_VOLUNTARY_EXITS: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+    const MAX_BYTES_PER_TRANSACTION: usize,
+    const MAX_TRANSACTIONS_PER_PAYLOAD: usize,
+>(
+    state: &BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+    >,
+    block: &BeaconBlock<
+        MAX_PROPOSER_SLASHINGS,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        MAX_ATTESTER_SLASHINGS,
+        MAX_ATTESTATIONS,
+        MAX_DEPOSITS,
+        MAX_VOLUNTARY_EXITS,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        MAX_BYTES_PER_TRANSACTION,
+        MAX_TRANSACTIONS_PER_PAYLOAD,
+    >,
+) -> Result<()> {
+    let root = state.hash_tree_root()?;
+    if block.state_root != root {
+        return Err(Error::StateRootMismatch { expected: block.state_root, got: root })
+    }
+    Ok(())
+}
+
 // `state_transition_block_in_slot` is separated out
 // to facilitate upgrades across forks which take place
 // after the call to `process_slots` in the sub-transition.
@@ -61,16 +117,16 @@ pub fn state_transition_block_in_slot<
         Validation::Enabled => true,
         Validation::Disabled => false,
     };
+    let block = &signed_block.message;
     if validate_result {
+        verify_proposer_index(state, block, context)?;
         verify_block_signature(state, signed_block, context)?;
     }
-    let block = &signed_block.message;
     process_block(state, block, context)?;
-    if validate_result && block.state_root != state.hash_tree_root()? {
-        Err(Error::InvalidStateRoot)
-    } else {
-        Ok(())
+    if validate_result {
+        validate_state_root(state, block)?;
     }
+    Ok(())
 }
 
 pub fn state_transition<
@@ -124,3 +180,61 @@ pub fn state_transition<
 
     state_transition_block_in_slot(state, signed_block, validation, context)
 }
+
+// Applies `signed_block` to a copy of `pre_state` (verifying the proposer and its signature)
+// and returns the root of the resulting state instead of the state itself, for callers that
+// only need to check a block's `state_root`.
+pub fn compute_post_state_root<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+    const MAX_PROPOSER_SLASHINGS: usize,
+    const MAX_ATTESTER_SLASHINGS: usize,
+    const MAX_ATTESTATIONS: usize,
+    const MAX_DEPOSITS: usize,
+    const MAX_VOLUNTARY_EXITS: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+    const MAX_BYTES_PER_TRANSACTION: usize,
+    const MAX_TRANSACTIONS_PER_PAYLOAD: usize,
+>(
+    pre_state: &BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+    >,
+    signed_block: &SignedBeaconBlock<
+        MAX_PROPOSER_SLASHINGS,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        MAX_ATTESTER_SLASHINGS,
+        MAX_ATTESTATIONS,
+        MAX_DEPOSITS,
+        MAX_VOLUNTARY_EXITS,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        MAX_BYTES_PER_TRANSACTION,
+        MAX_TRANSACTIONS_PER_PAYLOAD,
+    >,
+    context: &Context,
+) -> Result<Root> {
+    let mut state = pre_state.clone();
+    let block = &signed_block.message;
+    process_slots(&mut state, block.slot, context)?;
+    verify_proposer_index(&state, block, context)?;
+    verify_block_signature(&state, signed_block, context)?;
+    process_block(&mut state, block, context)?;
+    Ok(state.hash_tree_root()?)
+}
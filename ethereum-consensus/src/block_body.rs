@@ -0,0 +1,75 @@
+use crate::{primitives::Root, ssz::prelude::*, state_transition::Result};
+
+/// `BlockBody` abstracts over the `BeaconBlockBody` defined in each fork.
+pub trait BlockBody: HashTreeRoot {
+    /// Return the root committed to in the `body_root` of a `BeaconBlockHeader`.
+    fn body_root(&self) -> Result<Root> {
+        Ok(self.hash_tree_root()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{altair, bellatrix, capella, deneb, electra, phase0, primitives::Bytes32};
+
+    type ElectraBeaconBlockBody = electra::BeaconBlockBody<
+        { deneb::minimal::MAX_PROPOSER_SLASHINGS },
+        { deneb::minimal::MAX_VALIDATORS_PER_COMMITTEE * 4 },
+        4,
+        { electra::minimal::MAX_ATTESTER_SLASHINGS_ELECTRA },
+        { electra::minimal::MAX_ATTESTATIONS_ELECTRA },
+        { deneb::minimal::MAX_DEPOSITS },
+        { deneb::minimal::MAX_VOLUNTARY_EXITS },
+        { deneb::minimal::SYNC_COMMITTEE_SIZE },
+        { deneb::minimal::BYTES_PER_LOGS_BLOOM },
+        { deneb::minimal::MAX_EXTRA_DATA_BYTES },
+        { deneb::minimal::MAX_BYTES_PER_TRANSACTION },
+        { deneb::minimal::MAX_TRANSACTIONS_PER_PAYLOAD },
+        { deneb::minimal::MAX_WITHDRAWALS_PER_PAYLOAD },
+        { electra::minimal::MAX_DEPOSIT_RECEIPTS_PER_PAYLOAD },
+        { electra::minimal::MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD },
+        { deneb::minimal::MAX_BLS_TO_EXECUTION_CHANGES },
+        { deneb::minimal::MAX_BLOB_COMMITMENTS_PER_BLOCK },
+        { electra::minimal::MAX_CONSOLIDATIONS },
+    >;
+
+    fn assert_body_root<T: BlockBody>(body: T, expected: &str) {
+        let expected = Root::try_from(hex::decode(&expected[2..]).unwrap().as_ref()).unwrap();
+        assert_eq!(body.body_root().unwrap(), expected);
+    }
+
+    // NOTE: the expected roots were computed independently of this crate by Merkleizing
+    // each fork's `BeaconBlockBody` under the minimal preset, with only the graffiti set
+    #[test]
+    fn test_body_root() {
+        let graffiti = Bytes32::try_from([0xab; 32].as_ref()).unwrap();
+        assert_body_root(
+            phase0::minimal::BeaconBlockBody { graffiti: graffiti.clone(), ..Default::default() },
+            "0xf6cf29fdf7a1f505c2014241cd28452c533f92796b7b8701e71f1b1d47603310",
+        );
+        assert_body_root(
+            altair::minimal::BeaconBlockBody { graffiti: graffiti.clone(), ..Default::default() },
+            "0xe0b6873d274433f901ccce5e927d1625fa8cdb965d90a74f9290689aafd0bd00",
+        );
+        assert_body_root(
+            bellatrix::minimal::BeaconBlockBody {
+                graffiti: graffiti.clone(),
+                ..Default::default()
+            },
+            "0x7f1410ddb56ae0501309faa559c66a6995d53bb2950573abb92d147799fee76f",
+        );
+        assert_body_root(
+            capella::minimal::BeaconBlockBody { graffiti: graffiti.clone(), ..Default::default() },
+            "0xc77d57655aa6a224e322166915aba05232ce2423309bb8c4734f4ff99d3aec9e",
+        );
+        assert_body_root(
+            deneb::minimal::BeaconBlockBody { graffiti: graffiti.clone(), ..Default::default() },
+            "0x7da0b1aa6885cf512ed25990ebf1ea9e6334168500ce3339506a03a03d88d507",
+        );
+        assert_body_root(
+            ElectraBeaconBlockBody { graffiti, ..Default::default() },
+            "0x1832826a6273e3e99b3fde06222dae3eb4e3ba4239511b785d8342d6b02eefaf",
+        );
+    }
+}
@@ -1,5 +1,6 @@
 use crate::{
     altair::SyncAggregate,
+    block_body::BlockBody,
     capella::{ExecutionPayload, SignedBlsToExecutionChange},
     phase0::{
         Attestation, AttesterSlashing, Deposit, Eth1Data, ProposerSlashing, SignedVoluntaryExit,
@@ -46,6 +47,85 @@ pub struct BeaconBlockBody<
     pub bls_to_execution_changes: List<SignedBlsToExecutionChange, MAX_BLS_TO_EXECUTION_CHANGES>,
 }
 
+impl<
+        const MAX_PROPOSER_SLASHINGS: usize,
+        const MAX_VALIDATORS_PER_COMMITTEE: usize,
+        const MAX_ATTESTER_SLASHINGS: usize,
+        const MAX_ATTESTATIONS: usize,
+        const MAX_DEPOSITS: usize,
+        const MAX_VOLUNTARY_EXITS: usize,
+        const SYNC_COMMITTEE_SIZE: usize,
+        const BYTES_PER_LOGS_BLOOM: usize,
+        const MAX_EXTRA_DATA_BYTES: usize,
+        const MAX_BYTES_PER_TRANSACTION: usize,
+        const MAX_TRANSACTIONS_PER_PAYLOAD: usize,
+        const MAX_WITHDRAWALS_PER_PAYLOAD: usize,
+        const MAX_BLS_TO_EXECUTION_CHANGES: usize,
+    >
+    BeaconBlockBody<
+        MAX_PROPOSER_SLASHINGS,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        MAX_ATTESTER_SLASHINGS,
+        MAX_ATTESTATIONS,
+        MAX_DEPOSITS,
+        MAX_VOLUNTARY_EXITS,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        MAX_BYTES_PER_TRANSACTION,
+        MAX_TRANSACTIONS_PER_PAYLOAD,
+        MAX_WITHDRAWALS_PER_PAYLOAD,
+        MAX_BLS_TO_EXECUTION_CHANGES,
+    >
+{
+    pub const MAX_PROPOSER_SLASHINGS: usize = MAX_PROPOSER_SLASHINGS;
+    pub const MAX_VALIDATORS_PER_COMMITTEE: usize = MAX_VALIDATORS_PER_COMMITTEE;
+    pub const MAX_ATTESTER_SLASHINGS: usize = MAX_ATTESTER_SLASHINGS;
+    pub const MAX_ATTESTATIONS: usize = MAX_ATTESTATIONS;
+    pub const MAX_DEPOSITS: usize = MAX_DEPOSITS;
+    pub const MAX_VOLUNTARY_EXITS: usize = MAX_VOLUNTARY_EXITS;
+    pub const SYNC_COMMITTEE_SIZE: usize = SYNC_COMMITTEE_SIZE;
+    pub const BYTES_PER_LOGS_BLOOM: usize = BYTES_PER_LOGS_BLOOM;
+    pub const MAX_EXTRA_DATA_BYTES: usize = MAX_EXTRA_DATA_BYTES;
+    pub const MAX_BYTES_PER_TRANSACTION: usize = MAX_BYTES_PER_TRANSACTION;
+    pub const MAX_TRANSACTIONS_PER_PAYLOAD: usize = MAX_TRANSACTIONS_PER_PAYLOAD;
+    pub const MAX_WITHDRAWALS_PER_PAYLOAD: usize = MAX_WITHDRAWALS_PER_PAYLOAD;
+    pub const MAX_BLS_TO_EXECUTION_CHANGES: usize = MAX_BLS_TO_EXECUTION_CHANGES;
+}
+
+impl<
+        const MAX_PROPOSER_SLASHINGS: usize,
+        const MAX_VALIDATORS_PER_COMMITTEE: usize,
+        const MAX_ATTESTER_SLASHINGS: usize,
+        const MAX_ATTESTATIONS: usize,
+        const MAX_DEPOSITS: usize,
+        const MAX_VOLUNTARY_EXITS: usize,
+        const SYNC_COMMITTEE_SIZE: usize,
+        const BYTES_PER_LOGS_BLOOM: usize,
+        const MAX_EXTRA_DATA_BYTES: usize,
+        const MAX_BYTES_PER_TRANSACTION: usize,
+        const MAX_TRANSACTIONS_PER_PAYLOAD: usize,
+        const MAX_WITHDRAWALS_PER_PAYLOAD: usize,
+        const MAX_BLS_TO_EXECUTION_CHANGES: usize,
+    > BlockBody
+    for BeaconBlockBody<
+        MAX_PROPOSER_SLASHINGS,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        MAX_ATTESTER_SLASHINGS,
+        MAX_ATTESTATIONS,
+        MAX_DEPOSITS,
+        MAX_VOLUNTARY_EXITS,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        MAX_BYTES_PER_TRANSACTION,
+        MAX_TRANSACTIONS_PER_PAYLOAD,
+        MAX_WITHDRAWALS_PER_PAYLOAD,
+        MAX_BLS_TO_EXECUTION_CHANGES,
+    >
+{
+}
+
 #[derive(
     Default, Debug, Clone, SimpleSerialize, PartialEq, Eq, serde::Serialize, serde::Deserialize,
 )]
@@ -87,6 +167,52 @@ pub struct BeaconBlock<
     >,
 }
 
+impl<
+        const MAX_PROPOSER_SLASHINGS: usize,
+        const MAX_VALIDATORS_PER_COMMITTEE: usize,
+        const MAX_ATTESTER_SLASHINGS: usize,
+        const MAX_ATTESTATIONS: usize,
+        const MAX_DEPOSITS: usize,
+        const MAX_VOLUNTARY_EXITS: usize,
+        const SYNC_COMMITTEE_SIZE: usize,
+        const BYTES_PER_LOGS_BLOOM: usize,
+        const MAX_EXTRA_DATA_BYTES: usize,
+        const MAX_BYTES_PER_TRANSACTION: usize,
+        const MAX_TRANSACTIONS_PER_PAYLOAD: usize,
+        const MAX_WITHDRAWALS_PER_PAYLOAD: usize,
+        const MAX_BLS_TO_EXECUTION_CHANGES: usize,
+    >
+    BeaconBlock<
+        MAX_PROPOSER_SLASHINGS,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        MAX_ATTESTER_SLASHINGS,
+        MAX_ATTESTATIONS,
+        MAX_DEPOSITS,
+        MAX_VOLUNTARY_EXITS,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        MAX_BYTES_PER_TRANSACTION,
+        MAX_TRANSACTIONS_PER_PAYLOAD,
+        MAX_WITHDRAWALS_PER_PAYLOAD,
+        MAX_BLS_TO_EXECUTION_CHANGES,
+    >
+{
+    pub const MAX_PROPOSER_SLASHINGS: usize = MAX_PROPOSER_SLASHINGS;
+    pub const MAX_VALIDATORS_PER_COMMITTEE: usize = MAX_VALIDATORS_PER_COMMITTEE;
+    pub const MAX_ATTESTER_SLASHINGS: usize = MAX_ATTESTER_SLASHINGS;
+    pub const MAX_ATTESTATIONS: usize = MAX_ATTESTATIONS;
+    pub const MAX_DEPOSITS: usize = MAX_DEPOSITS;
+    pub const MAX_VOLUNTARY_EXITS: usize = MAX_VOLUNTARY_EXITS;
+    pub const SYNC_COMMITTEE_SIZE: usize = SYNC_COMMITTEE_SIZE;
+    pub const BYTES_PER_LOGS_BLOOM: usize = BYTES_PER_LOGS_BLOOM;
+    pub const MAX_EXTRA_DATA_BYTES: usize = MAX_EXTRA_DATA_BYTES;
+    pub const MAX_BYTES_PER_TRANSACTION: usize = MAX_BYTES_PER_TRANSACTION;
+    pub const MAX_TRANSACTIONS_PER_PAYLOAD: usize = MAX_TRANSACTIONS_PER_PAYLOAD;
+    pub const MAX_WITHDRAWALS_PER_PAYLOAD: usize = MAX_WITHDRAWALS_PER_PAYLOAD;
+    pub const MAX_BLS_TO_EXECUTION_CHANGES: usize = MAX_BLS_TO_EXECUTION_CHANGES;
+}
+
 #[derive(
     Default, Debug, Clone, SimpleSerialize, PartialEq, Eq, serde::Serialize, serde::Deserialize,
 )]
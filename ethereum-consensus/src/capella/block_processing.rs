@@ -10,8 +10,8 @@ use crate::{
     },
     crypto::hash,
     error::{
-        invalid_operation_error, InvalidBlsToExecutionChange, InvalidDeposit,
-        InvalidExecutionPayload, InvalidOperation, InvalidWithdrawals,
+        invalid_execution_payload_error, invalid_operation_error, InvalidBlsToExecutionChange,
+        InvalidDeposit, InvalidExecutionPayload, InvalidOperation, InvalidWithdrawals,
     },
     execution_engine::ExecutionEngine,
     primitives::{BLS_WITHDRAWAL_PREFIX, ETH1_ADDRESS_WITHDRAWAL_PREFIX},
@@ -251,7 +251,9 @@ pub fn process_execution_payload<
     }
 
     let execution_engine = context.execution_engine();
-    execution_engine.verify_and_notify_new_payload(&payload.clone())?;
+    execution_engine
+        .verify_and_notify_new_payload(&payload.clone())
+        .map_err(invalid_execution_payload_error)?;
 
     state.latest_execution_payload_header = ExecutionPayloadHeader {
         parent_hash: payload.parent_hash.clone(),
@@ -1,4 +1,5 @@
 use crate::{
+    block_body::BlockBody,
     capella::{
         get_next_sync_committee, process_deposit, BeaconBlockBody, BeaconBlockHeader, BeaconState,
         Deposit, DepositData, Eth1Data, ExecutionPayloadHeader, Fork, DEPOSIT_DATA_LIST_BOUND,
@@ -75,7 +76,7 @@ pub fn initialize_beacon_state_from_eth1<
         MAX_WITHDRAWALS_PER_PAYLOAD,
         MAX_BLS_TO_EXECUTION_CHANGES,
     >::default();
-    let body_root = latest_block_body.hash_tree_root()?;
+    let body_root = latest_block_body.body_root()?;
     let latest_block_header = BeaconBlockHeader { body_root, ..Default::default() };
     let randao_mixes = Vector::try_from(
         std::iter::repeat_n(eth1_block_hash, context.epochs_per_historical_vector as usize)
@@ -15,3 +15,33 @@ pub struct Withdrawal {
     #[serde(with = "crate::serde::as_str")]
     pub amount: Gwei,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::primitives::Root;
+
+    #[test]
+    fn test_withdrawal_ssz() {
+        let withdrawal = Withdrawal {
+            index: 7,
+            validator_index: 42,
+            address: ExecutionAddress::try_from([0xab; 20].as_ref()).unwrap(),
+            amount: 32_000_000_000,
+        };
+        let encoding = serialize(&withdrawal).unwrap();
+        assert_eq!(
+            hex::encode(&encoding),
+            "07000000000000002a00000000000000abababababababababababababababababababab0040597307000000"
+        );
+        let recovered: Withdrawal = deserialize(&encoding).unwrap();
+        assert_eq!(recovered, withdrawal);
+
+        // computed independently of `ssz_rs` from the SSZ Merkleization rules
+        let root = hex::decode("ed67318e2765b5eea5e3d275fc219b49b237f01205c5f2debe3b6c1aac9da379");
+        assert_eq!(
+            withdrawal.hash_tree_root().unwrap(),
+            Root::try_from(root.unwrap().as_ref()).unwrap()
+        );
+    }
+}
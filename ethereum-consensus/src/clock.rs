@@ -65,6 +65,41 @@ pub fn get_current_unix_time_in_nanos() -> u128 {
     SystemTime::now().duration_since(UNIX_EPOCH).expect("after `UNIX_EPOCH`").as_nanos()
 }
 
+/// The points within a slot that validator duties are keyed off: block proposal at the start,
+/// attestation at one third and aggregation at two thirds of the way through the slot.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SlotPhase {
+    Start,
+    OneThird,
+    TwoThirds,
+}
+
+impl SlotPhase {
+    fn index(self) -> u128 {
+        match self {
+            Self::Start => 0,
+            Self::OneThird => 1,
+            Self::TwoThirds => 2,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SlotTick {
+    pub slot: Slot,
+    pub phase: SlotPhase,
+}
+
+impl SlotTick {
+    pub fn next(&self) -> Self {
+        match self.phase {
+            SlotPhase::Start => Self { slot: self.slot, phase: SlotPhase::OneThird },
+            SlotPhase::OneThird => Self { slot: self.slot, phase: SlotPhase::TwoThirds },
+            SlotPhase::TwoThirds => Self { slot: self.slot + 1, phase: SlotPhase::Start },
+        }
+    }
+}
+
 pub trait TimeProvider {
     // Provide the current time to **nanosecond** precision.
     fn get_current_time(&self) -> u128;
@@ -212,6 +247,40 @@ impl<T: TimeProvider + Send + Sync> Clock<T> {
             Duration::from_nanos(u128_to_u64(target_slot_in_nanos - current_time))
         }
     }
+
+    // Return the current sub-slot tick, or `None` if before genesis.
+    pub fn current_tick(&self) -> Option<SlotTick> {
+        self.tick_at_time(self.get_current_time())
+    }
+
+    pub fn tick_at_time(&self, current_time: u128) -> Option<SlotTick> {
+        let slot = self.slot_at_time(current_time)?;
+        let offset = (current_time - self.genesis_time) % self.seconds_per_slot;
+        // NOTE: clamp in case `seconds_per_slot` is not evenly divisible into thirds
+        let phase = match offset / (self.seconds_per_slot / 3) {
+            0 => SlotPhase::Start,
+            1 => SlotPhase::OneThird,
+            _ => SlotPhase::TwoThirds,
+        };
+        Some(SlotTick { slot, phase })
+    }
+
+    /// Return the time in **nanoseconds** since the `UNIX_EPOCH` at which `tick` begins.
+    pub fn time_at_tick(&self, tick: SlotTick) -> u128 {
+        slot_to_nanos(tick.slot, self.seconds_per_slot, self.genesis_time) +
+            tick.phase.index() * (self.seconds_per_slot / 3)
+    }
+
+    /// Return a `Duration` until the next sub-slot tick relative to the
+    /// current time as determined by the clock.
+    pub fn duration_until_next_tick(&self) -> Duration {
+        let current_time = self.get_current_time();
+        let target_time = match self.tick_at_time(current_time) {
+            Some(tick) => self.time_at_tick(tick.next()),
+            None => self.genesis_time,
+        };
+        Duration::from_nanos(u128_to_u64(target_time.saturating_sub(current_time)))
+    }
 }
 
 pub type SystemClock = Clock<SystemTimeProvider>;
@@ -266,6 +335,53 @@ impl<T: TimeProvider + Send + Sync> Stream for SlotStream<T> {
     }
 }
 
+#[cfg(feature = "async")]
+/// Implements `futures_core::Stream` yielding the sub-slot ticks tracked by the `clock`.
+/// NOTE: the first poll will return the current tick, even if it is not aligned to the tick start.
+/// The delay to each following tick is recomputed from the clock on every wake so the stream
+/// does not accumulate drift; ticks are never repeated, but may be skipped if the stream
+/// falls behind.
+pub struct TickStream<T: TimeProvider + Send + Sync> {
+    delay: Pin<Box<Sleep>>,
+    clock: Clock<T>,
+    first_tick: Option<SlotTick>,
+    last_tick: Option<SlotTick>,
+}
+
+#[cfg(feature = "async")]
+impl<T: TimeProvider + Send + Sync> Clock<T> {
+    pub fn into_tick_stream(self) -> TickStream<T> {
+        let delay = Box::pin(sleep(self.duration_until_next_tick()));
+        let current_tick = self.current_tick();
+        TickStream { delay, clock: self, first_tick: current_tick, last_tick: None }
+    }
+}
+
+#[cfg(feature = "async")]
+impl<T: TimeProvider + Send + Sync> Stream for TickStream<T> {
+    type Item = SlotTick;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut task::Context<'_>) -> Poll<Option<Self::Item>> {
+        if let Some(tick) = self.first_tick.take() {
+            self.last_tick = Some(tick);
+            return Poll::Ready(Some(tick))
+        }
+        loop {
+            match Pin::new(&mut self.delay).poll(cx) {
+                Poll::Ready(_) => {
+                    self.delay = Box::pin(sleep(self.clock.duration_until_next_tick()));
+                    let tick = self.clock.current_tick();
+                    if tick.is_some() && tick > self.last_tick {
+                        self.last_tick = tick;
+                        return Poll::Ready(tick)
+                    }
+                }
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -290,6 +406,10 @@ mod tests {
             let next_tick = next_slot * self.seconds_per_slot;
             *tick = next_tick - 1;
         }
+
+        fn set_time(&self, time: u128) {
+            *self.tick.lock().unwrap() = time;
+        }
     }
 
     impl TimeProvider for Arc<Ticker> {
@@ -341,6 +461,61 @@ mod tests {
         assert!(clock.current_slot().is_none());
     }
 
+    #[test]
+    fn test_ticks() {
+        let seconds_per_slot: u64 = 12;
+        let time_provider = new_ticker(seconds_per_slot);
+        let clock = Clock::new(0, seconds_per_slot, 32, time_provider.clone());
+        let mut tick = clock.current_tick().unwrap();
+        assert_eq!(tick, SlotTick { slot: 0, phase: SlotPhase::Start });
+        assert_eq!(clock.duration_until_next_tick().as_secs(), 4);
+        for _ in 0..9 {
+            let next = tick.next();
+            assert!(next > tick);
+            let start = clock.time_at_tick(next);
+            let phase_offset = Duration::from_secs(4 * next.phase.index() as u64).as_nanos();
+            let slot_start = Duration::from_secs(next.slot * seconds_per_slot).as_nanos();
+            assert_eq!(start, slot_start + phase_offset);
+            time_provider.set_time(start - 1);
+            assert_eq!(clock.current_tick().unwrap(), tick);
+            assert_eq!(clock.duration_until_next_tick(), Duration::from_nanos(1));
+            time_provider.set_time(start);
+            assert_eq!(clock.current_tick().unwrap(), next);
+            tick = next;
+        }
+        assert_eq!(tick, SlotTick { slot: 3, phase: SlotPhase::Start });
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn test_tick_stream() {
+        use tokio_stream::StreamExt;
+
+        let seconds_per_slot: u64 = 12;
+        let time_provider = new_ticker(seconds_per_slot);
+        let clock = Clock::new(0, seconds_per_slot, 32, time_provider.clone());
+        let first_tick = SlotTick { slot: 0, phase: SlotPhase::Start };
+        // start just before the next tick so the real-time delay is minimal
+        time_provider.set_time(clock.time_at_tick(first_tick.next()) - 1);
+        let mut tick_stream = clock.clone().into_tick_stream();
+
+        let mut ticks = vec![];
+        while let Some(tick) = tick_stream.next().await {
+            ticks.push(tick);
+            if ticks.len() == 6 {
+                break
+            }
+            // jump world state ahead to just before the tick after next
+            time_provider.set_time(clock.time_at_tick(tick.next().next()) - 1);
+        }
+        let mut expected = vec![first_tick];
+        for _ in 1..6 {
+            expected.push(expected.last().unwrap().next());
+        }
+        assert_eq!(ticks, expected);
+        assert!(ticks.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
     #[cfg(feature = "async")]
     #[tokio::test]
     async fn test_slot_stream() {
@@ -1,7 +1,13 @@
 #[cfg(feature = "serde")]
 use crate::serde::try_bytes_from_hex_str;
-use crate::{primitives::Bytes32, ssz::prelude::*};
-use blst::{min_pk as bls_impl, BLST_ERROR};
+use crate::{
+    primitives::{Bytes32, Root},
+    ssz::prelude::*,
+};
+use blst::{
+    blst_hash_to_g2, blst_p2, blst_p2_compress, blst_scalar, min_pk as bls_impl, BLST_ERROR,
+};
+use rand::Rng;
 use sha2::{Digest, Sha256};
 use std::{
     fmt,
@@ -19,7 +25,7 @@ pub fn hash<D: AsRef<[u8]>>(data: D) -> Bytes32 {
     result
 }
 
-const BLS_DST: &[u8] = b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_";
+pub const BLS_DST: &[u8] = b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_";
 const BLS_PUBLIC_KEY_BYTES_LEN: usize = 48;
 const BLS_SECRET_KEY_BYTES_LEN: usize = 32;
 const BLS_SIGNATURE_BYTES_LEN: usize = 96;
@@ -31,6 +37,10 @@ pub enum Error {
     Hex(#[from] hex::FromHexError),
     #[error("inputs required for aggregation but none were provided")]
     EmptyAggregate,
+    #[error("public key is the point at infinity")]
+    InfinityPublicKey,
+    #[error("point is not in the correct subgroup")]
+    NotInSubgroup,
     #[error("{0}")]
     SimpleSerialize(#[from] SimpleSerializeError),
     #[error("randomness failure: {0}")]
@@ -61,6 +71,44 @@ impl From<BLST_ERROR> for BLSTError {
     }
 }
 
+impl From<BLST_ERROR> for Error {
+    fn from(err: BLST_ERROR) -> Self {
+        match err {
+            BLST_ERROR::BLST_POINT_NOT_IN_GROUP => Self::NotInSubgroup,
+            err => Self::BLST(err.into()),
+        }
+    }
+}
+
+// Hash `msg` to a point in G2 with the domain separation tag used for eth signatures.
+// Returns the point in compressed form.
+pub fn hash_to_curve(msg: &[u8]) -> [u8; BLS_SIGNATURE_BYTES_LEN] {
+    hash_to_curve_with_dst(msg, BLS_DST)
+}
+
+// Hash `msg` to a point in G2 with a caller-supplied domain separation tag,
+// e.g. for signature schemes outside the core protocol.
+// Returns the point in compressed form.
+pub fn hash_to_curve_with_dst(msg: &[u8], dst: &[u8]) -> [u8; BLS_SIGNATURE_BYTES_LEN] {
+    let mut point = blst_p2::default();
+    let mut out = [0u8; BLS_SIGNATURE_BYTES_LEN];
+    // SAFETY: all pointers are derived from live slices with matching lengths
+    // and `out` has room for a compressed G2 point.
+    unsafe {
+        blst_hash_to_g2(
+            &mut point,
+            msg.as_ptr(),
+            msg.len(),
+            dst.as_ptr(),
+            dst.len(),
+            std::ptr::null(),
+            0,
+        );
+        blst_p2_compress(out.as_mut_ptr(), &point);
+    }
+    out
+}
+
 pub fn verify_signature(
     public_key: &PublicKey,
     msg: &[u8],
@@ -136,6 +184,9 @@ pub fn eth_aggregate_public_keys(public_keys: &[PublicKey]) -> Result<PublicKey,
     if public_keys.is_empty() {
         return Err(Error::EmptyAggregate)
     }
+    if public_keys.iter().any(PublicKey::is_infinity) {
+        return Err(Error::InfinityPublicKey)
+    }
     let public_keys = public_keys
         .iter()
         .map(bls_impl::PublicKey::try_from)
@@ -159,6 +210,77 @@ pub fn eth_fast_aggregate_verify(
     }
 }
 
+// A signature to check as part of a batch: `signature` must be valid for `message` under the
+// aggregate of `public_keys`. `message` is a signing root, so it already commits to the domain
+// of the operation that was signed.
+#[derive(Debug, Clone)]
+pub struct SignatureSet<'a> {
+    pub public_keys: Vec<&'a PublicKey>,
+    pub message: Root,
+    pub signature: &'a Signature,
+}
+
+// Verify every signature in a block (proposal, randao reveal, operations, ...) in one batch.
+// Each set is weighted by a random scalar so that invalid signatures cannot cancel out.
+pub fn verify_block_signature_sets(sets: &[SignatureSet<'_>]) -> Result<(), Error> {
+    if sets.is_empty() {
+        return Ok(())
+    }
+
+    let public_keys = sets
+        .iter()
+        .map(|set| {
+            if set.public_keys.is_empty() {
+                return Err(Error::EmptyAggregate)
+            }
+            let public_keys = set
+                .public_keys
+                .iter()
+                .cloned()
+                .map(bls_impl::PublicKey::try_from)
+                .collect::<Result<Vec<bls_impl::PublicKey>, Error>>()?;
+            let public_keys: Vec<&bls_impl::PublicKey> = public_keys.iter().collect();
+            // NOTE: keys were validated on conversion above
+            let aggregate = bls_impl::AggregatePublicKey::aggregate(&public_keys, false)?;
+            Ok(aggregate.to_public_key())
+        })
+        .collect::<Result<Vec<bls_impl::PublicKey>, Error>>()?;
+    let public_keys: Vec<&bls_impl::PublicKey> = public_keys.iter().collect();
+    let signatures = sets
+        .iter()
+        .map(|set| bls_impl::Signature::try_from(set.signature))
+        .collect::<Result<Vec<bls_impl::Signature>, Error>>()?;
+    let signatures: Vec<&bls_impl::Signature> = signatures.iter().collect();
+    let messages: Vec<&[u8]> = sets.iter().map(|set| set.message.as_ref()).collect();
+
+    let mut rng = rand::thread_rng();
+    let randoms = sets
+        .iter()
+        .map(|_| {
+            let mut scalar = blst_scalar::default();
+            let value: u64 = rng.gen_range(1..=u64::MAX);
+            scalar.b[..8].copy_from_slice(&value.to_le_bytes());
+            scalar
+        })
+        .collect::<Vec<_>>();
+
+    let res = bls_impl::Signature::verify_multiple_aggregate_signatures(
+        &messages,
+        BLS_DST,
+        &public_keys,
+        false,
+        &signatures,
+        false,
+        &randoms,
+        64,
+    );
+    if res == BLST_ERROR::BLST_SUCCESS {
+        Ok(())
+    } else {
+        Err(Error::InvalidSignature)
+    }
+}
+
 #[derive(Clone, Default, serde::Deserialize)]
 #[serde(try_from = "String")]
 pub struct SecretKey(bls_impl::SecretKey);
@@ -209,6 +331,17 @@ impl SecretKey {
         Ok(Self(sk))
     }
 
+    // Derive the master secret key from `seed` following EIP-2333
+    pub fn derive_master_eip2333(seed: &[u8]) -> Result<Self, Error> {
+        let sk = bls_impl::SecretKey::derive_master_eip2333(seed).map_err(BLSTError::from)?;
+        Ok(Self(sk))
+    }
+
+    // Derive the child secret key at `index` from this (parent) secret key following EIP-2333
+    pub fn derive_child_eip2333(&self, index: u32) -> Self {
+        Self(self.0.derive_child_eip2333(index))
+    }
+
     pub fn public_key(&self) -> PublicKey {
         let pk = self.0.sk_to_pk();
         PublicKey::try_from(pk.to_bytes().as_ref()).unwrap()
@@ -224,6 +357,10 @@ impl SecretKey {
     }
 }
 
+// The compressed encoding of a public key. Decoding (via `TryFrom<&[u8]>`, SSZ or serde) only
+// checks the length: whether the bytes are a point in the G1 subgroup is checked lazily, when
+// the key is converted to a `bls_impl::PublicKey` to be used. This keeps decoding a state with
+// the full validator registry cheap and lets types holding a key implement `Default`.
 #[derive(
     Clone,
     Default,
@@ -276,14 +413,28 @@ impl TryFrom<&[u8]> for PublicKey {
     }
 }
 
+const INFINITY_COMPRESSED_PUBLIC_KEY: [u8; BLS_PUBLIC_KEY_BYTES_LEN] = [
+    0xc0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+];
+
+impl PublicKey {
+    pub fn is_infinity(&self) -> bool {
+        self.as_ref() == INFINITY_COMPRESSED_PUBLIC_KEY
+    }
+}
+
 impl TryFrom<&PublicKey> for bls_impl::PublicKey {
     type Error = Error;
 
     fn try_from(public_key: &PublicKey) -> Result<Self, Error> {
-        Self::key_validate(public_key.0.as_ref()).map_err(|err| BLSTError::from(err).into())
+        // NOTE: `key_validate` checks for subgroup membership (and rejects the point at infinity)
+        Self::key_validate(public_key.0.as_ref()).map_err(Into::into)
     }
 }
 
+// The compressed encoding of a signature. As with `PublicKey`, decoding only checks the length
+// and subgroup membership is checked lazily, when the signature is verified.
 #[derive(
     Clone, Default, Hash, PartialEq, Eq, SimpleSerialize, serde::Serialize, serde::Deserialize,
 )]
@@ -331,7 +482,8 @@ impl TryFrom<&Signature> for bls_impl::Signature {
     type Error = Error;
 
     fn try_from(signature: &Signature) -> Result<Self, Error> {
-        Self::from_bytes(signature.0.as_ref()).map_err(|err| BLSTError::from(err).into())
+        // NOTE: check for subgroup membership but allow the point at infinity
+        Self::sig_validate(signature.0.as_ref(), false).map_err(Into::into)
     }
 }
 
@@ -343,21 +495,39 @@ const INFINITY_COMPRESSED_SIGNATURE: [u8; BLS_SIGNATURE_BYTES_LEN] = [
 ];
 
 impl Signature {
+    pub fn infinity() -> Self {
+        Self(ByteVector::try_from(INFINITY_COMPRESSED_SIGNATURE.as_ref()).expect("correct length"))
+    }
+
     pub fn is_infinity(&self) -> bool {
         self.as_ref() == INFINITY_COMPRESSED_SIGNATURE
     }
 }
 
+#[cfg(feature = "test-utils")]
+pub const TEST_KEYS_SEED: &[u8] = b"ethereum-consensus test keys seed";
+
+// Return `count` deterministic secret keys for use in test fixtures.
+// The key for validator `i` is derived from `TEST_KEYS_SEED` following EIP-2333
+// along the EIP-2334 signing key path `m/12381/3600/i/0/0`.
+#[cfg(feature = "test-utils")]
+pub fn test_keys(count: usize) -> Vec<SecretKey> {
+    let master_key =
+        SecretKey::derive_master_eip2333(TEST_KEYS_SEED).expect("seed is at least 32 bytes");
+    (0..count as u32)
+        .map(|i| {
+            [12381, 3600, i, 0, 0]
+                .into_iter()
+                .fold(master_key.clone(), |key, index| key.derive_child_eip2333(index))
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use rand::prelude::*;
 
-    const INFINITY_COMPRESSED_PUBLIC_KEY: [u8; BLS_PUBLIC_KEY_BYTES_LEN] = [
-        0xc0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    ];
-
     #[test]
     fn signature() {
         let mut rng = thread_rng();
@@ -369,6 +539,71 @@ mod tests {
         assert!(verify_signature(&pk, msg, &sig).is_ok());
     }
 
+    #[test]
+    fn test_verify_block_signature_sets() {
+        let mut rng = thread_rng();
+        let secret_keys = (0..5).map(|_| SecretKey::random(&mut rng).unwrap()).collect::<Vec<_>>();
+        let public_keys = secret_keys.iter().map(SecretKey::public_key).collect::<Vec<_>>();
+        // signing roots for operations signed under different domains
+        let messages = [b"proposal", b"randao__", b"attester", b"vol_exit"]
+            .map(|domain| Root::try_from(hash(domain).as_ref()).unwrap());
+
+        let proposal = secret_keys[0].sign(messages[0].as_ref());
+        let randao_reveal = secret_keys[0].sign(messages[1].as_ref());
+        let attestation = aggregate(
+            &secret_keys[1..4].iter().map(|sk| sk.sign(messages[2].as_ref())).collect::<Vec<_>>(),
+        )
+        .unwrap();
+        let exit = secret_keys[4].sign(messages[3].as_ref());
+        let mut sets = vec![
+            SignatureSet {
+                public_keys: vec![&public_keys[0]],
+                message: messages[0],
+                signature: &proposal,
+            },
+            SignatureSet {
+                public_keys: vec![&public_keys[0]],
+                message: messages[1],
+                signature: &randao_reveal,
+            },
+            SignatureSet {
+                public_keys: public_keys[1..4].iter().collect(),
+                message: messages[2],
+                signature: &attestation,
+            },
+            SignatureSet {
+                public_keys: vec![&public_keys[4]],
+                message: messages[3],
+                signature: &exit,
+            },
+        ];
+        assert!(verify_block_signature_sets(&sets).is_ok());
+
+        let tampered = secret_keys[4].sign(messages[2].as_ref());
+        sets[3].signature = &tampered;
+        assert!(matches!(verify_block_signature_sets(&sets), Err(Error::InvalidSignature)));
+    }
+
+    #[test]
+    fn test_hash_to_curve() {
+        // test vector from RFC 9380, appendix J.10.1
+        let dst = b"QUUX-V01-CS02-with-BLS12381G2_XMD:SHA-256_SSWU_RO_";
+        let expected = hex::decode("a5cb8437535e20ecffaef7752baddf98034139c38452458baeefab379ba13dff5bf5dd71b72418717047f5b0f37da03d0141ebfbdca40eb85b87142e130ab689c673cf60f1a3e98d69335266f30d9b8d4ac44c1038e9dcdd5393faf5c41fb78a").unwrap();
+        assert_eq!(hash_to_curve_with_dst(b"", dst).as_ref(), expected);
+
+        let msg = b"message";
+        let expected = hex::decode("b0dd6d14f88bab7e02315d2898ddbb421a4ec69646c6a9521cd91d9d4611f2525a03219ba9aa14ba105fe363d8f707fa11ba8bf111e5fa70373bc6b6a1fdfdaaa8a8a2434caacb175e26f807b676769ecf5c839e7636435ff0e24f3f636a2031").unwrap();
+        let point = hash_to_curve(msg);
+        assert_eq!(point.as_ref(), expected);
+        assert_ne!(point, hash_to_curve_with_dst(msg, b"CUSTOM_DST"));
+
+        // signing with the unit secret key yields the hashed point
+        let mut one = [0u8; 32];
+        one[31] = 1;
+        let sk = SecretKey::try_from(one.as_ref()).unwrap();
+        assert_eq!(sk.sign(msg).as_ref(), point);
+    }
+
     #[test]
     #[should_panic(expected = "expected")]
     fn test_signature_from_null_bytes() {
@@ -486,6 +721,54 @@ mod tests {
         PublicKey::try_from(z.as_ref()).expect("can make a long public key");
     }
 
+    #[test]
+    fn test_eth_aggregate_public_keys() {
+        let mut rng = thread_rng();
+        let public_keys =
+            (0..4).map(|_| SecretKey::random(&mut rng).unwrap().public_key()).collect::<Vec<_>>();
+        assert!(eth_aggregate_public_keys(&public_keys).is_ok());
+
+        assert!(matches!(eth_aggregate_public_keys(&[]), Err(Error::EmptyAggregate)));
+
+        let mut public_keys = public_keys;
+        public_keys[2] = PublicKey::try_from(INFINITY_COMPRESSED_PUBLIC_KEY.as_ref()).unwrap();
+        assert!(public_keys[2].is_infinity());
+        assert!(matches!(eth_aggregate_public_keys(&public_keys), Err(Error::InfinityPublicKey)));
+    }
+
+    #[test]
+    fn test_points_not_in_subgroup() {
+        // on-curve points outside of the prime-order subgroups, from the `bls12-381-tests`
+        let public_key_bytes = hex::decode("8123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef").unwrap();
+        let public_key = PublicKey::try_from(public_key_bytes.as_ref()).unwrap();
+        let result = bls_impl::PublicKey::try_from(&public_key);
+        assert!(matches!(result, Err(Error::NotInSubgroup)));
+
+        let signature_bytes = hex::decode("8123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef").unwrap();
+        let signature = Signature::try_from(signature_bytes.as_ref()).unwrap();
+        let result = bls_impl::Signature::try_from(&signature);
+        assert!(matches!(result, Err(Error::NotInSubgroup)));
+
+        // decoding is lazy: SSZ and serde accept the points, which are only rejected on use
+        let decoded = PublicKey::deserialize(&public_key_bytes).unwrap();
+        assert_eq!(decoded, public_key);
+        let json = format!("\"0x{}\"", hex::encode(&public_key_bytes));
+        let decoded: PublicKey = serde_json::from_str(&json).unwrap();
+        assert!(matches!(bls_impl::PublicKey::try_from(&decoded), Err(Error::NotInSubgroup)));
+        let decoded = Signature::deserialize(&signature_bytes).unwrap();
+        assert_eq!(decoded, signature);
+        let json = format!("\"0x{}\"", hex::encode(&signature_bytes));
+        let decoded: Signature = serde_json::from_str(&json).unwrap();
+        assert!(matches!(bls_impl::Signature::try_from(&decoded), Err(Error::NotInSubgroup)));
+
+        let mut rng = thread_rng();
+        let public_key = SecretKey::random(&mut rng).unwrap().public_key();
+        assert!(matches!(
+            verify_signature(&public_key, b"message", &signature),
+            Err(Error::NotInSubgroup)
+        ));
+    }
+
     #[test]
     fn test_aggregate_verify() {
         let n = 20;
@@ -577,4 +860,34 @@ mod tests {
         let recovered_signature: Signature = serde_json::from_str(&serialized_signature).unwrap();
         assert_eq!(sig, recovered_signature);
     }
+
+    #[test]
+    fn test_eip2333_derivation() {
+        // test case 0 from EIP-2333
+        let seed = hex::decode("c55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e53495531f09a6987599d18264c1e1c92f2cf141630c7a3c4ab7c81b2f001698e7463b04").unwrap();
+        let master_key = SecretKey::derive_master_eip2333(&seed).unwrap();
+        assert_eq!(
+            hex::encode(master_key.clone().to_bytes()),
+            "0d7359d57963ab8fbbde1852dcf553fedbc31f464d80ee7d40ae683122b45070"
+        );
+        let child_key = master_key.derive_child_eip2333(0);
+        assert_eq!(
+            hex::encode(child_key.to_bytes()),
+            "2d18bd6c14e6d15bf8b5085c9b74f3daae3b03cc2014770a599d8c1539e50f8e"
+        );
+    }
+
+    #[cfg(feature = "test-utils")]
+    #[test]
+    fn test_test_keys_are_stable() {
+        let keys = test_keys(2);
+        assert_eq!(keys.len(), 2);
+        let expected_public_key = hex::decode("8af535a44bccf447875fa5372a56a807fbc2c3c0be5579a0a3b0e6e0559195507e1874304659d7d86058717ca84c01f5").unwrap();
+        assert_eq!(
+            keys[0].public_key(),
+            PublicKey::try_from(expected_public_key.as_ref()).unwrap()
+        );
+        assert_ne!(keys[0].public_key(), keys[1].public_key());
+        assert_eq!(test_keys(1)[0].public_key(), keys[0].public_key());
+    }
 }
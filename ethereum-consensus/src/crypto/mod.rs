@@ -3,9 +3,12 @@ pub mod kzg;
 
 pub use bls::{
     aggregate, aggregate_verify, eth_aggregate_public_keys, eth_fast_aggregate_verify,
-    fast_aggregate_verify, hash, verify_signature, Error as BlsError, PublicKey, SecretKey,
-    Signature,
+    fast_aggregate_verify, hash, hash_to_curve, hash_to_curve_with_dst,
+    verify_block_signature_sets, verify_signature, Error as BlsError, PublicKey, SecretKey,
+    Signature, SignatureSet, BLS_DST,
 };
+#[cfg(feature = "test-utils")]
+pub use bls::{test_keys, TEST_KEYS_SEED};
 pub use kzg::{
     kzg_settings_with_precompute_arc, Error as KzgError, KzgCommitment, KzgProof, KzgSettings,
     PRECOMPUTE,
@@ -1,5 +1,6 @@
 use crate::{
     altair::SyncAggregate,
+    block_body::BlockBody,
     capella::SignedBlsToExecutionChange,
     crypto::KzgCommitment,
     deneb::ExecutionPayload,
@@ -50,6 +51,90 @@ pub struct BeaconBlockBody<
     pub blob_kzg_commitments: List<KzgCommitment, MAX_BLOB_COMMITMENTS_PER_BLOCK>,
 }
 
+impl<
+        const MAX_PROPOSER_SLASHINGS: usize,
+        const MAX_VALIDATORS_PER_COMMITTEE: usize,
+        const MAX_ATTESTER_SLASHINGS: usize,
+        const MAX_ATTESTATIONS: usize,
+        const MAX_DEPOSITS: usize,
+        const MAX_VOLUNTARY_EXITS: usize,
+        const SYNC_COMMITTEE_SIZE: usize,
+        const BYTES_PER_LOGS_BLOOM: usize,
+        const MAX_EXTRA_DATA_BYTES: usize,
+        const MAX_BYTES_PER_TRANSACTION: usize,
+        const MAX_TRANSACTIONS_PER_PAYLOAD: usize,
+        const MAX_WITHDRAWALS_PER_PAYLOAD: usize,
+        const MAX_BLS_TO_EXECUTION_CHANGES: usize,
+        const MAX_BLOB_COMMITMENTS_PER_BLOCK: usize,
+    >
+    BeaconBlockBody<
+        MAX_PROPOSER_SLASHINGS,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        MAX_ATTESTER_SLASHINGS,
+        MAX_ATTESTATIONS,
+        MAX_DEPOSITS,
+        MAX_VOLUNTARY_EXITS,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        MAX_BYTES_PER_TRANSACTION,
+        MAX_TRANSACTIONS_PER_PAYLOAD,
+        MAX_WITHDRAWALS_PER_PAYLOAD,
+        MAX_BLS_TO_EXECUTION_CHANGES,
+        MAX_BLOB_COMMITMENTS_PER_BLOCK,
+    >
+{
+    pub const MAX_PROPOSER_SLASHINGS: usize = MAX_PROPOSER_SLASHINGS;
+    pub const MAX_VALIDATORS_PER_COMMITTEE: usize = MAX_VALIDATORS_PER_COMMITTEE;
+    pub const MAX_ATTESTER_SLASHINGS: usize = MAX_ATTESTER_SLASHINGS;
+    pub const MAX_ATTESTATIONS: usize = MAX_ATTESTATIONS;
+    pub const MAX_DEPOSITS: usize = MAX_DEPOSITS;
+    pub const MAX_VOLUNTARY_EXITS: usize = MAX_VOLUNTARY_EXITS;
+    pub const SYNC_COMMITTEE_SIZE: usize = SYNC_COMMITTEE_SIZE;
+    pub const BYTES_PER_LOGS_BLOOM: usize = BYTES_PER_LOGS_BLOOM;
+    pub const MAX_EXTRA_DATA_BYTES: usize = MAX_EXTRA_DATA_BYTES;
+    pub const MAX_BYTES_PER_TRANSACTION: usize = MAX_BYTES_PER_TRANSACTION;
+    pub const MAX_TRANSACTIONS_PER_PAYLOAD: usize = MAX_TRANSACTIONS_PER_PAYLOAD;
+    pub const MAX_WITHDRAWALS_PER_PAYLOAD: usize = MAX_WITHDRAWALS_PER_PAYLOAD;
+    pub const MAX_BLS_TO_EXECUTION_CHANGES: usize = MAX_BLS_TO_EXECUTION_CHANGES;
+    pub const MAX_BLOB_COMMITMENTS_PER_BLOCK: usize = MAX_BLOB_COMMITMENTS_PER_BLOCK;
+}
+
+impl<
+        const MAX_PROPOSER_SLASHINGS: usize,
+        const MAX_VALIDATORS_PER_COMMITTEE: usize,
+        const MAX_ATTESTER_SLASHINGS: usize,
+        const MAX_ATTESTATIONS: usize,
+        const MAX_DEPOSITS: usize,
+        const MAX_VOLUNTARY_EXITS: usize,
+        const SYNC_COMMITTEE_SIZE: usize,
+        const BYTES_PER_LOGS_BLOOM: usize,
+        const MAX_EXTRA_DATA_BYTES: usize,
+        const MAX_BYTES_PER_TRANSACTION: usize,
+        const MAX_TRANSACTIONS_PER_PAYLOAD: usize,
+        const MAX_WITHDRAWALS_PER_PAYLOAD: usize,
+        const MAX_BLS_TO_EXECUTION_CHANGES: usize,
+        const MAX_BLOB_COMMITMENTS_PER_BLOCK: usize,
+    > BlockBody
+    for BeaconBlockBody<
+        MAX_PROPOSER_SLASHINGS,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        MAX_ATTESTER_SLASHINGS,
+        MAX_ATTESTATIONS,
+        MAX_DEPOSITS,
+        MAX_VOLUNTARY_EXITS,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        MAX_BYTES_PER_TRANSACTION,
+        MAX_TRANSACTIONS_PER_PAYLOAD,
+        MAX_WITHDRAWALS_PER_PAYLOAD,
+        MAX_BLS_TO_EXECUTION_CHANGES,
+        MAX_BLOB_COMMITMENTS_PER_BLOCK,
+    >
+{
+}
+
 #[derive(
     Default, Debug, Clone, SimpleSerialize, PartialEq, Eq, serde::Serialize, serde::Deserialize,
 )]
@@ -93,6 +178,55 @@ pub struct BeaconBlock<
     >,
 }
 
+impl<
+        const MAX_PROPOSER_SLASHINGS: usize,
+        const MAX_VALIDATORS_PER_COMMITTEE: usize,
+        const MAX_ATTESTER_SLASHINGS: usize,
+        const MAX_ATTESTATIONS: usize,
+        const MAX_DEPOSITS: usize,
+        const MAX_VOLUNTARY_EXITS: usize,
+        const SYNC_COMMITTEE_SIZE: usize,
+        const BYTES_PER_LOGS_BLOOM: usize,
+        const MAX_EXTRA_DATA_BYTES: usize,
+        const MAX_BYTES_PER_TRANSACTION: usize,
+        const MAX_TRANSACTIONS_PER_PAYLOAD: usize,
+        const MAX_WITHDRAWALS_PER_PAYLOAD: usize,
+        const MAX_BLS_TO_EXECUTION_CHANGES: usize,
+        const MAX_BLOB_COMMITMENTS_PER_BLOCK: usize,
+    >
+    BeaconBlock<
+        MAX_PROPOSER_SLASHINGS,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        MAX_ATTESTER_SLASHINGS,
+        MAX_ATTESTATIONS,
+        MAX_DEPOSITS,
+        MAX_VOLUNTARY_EXITS,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        MAX_BYTES_PER_TRANSACTION,
+        MAX_TRANSACTIONS_PER_PAYLOAD,
+        MAX_WITHDRAWALS_PER_PAYLOAD,
+        MAX_BLS_TO_EXECUTION_CHANGES,
+        MAX_BLOB_COMMITMENTS_PER_BLOCK,
+    >
+{
+    pub const MAX_PROPOSER_SLASHINGS: usize = MAX_PROPOSER_SLASHINGS;
+    pub const MAX_VALIDATORS_PER_COMMITTEE: usize = MAX_VALIDATORS_PER_COMMITTEE;
+    pub const MAX_ATTESTER_SLASHINGS: usize = MAX_ATTESTER_SLASHINGS;
+    pub const MAX_ATTESTATIONS: usize = MAX_ATTESTATIONS;
+    pub const MAX_DEPOSITS: usize = MAX_DEPOSITS;
+    pub const MAX_VOLUNTARY_EXITS: usize = MAX_VOLUNTARY_EXITS;
+    pub const SYNC_COMMITTEE_SIZE: usize = SYNC_COMMITTEE_SIZE;
+    pub const BYTES_PER_LOGS_BLOOM: usize = BYTES_PER_LOGS_BLOOM;
+    pub const MAX_EXTRA_DATA_BYTES: usize = MAX_EXTRA_DATA_BYTES;
+    pub const MAX_BYTES_PER_TRANSACTION: usize = MAX_BYTES_PER_TRANSACTION;
+    pub const MAX_TRANSACTIONS_PER_PAYLOAD: usize = MAX_TRANSACTIONS_PER_PAYLOAD;
+    pub const MAX_WITHDRAWALS_PER_PAYLOAD: usize = MAX_WITHDRAWALS_PER_PAYLOAD;
+    pub const MAX_BLS_TO_EXECUTION_CHANGES: usize = MAX_BLS_TO_EXECUTION_CHANGES;
+    pub const MAX_BLOB_COMMITMENTS_PER_BLOCK: usize = MAX_BLOB_COMMITMENTS_PER_BLOCK;
+}
+
 #[derive(
     Default, Debug, Clone, SimpleSerialize, PartialEq, Eq, serde::Serialize, serde::Deserialize,
 )]
@@ -133,9 +267,29 @@ pub struct SignedBeaconBlock<
 
 #[cfg(test)]
 mod tests {
-    use crate::deneb::mainnet::BeaconBlockBody;
+    use crate::deneb::mainnet::{self, BeaconBlockBody};
     use ssz_rs::prelude::*;
 
+    #[test]
+    fn test_preset_bounds() {
+        assert_eq!(BeaconBlockBody::MAX_PROPOSER_SLASHINGS, mainnet::MAX_PROPOSER_SLASHINGS);
+        assert_eq!(BeaconBlockBody::MAX_ATTESTER_SLASHINGS, mainnet::MAX_ATTESTER_SLASHINGS);
+        assert_eq!(BeaconBlockBody::MAX_ATTESTATIONS, 128);
+        assert_eq!(BeaconBlockBody::MAX_DEPOSITS, 16);
+        assert_eq!(BeaconBlockBody::MAX_VALIDATORS_PER_COMMITTEE, 2048);
+        assert_eq!(BeaconBlockBody::MAX_VOLUNTARY_EXITS, mainnet::MAX_VOLUNTARY_EXITS);
+        assert_eq!(BeaconBlockBody::SYNC_COMMITTEE_SIZE, mainnet::SYNC_COMMITTEE_SIZE);
+        assert_eq!(
+            BeaconBlockBody::MAX_BLOB_COMMITMENTS_PER_BLOCK,
+            mainnet::MAX_BLOB_COMMITMENTS_PER_BLOCK
+        );
+        assert_eq!(mainnet::BeaconBlock::MAX_ATTESTATIONS, BeaconBlockBody::MAX_ATTESTATIONS);
+        assert_eq!(
+            mainnet::BeaconBlock::MAX_WITHDRAWALS_PER_PAYLOAD,
+            mainnet::MAX_WITHDRAWALS_PER_PAYLOAD
+        );
+    }
+
     #[test]
     fn test_generalized_index() {
         let mut indices = vec![];
@@ -13,8 +13,8 @@ use crate::{
     },
     domains::DomainType,
     error::{
-        invalid_operation_error, InvalidAttestation, InvalidExecutionPayload, InvalidOperation,
-        InvalidVoluntaryExit,
+        invalid_execution_payload_error, invalid_operation_error, InvalidAttestation,
+        InvalidExecutionPayload, InvalidOperation, InvalidVoluntaryExit,
     },
     execution_engine::ExecutionEngine,
     primitives::FAR_FUTURE_EPOCH,
@@ -242,8 +242,11 @@ pub fn process_execution_payload<
         execution_payload: payload.clone(),
         versioned_hashes,
         parent_beacon_block_root: state.latest_block_header.parent_root,
+        blob_kzg_commitments: body.blob_kzg_commitments.to_vec(),
     };
-    execution_engine.verify_and_notify_new_payload(&new_payload_request)?;
+    execution_engine
+        .verify_and_notify_new_payload(&new_payload_request)
+        .map_err(invalid_execution_payload_error)?;
 
     state.latest_execution_payload_header = ExecutionPayloadHeader {
         parent_hash: payload.parent_hash.clone(),
@@ -1,5 +1,11 @@
+#[cfg(feature = "engine-api")]
+use crate::engine_api::{to_data, to_quantity, EngineApiCall};
 use crate::{
-    deneb::{blob_sidecar::VersionedHash, execution_payload::ExecutionPayload},
+    crypto::KzgCommitment,
+    deneb::{
+        blob_sidecar::VersionedHash, execution_payload::ExecutionPayload,
+        helpers::kzg_commitment_to_versioned_hash,
+    },
     execution_engine::PayloadRequest,
     primitives::Root,
 };
@@ -20,6 +26,8 @@ pub struct NewPayloadRequest<
     >,
     pub versioned_hashes: Vec<VersionedHash>,
     pub parent_beacon_block_root: Root,
+    // The commitments from the block body, positionally paired with `versioned_hashes`.
+    pub blob_kzg_commitments: Vec<KzgCommitment>,
 }
 
 impl<
@@ -37,4 +45,106 @@ impl<
         MAX_WITHDRAWALS_PER_PAYLOAD,
     >
 {
+    fn is_valid_versioned_hashes(&self) -> bool {
+        self.versioned_hashes.len() == self.blob_kzg_commitments.len() &&
+            self.blob_kzg_commitments
+                .iter()
+                .zip(&self.versioned_hashes)
+                .all(|(commitment, hash)| &kzg_commitment_to_versioned_hash(commitment) == hash)
+    }
+
+    #[cfg(feature = "engine-api")]
+    fn engine_api_call(&self) -> Option<EngineApiCall> {
+        let payload = &self.execution_payload;
+        let withdrawals = payload
+            .withdrawals
+            .iter()
+            .map(|withdrawal| {
+                serde_json::json!({
+                    "index": to_quantity(withdrawal.index),
+                    "validatorIndex": to_quantity(withdrawal.validator_index),
+                    "address": to_data(&withdrawal.address),
+                    "amount": to_quantity(withdrawal.amount),
+                })
+            })
+            .collect::<Vec<_>>();
+        let execution_payload = serde_json::json!({
+            "parentHash": to_data(&payload.parent_hash),
+            "feeRecipient": to_data(&payload.fee_recipient),
+            "stateRoot": to_data(&payload.state_root),
+            "receiptsRoot": to_data(&payload.receipts_root),
+            "logsBloom": to_data(&payload.logs_bloom),
+            "prevRandao": to_data(&payload.prev_randao),
+            "blockNumber": to_quantity(payload.block_number),
+            "gasLimit": to_quantity(payload.gas_limit),
+            "gasUsed": to_quantity(payload.gas_used),
+            "timestamp": to_quantity(payload.timestamp),
+            "extraData": to_data(&payload.extra_data),
+            "baseFeePerGas": to_quantity(payload.base_fee_per_gas),
+            "blockHash": to_data(&payload.block_hash),
+            "transactions": payload.transactions.iter().map(to_data).collect::<Vec<_>>(),
+            "withdrawals": withdrawals,
+            "blobGasUsed": to_quantity(payload.blob_gas_used),
+            "excessBlobGas": to_quantity(payload.excess_blob_gas),
+        });
+        let versioned_hashes = self.versioned_hashes.iter().map(to_data).collect::<Vec<_>>();
+        Some(EngineApiCall {
+            method: "engine_newPayloadV3",
+            params: vec![
+                execution_payload,
+                versioned_hashes.into(),
+                to_data(&self.parent_beacon_block_root).into(),
+            ],
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        deneb::mainnet::ExecutionPayload,
+        error::{Error, ExecutionEngineError},
+        execution_engine::ExecutionEngine,
+    };
+
+    fn new_payload_request(
+        blob_kzg_commitments: Vec<KzgCommitment>,
+        versioned_hashes: Vec<VersionedHash>,
+    ) -> impl PayloadRequest {
+        NewPayloadRequest {
+            execution_payload: ExecutionPayload::default(),
+            versioned_hashes,
+            parent_beacon_block_root: Root::default(),
+            blob_kzg_commitments,
+        }
+    }
+
+    #[test]
+    fn test_is_valid_versioned_hashes() {
+        let commitments = (1..=3u8)
+            .map(|i| KzgCommitment::try_from([i; 48].as_ref()).unwrap())
+            .collect::<Vec<_>>();
+        let hashes = commitments.iter().map(kzg_commitment_to_versioned_hash).collect::<Vec<_>>();
+
+        assert!(new_payload_request(vec![], vec![]).is_valid_versioned_hashes());
+        assert!(
+            new_payload_request(commitments.clone(), hashes.clone()).is_valid_versioned_hashes()
+        );
+
+        let mut reordered = hashes.clone();
+        reordered.swap(0, 1);
+        assert!(!new_payload_request(commitments.clone(), reordered).is_valid_versioned_hashes());
+        assert!(!new_payload_request(commitments.clone(), hashes[..2].to_vec())
+            .is_valid_versioned_hashes());
+        assert!(!new_payload_request(vec![], hashes[..1].to_vec()).is_valid_versioned_hashes());
+
+        let request = new_payload_request(commitments[..1].to_vec(), hashes[1..2].to_vec());
+        assert!(matches!(
+            true.verify_and_notify_new_payload(&request),
+            Err(Error::ExecutionEngine(ExecutionEngineError::InvalidVersionedHashes))
+        ));
+        let request = new_payload_request(commitments, hashes);
+        assert!(true.verify_and_notify_new_payload(&request).is_ok());
+    }
 }
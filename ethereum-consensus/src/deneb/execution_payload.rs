@@ -1,11 +1,17 @@
 use crate::{
     bellatrix::Transaction,
     capella::Withdrawal,
+    deneb::blob_sidecar::VersionedHash,
     primitives::{Bytes32, ExecutionAddress, Hash32, Root},
     ssz::prelude::*,
     Error,
 };
 
+// EIP-2718 type of an EIP-4844 blob transaction
+pub const BLOB_TX_TYPE: u8 = 0x03;
+// Position of `blob_versioned_hashes` in the fields of a blob transaction
+const BLOB_VERSIONED_HASHES_FIELD_INDEX: usize = 10;
+
 #[derive(
     Default, Debug, Clone, SimpleSerialize, PartialEq, Eq, serde::Serialize, serde::Deserialize,
 )]
@@ -75,6 +81,43 @@ pub struct ExecutionPayloadHeader<
     pub excess_blob_gas: u64,
 }
 
+impl<
+        const BYTES_PER_LOGS_BLOOM: usize,
+        const MAX_EXTRA_DATA_BYTES: usize,
+        const MAX_BYTES_PER_TRANSACTION: usize,
+        const MAX_TRANSACTIONS_PER_PAYLOAD: usize,
+        const MAX_WITHDRAWALS_PER_PAYLOAD: usize,
+    >
+    ExecutionPayload<
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        MAX_BYTES_PER_TRANSACTION,
+        MAX_TRANSACTIONS_PER_PAYLOAD,
+        MAX_WITHDRAWALS_PER_PAYLOAD,
+    >
+{
+    pub fn transaction_count(&self) -> usize {
+        self.transactions.len()
+    }
+
+    // Iterate over the opaque (EIP-2718 encoded) transactions of this payload.
+    pub fn iter_transactions(&self) -> impl Iterator<Item = &[u8]> {
+        self.transactions.iter().map(|transaction| transaction.as_ref())
+    }
+
+    // Return the `blob_versioned_hashes` of every blob transaction in this payload, in order,
+    // e.g. to compare against those derived from a block's `blob_kzg_commitments`.
+    pub fn blob_versioned_hashes(&self) -> Result<Vec<VersionedHash>, Error> {
+        let mut versioned_hashes = vec![];
+        for transaction in self.iter_transactions() {
+            if let Some(hashes) = get_blob_versioned_hashes(transaction)? {
+                versioned_hashes.extend(hashes);
+            }
+        }
+        Ok(versioned_hashes)
+    }
+}
+
 impl<
         'a,
         const BYTES_PER_LOGS_BLOOM: usize,
@@ -129,3 +172,126 @@ impl<
         })
     }
 }
+
+fn invalid_blob_transaction(reason: &str) -> Error {
+    Error::InvalidBlobTransaction(reason.to_string())
+}
+
+// Split the RLP item at the front of `data` into whether it is a list, its payload and the
+// remaining bytes.
+fn split_rlp_item(data: &[u8]) -> Result<(bool, &[u8], &[u8]), Error> {
+    let (&prefix, rest) =
+        data.split_first().ok_or_else(|| invalid_blob_transaction("empty item"))?;
+    let (is_list, length, rest) = match prefix {
+        0x00..=0x7f => return Ok((false, &data[..1], rest)),
+        0x80..=0xb7 => (false, (prefix - 0x80) as usize, rest),
+        0xc0..=0xf7 => (true, (prefix - 0xc0) as usize, rest),
+        _ => {
+            let is_list = prefix >= 0xf8;
+            let length_of_length = (prefix - if is_list { 0xf7 } else { 0xb7 }) as usize;
+            if length_of_length > rest.len() || length_of_length > 8 {
+                return Err(invalid_blob_transaction("item length out of bounds"))
+            }
+            let (length_bytes, rest) = rest.split_at(length_of_length);
+            let length = length_bytes.iter().fold(0u64, |acc, byte| (acc << 8) | *byte as u64);
+            let length =
+                usize::try_from(length).map_err(|_| invalid_blob_transaction("item too long"))?;
+            (is_list, length, rest)
+        }
+    };
+    if length > rest.len() {
+        return Err(invalid_blob_transaction("item extends past the end of the input"))
+    }
+    let (payload, rest) = rest.split_at(length);
+    Ok((is_list, payload, rest))
+}
+
+// Return the `blob_versioned_hashes` of `transaction` if it is a blob transaction, i.e.
+// `BLOB_TX_TYPE || rlp([chain_id, ..., max_fee_per_blob_gas, blob_versioned_hashes, ...])`,
+// decoding only as much of the RLP as is needed to reach them. Other transactions give `None`.
+pub fn get_blob_versioned_hashes(transaction: &[u8]) -> Result<Option<Vec<VersionedHash>>, Error> {
+    let Some((&BLOB_TX_TYPE, encoding)) = transaction.split_first() else { return Ok(None) };
+    let (is_list, mut fields, _) = split_rlp_item(encoding)?;
+    if !is_list {
+        return Err(invalid_blob_transaction("payload is not a list"))
+    }
+    for _ in 0..BLOB_VERSIONED_HASHES_FIELD_INDEX {
+        (_, _, fields) = split_rlp_item(fields)?;
+    }
+    let (is_list, mut hashes, _) = split_rlp_item(fields)?;
+    if !is_list {
+        return Err(invalid_blob_transaction("blob versioned hashes are not a list"))
+    }
+    let mut versioned_hashes = vec![];
+    while !hashes.is_empty() {
+        let (is_list, hash, rest) = split_rlp_item(hashes)?;
+        if is_list || hash.len() != 32 {
+            return Err(invalid_blob_transaction("blob versioned hash is not 32 bytes"))
+        }
+        versioned_hashes.push(VersionedHash::try_from(hash).expect("is 32 bytes"));
+        hashes = rest;
+    }
+    Ok(Some(versioned_hashes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::deneb::mainnet::ExecutionPayload;
+
+    fn rlp_string(bytes: &[u8]) -> Vec<u8> {
+        match bytes {
+            [byte] if *byte < 0x80 => vec![*byte],
+            _ => [&[0x80 + bytes.len() as u8], bytes].concat(),
+        }
+    }
+
+    fn rlp_list(items: &[Vec<u8>]) -> Vec<u8> {
+        let payload = items.concat();
+        if payload.len() < 56 {
+            return [vec![0xc0 + payload.len() as u8], payload].concat()
+        }
+        let length = (payload.len() as u64).to_be_bytes();
+        let length = &length[length.iter().position(|byte| *byte != 0).unwrap()..];
+        [vec![0xf7 + length.len() as u8], length.to_vec(), payload].concat()
+    }
+
+    #[test]
+    fn test_blob_versioned_hashes() {
+        let hashes = [[0x01; 32], [0x02; 32]];
+        let fields = vec![
+            rlp_string(&[0x01]),       // chain_id
+            rlp_string(&[]),           // nonce
+            rlp_string(&[0x3b; 4]),    // max_priority_fee_per_gas
+            rlp_string(&[0x3b; 5]),    // max_fee_per_gas
+            rlp_string(&[0x52, 0x08]), // gas_limit
+            rlp_string(&[0xaa; 20]),   // to
+            rlp_string(&[]),           // value
+            rlp_string(&[0xde; 40]),   // data
+            rlp_list(&[]),             // access_list
+            rlp_string(&[0x07]),       // max_fee_per_blob_gas
+            rlp_list(&hashes.iter().map(|hash| rlp_string(hash)).collect::<Vec<_>>()),
+            rlp_string(&[0x01]),     // y_parity
+            rlp_string(&[0x11; 32]), // r
+            rlp_string(&[0x22; 32]), // s
+        ];
+        let blob_transaction = [vec![BLOB_TX_TYPE], rlp_list(&fields)].concat();
+        let expected = hashes
+            .iter()
+            .map(|hash| VersionedHash::try_from(hash.as_ref()).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(get_blob_versioned_hashes(&blob_transaction).unwrap(), Some(expected.clone()));
+
+        let legacy_transaction = rlp_list(&fields[..9]);
+        assert_eq!(get_blob_versioned_hashes(&legacy_transaction).unwrap(), None);
+        let truncated = &blob_transaction[..blob_transaction.len() - 1];
+        assert!(get_blob_versioned_hashes(truncated).is_err());
+
+        let mut payload = ExecutionPayload::default();
+        payload.transactions.push(Transaction::try_from(legacy_transaction.as_ref()).unwrap());
+        payload.transactions.push(Transaction::try_from(blob_transaction.as_ref()).unwrap());
+        assert_eq!(payload.transaction_count(), 2);
+        assert_eq!(payload.iter_transactions().nth(1), Some(blob_transaction.as_ref()));
+        assert_eq!(payload.blob_versioned_hashes().unwrap(), expected);
+    }
+}
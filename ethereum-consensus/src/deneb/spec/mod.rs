@@ -7,7 +7,7 @@ pub use crate::{
             TIMELY_SOURCE_FLAG_INDEX, TIMELY_SOURCE_WEIGHT, TIMELY_TARGET_FLAG_INDEX,
             TIMELY_TARGET_WEIGHT, WEIGHT_DENOMINATOR,
         },
-        helpers::{add_flag, has_flag},
+        helpers::{add_flag, has_flag, simulate_attestation_rewards},
         light_client::{
             CURRENT_SYNC_COMMITTEE_INDEX, CURRENT_SYNC_COMMITTEE_INDEX_FLOOR_LOG_2,
             FINALIZED_ROOT_INDEX, FINALIZED_ROOT_INDEX_FLOOR_LOG_2, NEXT_SYNC_COMMITTEE_INDEX,
@@ -44,7 +44,9 @@ pub use crate::{
         },
         epoch_processing::process_registry_updates,
         execution_engine::NewPayloadRequest,
-        execution_payload::{ExecutionPayload, ExecutionPayloadHeader},
+        execution_payload::{
+            get_blob_versioned_hashes, ExecutionPayload, ExecutionPayloadHeader, BLOB_TX_TYPE,
+        },
         fork::upgrade_to_deneb,
         genesis::initialize_beacon_state_from_eth1,
         helpers::{
@@ -63,21 +65,24 @@ pub use crate::{
         block_processing::{get_validator_from_deposit, xor},
         constants::{
             BASE_REWARDS_PER_EPOCH, DEPOSIT_CONTRACT_TREE_DEPTH, DEPOSIT_DATA_LIST_BOUND,
-            JUSTIFICATION_BITS_LENGTH,
+            JUSTIFICATION_BITS_LENGTH, TARGET_AGGREGATORS_PER_COMMITTEE,
         },
         helpers::{
-            compute_activation_exit_epoch, compute_committee, compute_domain,
-            compute_epoch_at_slot, compute_fork_data_root, compute_fork_digest,
+            aggregate_attestations, compute_activation_exit_epoch, compute_committee,
+            compute_domain, compute_epoch_at_slot, compute_fork_data_root, compute_fork_digest,
             compute_shuffled_index, compute_shuffled_indices, compute_start_slot_at_epoch,
             is_active_validator, is_eligible_for_activation_queue, is_slashable_attestation_data,
-            is_slashable_validator,
+            is_slashable_validator, verify_merkle_branch,
         },
         operations::{
             Attestation, AttestationData, AttesterSlashing, Checkpoint, Deposit, DepositData,
             DepositMessage, Eth1Data, IndexedAttestation, PendingAttestation, ProposerSlashing,
             SignedVoluntaryExit, VoluntaryExit,
         },
-        validator::{AggregateAndProof, Eth1Block, SignedAggregateAndProof, Validator},
+        validator::{
+            get_eth1_data, is_candidate_block, AggregateAndProof, Eth1Block,
+            SignedAggregateAndProof, Validator,
+        },
     },
     primitives::*,
     signing::*,
@@ -396,6 +401,43 @@ pub fn add_validator_to_registry<
     state.current_epoch_participation.push(ParticipationFlags::default());
     state.inactivity_scores.push(0);
 }
+pub fn get_sync_aggregate_rewards<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+>(
+    state: &BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+    >,
+    context: &Context,
+) -> Result<(Gwei, Gwei)> {
+    let total_active_increments =
+        get_total_active_balance(state, context)? / context.effective_balance_increment;
+    let total_base_rewards =
+        get_base_reward_per_increment(state, context)? * total_active_increments;
+    let max_participant_rewards =
+        total_base_rewards * SYNC_REWARD_WEIGHT / WEIGHT_DENOMINATOR / context.slots_per_epoch;
+    let participant_reward = max_participant_rewards / context.sync_committee_size as u64;
+    let proposer_reward =
+        participant_reward * PROPOSER_WEIGHT / (WEIGHT_DENOMINATOR - PROPOSER_WEIGHT);
+    Ok((participant_reward, proposer_reward))
+}
 pub fn process_sync_aggregate<
     const SLOTS_PER_HISTORICAL_ROOT: usize,
     const HISTORICAL_ROOTS_LIMIT: usize,
@@ -451,15 +493,7 @@ pub fn process_sync_aggregate<
             },
         )));
     }
-    let total_active_increments =
-        get_total_active_balance(state, context)? / context.effective_balance_increment;
-    let total_base_rewards =
-        get_base_reward_per_increment(state, context)? * total_active_increments;
-    let max_participant_rewards =
-        total_base_rewards * SYNC_REWARD_WEIGHT / WEIGHT_DENOMINATOR / context.slots_per_epoch;
-    let participant_reward = max_participant_rewards / context.sync_committee_size as u64;
-    let proposer_reward =
-        participant_reward * PROPOSER_WEIGHT / (WEIGHT_DENOMINATOR - PROPOSER_WEIGHT);
+    let (participant_reward, proposer_reward) = get_sync_aggregate_rewards(state, context)?;
     let all_public_keys = state
         .validators
         .iter()
@@ -483,6 +517,98 @@ pub fn process_sync_aggregate<
     }
     Ok(())
 }
+pub fn compute_block_reward<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+    const MAX_PROPOSER_SLASHINGS: usize,
+    const MAX_ATTESTER_SLASHINGS: usize,
+    const MAX_ATTESTATIONS: usize,
+    const MAX_DEPOSITS: usize,
+    const MAX_VOLUNTARY_EXITS: usize,
+    const MAX_BYTES_PER_TRANSACTION: usize,
+    const MAX_TRANSACTIONS_PER_PAYLOAD: usize,
+    const MAX_WITHDRAWALS_PER_PAYLOAD: usize,
+    const MAX_BLS_TO_EXECUTION_CHANGES: usize,
+    const MAX_BLOB_COMMITMENTS_PER_BLOCK: usize,
+>(
+    state: &BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+    >,
+    block: &BeaconBlock<
+        MAX_PROPOSER_SLASHINGS,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        MAX_ATTESTER_SLASHINGS,
+        MAX_ATTESTATIONS,
+        MAX_DEPOSITS,
+        MAX_VOLUNTARY_EXITS,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        MAX_BYTES_PER_TRANSACTION,
+        MAX_TRANSACTIONS_PER_PAYLOAD,
+        MAX_WITHDRAWALS_PER_PAYLOAD,
+        MAX_BLS_TO_EXECUTION_CHANGES,
+        MAX_BLOB_COMMITMENTS_PER_BLOCK,
+    >,
+    context: &Context,
+) -> Result<Gwei> {
+    let mut state = state.clone();
+    let proposer_index = get_beacon_proposer_index(&state, context)?;
+    let mut reward = 0;
+    for proposer_slashing in block.body.proposer_slashings.iter() {
+        process_proposer_slashing(&mut state, proposer_slashing, context)?;
+        let slashed_index = proposer_slashing.signed_header_1.message.proposer_index;
+        reward += get_whistleblower_reward(&state, slashed_index, context);
+    }
+    for attester_slashing in block.body.attester_slashings.iter() {
+        let indices_2 =
+            attester_slashing.attestation_2.attesting_indices.iter().collect::<HashSet<_>>();
+        let unslashed_indices = attester_slashing
+            .attestation_1
+            .attesting_indices
+            .iter()
+            .filter(|&index| {
+                indices_2.contains(index) &&
+                    state.validators.get(*index).is_some_and(|validator| !validator.slashed)
+            })
+            .copied()
+            .collect::<Vec<_>>();
+        process_attester_slashing(&mut state, attester_slashing, context)?;
+        for index in unslashed_indices {
+            if state.validators[index].slashed {
+                reward += get_whistleblower_reward(&state, index, context);
+            }
+        }
+    }
+    for attestation in block.body.attestations.iter() {
+        let balance = state.balances[proposer_index];
+        process_attestation(&mut state, attestation, context)?;
+        reward += state.balances[proposer_index] - balance;
+    }
+    let (_, proposer_reward) = get_sync_aggregate_rewards(&state, context)?;
+    process_sync_aggregate(&mut state, &block.body.sync_aggregate, context)?;
+    let participant_count =
+        block.body.sync_aggregate.sync_committee_bits.iter().filter(|bit| **bit).count();
+    reward += proposer_reward * participant_count as u64;
+    Ok(reward)
+}
 pub fn process_proposer_slashing<
     const SLOTS_PER_HISTORICAL_ROOT: usize,
     const HISTORICAL_ROOTS_LIMIT: usize,
@@ -700,7 +826,7 @@ pub fn process_deposit<
     let depth = DEPOSIT_CONTRACT_TREE_DEPTH + 1;
     let index = state.eth1_deposit_index as usize;
     let root = state.eth1_data.deposit_root;
-    if is_valid_merkle_branch(leaf, branch, depth, index, root).is_err() {
+    if !verify_merkle_branch(leaf, branch, depth, index, root) {
         return Err(invalid_operation_error(InvalidOperation::Deposit(
             InvalidDeposit::InvalidProof { leaf, branch: branch.to_vec(), depth, index, root },
         )));
@@ -712,6 +838,67 @@ pub fn process_deposit<
     let signature = &deposit.data.signature;
     apply_deposit(state, public_key, withdrawal_credentials, amount, signature, context)
 }
+pub fn verify_proposer_index<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+    const MAX_PROPOSER_SLASHINGS: usize,
+    const MAX_ATTESTER_SLASHINGS: usize,
+    const MAX_ATTESTATIONS: usize,
+    const MAX_DEPOSITS: usize,
+    const MAX_VOLUNTARY_EXITS: usize,
+    const MAX_BYTES_PER_TRANSACTION: usize,
+    const MAX_TRANSACTIONS_PER_PAYLOAD: usize,
+    const MAX_WITHDRAWALS_PER_PAYLOAD: usize,
+    const MAX_BLS_TO_EXECUTION_CHANGES: usize,
+    const MAX_BLOB_COMMITMENTS_PER_BLOCK: usize,
+>(
+    state: &BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+    >,
+    block: &BeaconBlock<
+        MAX_PROPOSER_SLASHINGS,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        MAX_ATTESTER_SLASHINGS,
+        MAX_ATTESTATIONS,
+        MAX_DEPOSITS,
+        MAX_VOLUNTARY_EXITS,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        MAX_BYTES_PER_TRANSACTION,
+        MAX_TRANSACTIONS_PER_PAYLOAD,
+        MAX_WITHDRAWALS_PER_PAYLOAD,
+        MAX_BLS_TO_EXECUTION_CHANGES,
+        MAX_BLOB_COMMITMENTS_PER_BLOCK,
+    >,
+    context: &Context,
+) -> Result<()> {
+    let proposer_index = get_beacon_proposer_index(state, context)?;
+    if block.proposer_index != proposer_index {
+        return Err(invalid_header_error(InvalidBeaconBlockHeader::ProposerIndexMismatch {
+            block_proposer_index: block.proposer_index,
+            proposer_index,
+        }));
+    }
+    Ok(())
+}
 pub fn process_block_header<
     const SLOTS_PER_HISTORICAL_ROOT: usize,
     const HISTORICAL_ROOTS_LIMIT: usize,
@@ -776,13 +963,7 @@ pub fn process_block_header<
             latest_block_header_slot: state.latest_block_header.slot,
         }));
     }
-    let proposer_index = get_beacon_proposer_index(state, context)?;
-    if block.proposer_index != proposer_index {
-        return Err(invalid_header_error(InvalidBeaconBlockHeader::ProposerIndexMismatch {
-            block_proposer_index: block.proposer_index,
-            proposer_index,
-        }));
-    }
+    verify_proposer_index(state, block, context)?;
     let expected_parent_root = state.latest_block_header.hash_tree_root()?;
     if block.parent_root != expected_parent_root {
         return Err(invalid_header_error(InvalidBeaconBlockHeader::ParentBlockRootMismatch {
@@ -799,7 +980,9 @@ pub fn process_block_header<
     };
     let proposer = &state.validators[block.proposer_index];
     if proposer.slashed {
-        return Err(invalid_header_error(InvalidBeaconBlockHeader::ProposerSlashed(proposer_index)));
+        return Err(invalid_header_error(InvalidBeaconBlockHeader::ProposerSlashed(
+            block.proposer_index,
+        )));
     }
     Ok(())
 }
@@ -2139,6 +2322,38 @@ pub fn get_unslashed_participating_indices<
         })
         .collect::<HashSet<_>>())
 }
+pub fn get_attestation_participation_flags<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+>(
+    state: &BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+    >,
+    data: &AttestationData,
+    inclusion_delay: u64,
+    context: &Context,
+) -> Result<ParticipationFlags> {
+    let flag_indices =
+        get_attestation_participation_flag_indices(state, data, inclusion_delay, context)?;
+    Ok(flag_indices.into_iter().fold(0, add_flag))
+}
 pub fn get_flag_index_deltas<
     const SLOTS_PER_HISTORICAL_ROOT: usize,
     const HISTORICAL_ROOTS_LIMIT: usize,
@@ -2193,6 +2408,35 @@ pub fn get_flag_index_deltas<
     }
     Ok((rewards, penalties))
 }
+pub fn get_whistleblower_reward<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+>(
+    state: &BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+    >,
+    slashed_index: ValidatorIndex,
+    context: &Context,
+) -> Gwei {
+    state.validators[slashed_index].effective_balance / context.whistleblower_reward_quotient
+}
 pub fn is_eligible_for_activation<
     const SLOTS_PER_HISTORICAL_ROOT: usize,
     const HISTORICAL_ROOTS_LIMIT: usize,
@@ -2353,6 +2597,43 @@ pub fn verify_block_signature<
     let public_key = &proposer.public_key;
     verify_signed_data(&signed_block.message, &signed_block.signature, public_key, domain)
 }
+pub fn verify_block_header_signature<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+>(
+    state: &BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+    >,
+    signed_header: &SignedBeaconBlockHeader,
+    context: &Context,
+) -> Result<()> {
+    let proposer_index = signed_header.message.proposer_index;
+    let proposer = state
+        .validators
+        .get(proposer_index)
+        .ok_or(Error::OutOfBounds { requested: proposer_index, bound: state.validators.len() })?;
+    let epoch = compute_epoch_at_slot(signed_header.message.slot, context);
+    let domain = get_domain(state, DomainType::BeaconProposer, Some(epoch), context)?;
+    let public_key = &proposer.public_key;
+    verify_signed_data(&signed_header.message, &signed_header.signature, public_key, domain)
+}
 pub fn get_domain<
     const SLOTS_PER_HISTORICAL_ROOT: usize,
     const HISTORICAL_ROOTS_LIMIT: usize,
@@ -3147,6 +3428,124 @@ pub fn get_eligible_validator_indices<
         }
     })
 }
+pub fn build_pubkey_index<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+>(
+    state: &BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+    >,
+) -> HashMap<BlsPublicKey, ValidatorIndex> {
+    let mut index = HashMap::with_capacity(state.validators.len());
+    update_pubkey_index(&mut index, state);
+    index
+}
+pub fn update_pubkey_index<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+>(
+    index: &mut HashMap<BlsPublicKey, ValidatorIndex>,
+    state: &BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+    >,
+) {
+    for (i, validator) in state.validators.iter().enumerate().skip(index.len()) {
+        index.insert(validator.public_key.clone(), i);
+    }
+}
+pub fn build_validator_pubkeys<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+>(
+    state: &BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+    >,
+) -> HashMap<ValidatorIndex, BlsPublicKey> {
+    let mut pubkeys = HashMap::with_capacity(state.validators.len());
+    update_validator_pubkeys(&mut pubkeys, state);
+    pubkeys
+}
+pub fn update_validator_pubkeys<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+>(
+    pubkeys: &mut HashMap<ValidatorIndex, BlsPublicKey>,
+    state: &BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+    >,
+) {
+    for (i, validator) in state.validators.iter().enumerate().skip(pubkeys.len()) {
+        pubkeys.insert(i, validator.public_key.clone());
+    }
+}
 pub fn process_slots<
     const SLOTS_PER_HISTORICAL_ROOT: usize,
     const HISTORICAL_ROOTS_LIMIT: usize,
@@ -3223,6 +3622,141 @@ pub fn process_slot<
     state.block_roots[root_index as usize] = previous_block_root;
     Ok(())
 }
+pub fn process_slots_with_cache<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+>(
+    state: &mut BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+    >,
+    slot: Slot,
+    cache: &mut ListRootCache<Validator>,
+    context: &Context,
+) -> Result<()> {
+    if state.slot >= slot {
+        return Err(Error::TransitionToPreviousSlot { requested: slot, current: state.slot });
+    }
+    while state.slot < slot {
+        process_slot_with_cache(state, cache, context)?;
+        if (state.slot + 1) % context.slots_per_epoch == 0 {
+            process_epoch(state, context)?;
+        }
+        state.slot += 1;
+    }
+    Ok(())
+}
+pub fn process_slot_with_cache<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+>(
+    state: &mut BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+    >,
+    cache: &mut ListRootCache<Validator>,
+    context: &Context,
+) -> Result<()> {
+    let previous_state_root = state.hash_tree_root_with_cache(cache)?;
+    let root_index = state.slot % context.slots_per_historical_root;
+    state.state_roots[root_index as usize] = previous_state_root;
+    if state.latest_block_header.state_root == Root::default() {
+        state.latest_block_header.state_root = previous_state_root;
+    }
+    let previous_block_root = state.latest_block_header.hash_tree_root()?;
+    let root_index = state.slot % context.slots_per_historical_root;
+    state.block_roots[root_index as usize] = previous_block_root;
+    Ok(())
+}
+pub fn validate_state_root<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+    const MAX_PROPOSER_SLASHINGS: usize,
+    const MAX_ATTESTER_SLASHINGS: usize,
+    const MAX_ATTESTATIONS: usize,
+    const MAX_DEPOSITS: usize,
+    const MAX_VOLUNTARY_EXITS: usize,
+    const MAX_BYTES_PER_TRANSACTION: usize,
+    const MAX_TRANSACTIONS_PER_PAYLOAD: usize,
+    const MAX_WITHDRAWALS_PER_PAYLOAD: usize,
+    const MAX_BLS_TO_EXECUTION_CHANGES: usize,
+    const MAX_BLOB_COMMITMENTS_PER_BLOCK: usize,
+>(
+    state: &BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+    >,
+    block: &BeaconBlock<
+        MAX_PROPOSER_SLASHINGS,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        MAX_ATTESTER_SLASHINGS,
+        MAX_ATTESTATIONS,
+        MAX_DEPOSITS,
+        MAX_VOLUNTARY_EXITS,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        MAX_BYTES_PER_TRANSACTION,
+        MAX_TRANSACTIONS_PER_PAYLOAD,
+        MAX_WITHDRAWALS_PER_PAYLOAD,
+        MAX_BLS_TO_EXECUTION_CHANGES,
+        MAX_BLOB_COMMITMENTS_PER_BLOCK,
+    >,
+) -> Result<()> {
+    let root = state.hash_tree_root()?;
+    if block.state_root != root {
+        return Err(Error::StateRootMismatch { expected: block.state_root, got: root });
+    }
+    Ok(())
+}
 pub fn state_transition_block_in_slot<
     const SLOTS_PER_HISTORICAL_ROOT: usize,
     const HISTORICAL_ROOTS_LIMIT: usize,
@@ -3280,16 +3814,16 @@ pub fn state_transition_block_in_slot<
         Validation::Enabled => true,
         Validation::Disabled => false,
     };
+    let block = &signed_block.message;
     if validate_result {
+        verify_proposer_index(state, block, context)?;
         verify_block_signature(state, signed_block, context)?;
     }
-    let block = &signed_block.message;
     process_block(state, block, context)?;
-    if validate_result && block.state_root != state.hash_tree_root()? {
-        Err(Error::InvalidStateRoot)
-    } else {
-        Ok(())
+    if validate_result {
+        validate_state_root(state, block)?;
     }
+    Ok(())
 }
 pub fn state_transition<
     const SLOTS_PER_HISTORICAL_ROOT: usize,
@@ -3347,3 +3881,283 @@ pub fn state_transition<
     process_slots(state, signed_block.message.slot, context)?;
     state_transition_block_in_slot(state, signed_block, validation, context)
 }
+pub fn compute_post_state_root<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+    const MAX_PROPOSER_SLASHINGS: usize,
+    const MAX_ATTESTER_SLASHINGS: usize,
+    const MAX_ATTESTATIONS: usize,
+    const MAX_DEPOSITS: usize,
+    const MAX_VOLUNTARY_EXITS: usize,
+    const MAX_BYTES_PER_TRANSACTION: usize,
+    const MAX_TRANSACTIONS_PER_PAYLOAD: usize,
+    const MAX_WITHDRAWALS_PER_PAYLOAD: usize,
+    const MAX_BLS_TO_EXECUTION_CHANGES: usize,
+    const MAX_BLOB_COMMITMENTS_PER_BLOCK: usize,
+>(
+    pre_state: &BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+    >,
+    signed_block: &SignedBeaconBlock<
+        MAX_PROPOSER_SLASHINGS,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        MAX_ATTESTER_SLASHINGS,
+        MAX_ATTESTATIONS,
+        MAX_DEPOSITS,
+        MAX_VOLUNTARY_EXITS,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        MAX_BYTES_PER_TRANSACTION,
+        MAX_TRANSACTIONS_PER_PAYLOAD,
+        MAX_WITHDRAWALS_PER_PAYLOAD,
+        MAX_BLS_TO_EXECUTION_CHANGES,
+        MAX_BLOB_COMMITMENTS_PER_BLOCK,
+    >,
+    context: &Context,
+) -> Result<Root> {
+    let mut state = pre_state.clone();
+    let block = &signed_block.message;
+    process_slots(&mut state, block.slot, context)?;
+    verify_proposer_index(&state, block, context)?;
+    verify_block_signature(&state, signed_block, context)?;
+    process_block(&mut state, block, context)?;
+    Ok(state.hash_tree_root()?)
+}
+pub fn compute_time_at_slot<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+>(
+    state: &BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+    >,
+    slot: Slot,
+    context: &Context,
+) -> u64 {
+    state.genesis_time + slot * context.seconds_per_slot
+}
+pub fn voting_period_start_time<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+>(
+    state: &BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+    >,
+    context: &Context,
+) -> u64 {
+    let slots_per_voting_period = context.epochs_per_eth1_voting_period * context.slots_per_epoch;
+    let eth1_voting_period_start_slot = state.slot - state.slot % slots_per_voting_period;
+    compute_time_at_slot(state, eth1_voting_period_start_slot, context)
+}
+pub fn get_eth1_vote<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+>(
+    state: &BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+    >,
+    eth1_chain: &[Eth1Block],
+    context: &Context,
+) -> Result<Eth1Data> {
+    let period_start = voting_period_start_time(state, context);
+    let mut votes_to_consider = vec![];
+    for block in eth1_chain {
+        if !is_candidate_block(block, period_start, context) {
+            continue;
+        }
+        let eth1_data = get_eth1_data(block)?;
+        if eth1_data.deposit_count >= state.eth1_data.deposit_count {
+            votes_to_consider.push(eth1_data);
+        }
+    }
+    let valid_votes = state
+        .eth1_data_votes
+        .iter()
+        .filter(|vote| votes_to_consider.contains(vote))
+        .collect::<Vec<_>>();
+    let mut selected_vote: Option<(&Eth1Data, usize)> = None;
+    for &vote in &valid_votes {
+        let count = valid_votes.iter().filter(|&&other| other == vote).count();
+        if selected_vote.is_none_or(|(_, selected_count)| count > selected_count) {
+            selected_vote = Some((vote, count));
+        }
+    }
+    let vote = match selected_vote {
+        Some((vote, _)) => vote,
+        None => votes_to_consider.last().unwrap_or(&state.eth1_data),
+    };
+    Ok(vote.clone())
+}
+pub fn is_aggregator<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+>(
+    state: &BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+    >,
+    slot: Slot,
+    index: CommitteeIndex,
+    slot_signature: &BlsSignature,
+    context: &Context,
+) -> Result<bool> {
+    let committee = get_beacon_committee(state, slot, index, context)?;
+    let modulo = usize::max(1, committee.len() / TARGET_AGGREGATORS_PER_COMMITTEE) as u64;
+    let digest: [u8; 8] = hash(slot_signature.as_ref()).as_ref()[..8].try_into().unwrap();
+    Ok(u64::from_le_bytes(digest) % modulo == 0)
+}
+pub fn verify_aggregate_and_proof<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+>(
+    state: &BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+    >,
+    signed_aggregate_and_proof: &SignedAggregateAndProof<MAX_VALIDATORS_PER_COMMITTEE>,
+    context: &Context,
+) -> Result<()> {
+    let aggregate_and_proof = &signed_aggregate_and_proof.message;
+    let aggregator_index = aggregate_and_proof.aggregator_index;
+    let aggregate = &aggregate_and_proof.aggregate;
+    let data = &aggregate.data;
+    let committee = get_beacon_committee(state, data.slot, data.index, context)?;
+    if !committee.contains(&aggregator_index) {
+        return Err(InvalidAggregateAndProof::AggregatorNotInCommittee(aggregator_index).into());
+    }
+    let selection_proof = &aggregate_and_proof.selection_proof;
+    if !is_aggregator(state, data.slot, data.index, selection_proof, context)? {
+        return Err(InvalidAggregateAndProof::NotAggregator(aggregator_index).into());
+    }
+    let public_key = &state.validators[aggregator_index].public_key;
+    let epoch = compute_epoch_at_slot(data.slot, context);
+    let domain = get_domain(state, DomainType::SelectionProof, Some(epoch), context)?;
+    verify_signed_data(&data.slot, selection_proof, public_key, domain)
+        .map_err(|_| InvalidAggregateAndProof::InvalidSelectionProof(aggregator_index))?;
+    let domain = get_domain(state, DomainType::AggregateAndProof, Some(epoch), context)?;
+    verify_signed_data(
+        aggregate_and_proof,
+        &signed_aggregate_and_proof.signature,
+        public_key,
+        domain,
+    )
+    .map_err(|_| InvalidAggregateAndProof::InvalidSignature(aggregator_index))?;
+    if aggregate.aggregation_bits.len() != committee.len() {
+        return Err(InvalidAggregateAndProof::AggregationBitsLengthMismatch {
+            expected: committee.len(),
+            provided: aggregate.aggregation_bits.len(),
+        }
+        .into());
+    }
+    let mut public_keys = vec![];
+    for (i, &index) in committee.iter().enumerate() {
+        if aggregate.aggregation_bits[i] {
+            public_keys.push(&state.validators[index].public_key);
+        }
+    }
+    let domain = get_domain(state, DomainType::BeaconAttester, Some(data.target.epoch), context)?;
+    let signing_root = compute_signing_root(data, domain)?;
+    fast_aggregate_verify(&public_keys, signing_root.as_ref(), &aggregate.signature)
+        .map_err(|_| InvalidAggregateAndProof::InvalidAggregateSignature)?;
+    Ok(())
+}
@@ -1,5 +1,6 @@
 use crate::{
     altair::SyncAggregate,
+    block_body::BlockBody,
     capella::SignedBlsToExecutionChange,
     crypto::KzgCommitment,
     electra::{
@@ -58,6 +59,110 @@ pub struct BeaconBlockBody<
     pub consolidations: List<SignedConsolidation, MAX_CONSOLIDATIONS>,
 }
 
+impl<
+        const MAX_PROPOSER_SLASHINGS: usize,
+        const MAX_VALIDATORS_PER_SLOT: usize,
+        const MAX_COMMITTEES_PER_SLOT: usize,
+        const MAX_ATTESTER_SLASHINGS: usize,
+        const MAX_ATTESTATIONS: usize,
+        const MAX_DEPOSITS: usize,
+        const MAX_VOLUNTARY_EXITS: usize,
+        const SYNC_COMMITTEE_SIZE: usize,
+        const BYTES_PER_LOGS_BLOOM: usize,
+        const MAX_EXTRA_DATA_BYTES: usize,
+        const MAX_BYTES_PER_TRANSACTION: usize,
+        const MAX_TRANSACTIONS_PER_PAYLOAD: usize,
+        const MAX_WITHDRAWALS_PER_PAYLOAD: usize,
+        const MAX_DEPOSIT_RECEIPTS_PER_PAYLOAD: usize,
+        const MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD: usize,
+        const MAX_BLS_TO_EXECUTION_CHANGES: usize,
+        const MAX_BLOB_COMMITMENTS_PER_BLOCK: usize,
+        const MAX_CONSOLIDATIONS: usize,
+    >
+    BeaconBlockBody<
+        MAX_PROPOSER_SLASHINGS,
+        MAX_VALIDATORS_PER_SLOT,
+        MAX_COMMITTEES_PER_SLOT,
+        MAX_ATTESTER_SLASHINGS,
+        MAX_ATTESTATIONS,
+        MAX_DEPOSITS,
+        MAX_VOLUNTARY_EXITS,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        MAX_BYTES_PER_TRANSACTION,
+        MAX_TRANSACTIONS_PER_PAYLOAD,
+        MAX_WITHDRAWALS_PER_PAYLOAD,
+        MAX_DEPOSIT_RECEIPTS_PER_PAYLOAD,
+        MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD,
+        MAX_BLS_TO_EXECUTION_CHANGES,
+        MAX_BLOB_COMMITMENTS_PER_BLOCK,
+        MAX_CONSOLIDATIONS,
+    >
+{
+    pub const MAX_PROPOSER_SLASHINGS: usize = MAX_PROPOSER_SLASHINGS;
+    pub const MAX_VALIDATORS_PER_SLOT: usize = MAX_VALIDATORS_PER_SLOT;
+    pub const MAX_COMMITTEES_PER_SLOT: usize = MAX_COMMITTEES_PER_SLOT;
+    pub const MAX_ATTESTER_SLASHINGS: usize = MAX_ATTESTER_SLASHINGS;
+    pub const MAX_ATTESTATIONS: usize = MAX_ATTESTATIONS;
+    pub const MAX_DEPOSITS: usize = MAX_DEPOSITS;
+    pub const MAX_VOLUNTARY_EXITS: usize = MAX_VOLUNTARY_EXITS;
+    pub const SYNC_COMMITTEE_SIZE: usize = SYNC_COMMITTEE_SIZE;
+    pub const BYTES_PER_LOGS_BLOOM: usize = BYTES_PER_LOGS_BLOOM;
+    pub const MAX_EXTRA_DATA_BYTES: usize = MAX_EXTRA_DATA_BYTES;
+    pub const MAX_BYTES_PER_TRANSACTION: usize = MAX_BYTES_PER_TRANSACTION;
+    pub const MAX_TRANSACTIONS_PER_PAYLOAD: usize = MAX_TRANSACTIONS_PER_PAYLOAD;
+    pub const MAX_WITHDRAWALS_PER_PAYLOAD: usize = MAX_WITHDRAWALS_PER_PAYLOAD;
+    pub const MAX_DEPOSIT_RECEIPTS_PER_PAYLOAD: usize = MAX_DEPOSIT_RECEIPTS_PER_PAYLOAD;
+    pub const MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD: usize = MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD;
+    pub const MAX_BLS_TO_EXECUTION_CHANGES: usize = MAX_BLS_TO_EXECUTION_CHANGES;
+    pub const MAX_BLOB_COMMITMENTS_PER_BLOCK: usize = MAX_BLOB_COMMITMENTS_PER_BLOCK;
+    pub const MAX_CONSOLIDATIONS: usize = MAX_CONSOLIDATIONS;
+}
+
+impl<
+        const MAX_PROPOSER_SLASHINGS: usize,
+        const MAX_VALIDATORS_PER_SLOT: usize,
+        const MAX_COMMITTEES_PER_SLOT: usize,
+        const MAX_ATTESTER_SLASHINGS: usize,
+        const MAX_ATTESTATIONS: usize,
+        const MAX_DEPOSITS: usize,
+        const MAX_VOLUNTARY_EXITS: usize,
+        const SYNC_COMMITTEE_SIZE: usize,
+        const BYTES_PER_LOGS_BLOOM: usize,
+        const MAX_EXTRA_DATA_BYTES: usize,
+        const MAX_BYTES_PER_TRANSACTION: usize,
+        const MAX_TRANSACTIONS_PER_PAYLOAD: usize,
+        const MAX_WITHDRAWALS_PER_PAYLOAD: usize,
+        const MAX_DEPOSIT_RECEIPTS_PER_PAYLOAD: usize,
+        const MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD: usize,
+        const MAX_BLS_TO_EXECUTION_CHANGES: usize,
+        const MAX_BLOB_COMMITMENTS_PER_BLOCK: usize,
+        const MAX_CONSOLIDATIONS: usize,
+    > BlockBody
+    for BeaconBlockBody<
+        MAX_PROPOSER_SLASHINGS,
+        MAX_VALIDATORS_PER_SLOT,
+        MAX_COMMITTEES_PER_SLOT,
+        MAX_ATTESTER_SLASHINGS,
+        MAX_ATTESTATIONS,
+        MAX_DEPOSITS,
+        MAX_VOLUNTARY_EXITS,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        MAX_BYTES_PER_TRANSACTION,
+        MAX_TRANSACTIONS_PER_PAYLOAD,
+        MAX_WITHDRAWALS_PER_PAYLOAD,
+        MAX_DEPOSIT_RECEIPTS_PER_PAYLOAD,
+        MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD,
+        MAX_BLS_TO_EXECUTION_CHANGES,
+        MAX_BLOB_COMMITMENTS_PER_BLOCK,
+        MAX_CONSOLIDATIONS,
+    >
+{
+}
+
 #[derive(
     Default, Debug, Clone, SimpleSerialize, PartialEq, Eq, serde::Serialize, serde::Deserialize,
 )]
@@ -109,6 +214,67 @@ pub struct BeaconBlock<
     >,
 }
 
+impl<
+        const MAX_PROPOSER_SLASHINGS: usize,
+        const MAX_VALIDATORS_PER_SLOT: usize,
+        const MAX_COMMITTEES_PER_SLOT: usize,
+        const MAX_ATTESTER_SLASHINGS: usize,
+        const MAX_ATTESTATIONS: usize,
+        const MAX_DEPOSITS: usize,
+        const MAX_VOLUNTARY_EXITS: usize,
+        const SYNC_COMMITTEE_SIZE: usize,
+        const BYTES_PER_LOGS_BLOOM: usize,
+        const MAX_EXTRA_DATA_BYTES: usize,
+        const MAX_BYTES_PER_TRANSACTION: usize,
+        const MAX_TRANSACTIONS_PER_PAYLOAD: usize,
+        const MAX_WITHDRAWALS_PER_PAYLOAD: usize,
+        const MAX_DEPOSIT_RECEIPTS_PER_PAYLOAD: usize,
+        const MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD: usize,
+        const MAX_BLS_TO_EXECUTION_CHANGES: usize,
+        const MAX_BLOB_COMMITMENTS_PER_BLOCK: usize,
+        const MAX_CONSOLIDATIONS: usize,
+    >
+    BeaconBlock<
+        MAX_PROPOSER_SLASHINGS,
+        MAX_VALIDATORS_PER_SLOT,
+        MAX_COMMITTEES_PER_SLOT,
+        MAX_ATTESTER_SLASHINGS,
+        MAX_ATTESTATIONS,
+        MAX_DEPOSITS,
+        MAX_VOLUNTARY_EXITS,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        MAX_BYTES_PER_TRANSACTION,
+        MAX_TRANSACTIONS_PER_PAYLOAD,
+        MAX_WITHDRAWALS_PER_PAYLOAD,
+        MAX_DEPOSIT_RECEIPTS_PER_PAYLOAD,
+        MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD,
+        MAX_BLS_TO_EXECUTION_CHANGES,
+        MAX_BLOB_COMMITMENTS_PER_BLOCK,
+        MAX_CONSOLIDATIONS,
+    >
+{
+    pub const MAX_PROPOSER_SLASHINGS: usize = MAX_PROPOSER_SLASHINGS;
+    pub const MAX_VALIDATORS_PER_SLOT: usize = MAX_VALIDATORS_PER_SLOT;
+    pub const MAX_COMMITTEES_PER_SLOT: usize = MAX_COMMITTEES_PER_SLOT;
+    pub const MAX_ATTESTER_SLASHINGS: usize = MAX_ATTESTER_SLASHINGS;
+    pub const MAX_ATTESTATIONS: usize = MAX_ATTESTATIONS;
+    pub const MAX_DEPOSITS: usize = MAX_DEPOSITS;
+    pub const MAX_VOLUNTARY_EXITS: usize = MAX_VOLUNTARY_EXITS;
+    pub const SYNC_COMMITTEE_SIZE: usize = SYNC_COMMITTEE_SIZE;
+    pub const BYTES_PER_LOGS_BLOOM: usize = BYTES_PER_LOGS_BLOOM;
+    pub const MAX_EXTRA_DATA_BYTES: usize = MAX_EXTRA_DATA_BYTES;
+    pub const MAX_BYTES_PER_TRANSACTION: usize = MAX_BYTES_PER_TRANSACTION;
+    pub const MAX_TRANSACTIONS_PER_PAYLOAD: usize = MAX_TRANSACTIONS_PER_PAYLOAD;
+    pub const MAX_WITHDRAWALS_PER_PAYLOAD: usize = MAX_WITHDRAWALS_PER_PAYLOAD;
+    pub const MAX_DEPOSIT_RECEIPTS_PER_PAYLOAD: usize = MAX_DEPOSIT_RECEIPTS_PER_PAYLOAD;
+    pub const MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD: usize = MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD;
+    pub const MAX_BLS_TO_EXECUTION_CHANGES: usize = MAX_BLS_TO_EXECUTION_CHANGES;
+    pub const MAX_BLOB_COMMITMENTS_PER_BLOCK: usize = MAX_BLOB_COMMITMENTS_PER_BLOCK;
+    pub const MAX_CONSOLIDATIONS: usize = MAX_CONSOLIDATIONS;
+}
+
 #[derive(
     Default, Debug, Clone, SimpleSerialize, PartialEq, Eq, serde::Serialize, serde::Deserialize,
 )]
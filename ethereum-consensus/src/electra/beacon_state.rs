@@ -34,6 +34,22 @@ pub struct PendingBalanceDeposit {
     pub amount: Gwei,
 }
 
+// Element of the `pending_deposits` queue as reported by beacon nodes following the final
+// Electra specification, which supersedes `PendingBalanceDeposit`.
+#[derive(
+    Default, Debug, Clone, SimpleSerialize, PartialEq, Eq, serde::Serialize, serde::Deserialize,
+)]
+pub struct PendingDeposit {
+    #[serde(rename = "pubkey")]
+    pub public_key: BlsPublicKey,
+    pub withdrawal_credentials: Bytes32,
+    #[serde(with = "crate::serde::as_str")]
+    pub amount: Gwei,
+    pub signature: BlsSignature,
+    #[serde(with = "crate::serde::as_str")]
+    pub slot: Slot,
+}
+
 #[derive(
     Default, Debug, Clone, SimpleSerialize, PartialEq, Eq, serde::Serialize, serde::Deserialize,
 )]
@@ -67,6 +83,17 @@ pub struct ExecutionLayerWithdrawalRequest {
     pub amount: Gwei,
 }
 
+#[derive(
+    Default, Debug, Clone, SimpleSerialize, PartialEq, Eq, serde::Serialize, serde::Deserialize,
+)]
+pub struct ConsolidationRequest {
+    pub source_address: ExecutionAddress,
+    #[serde(rename = "source_pubkey")]
+    pub source_public_key: BlsPublicKey,
+    #[serde(rename = "target_pubkey")]
+    pub target_public_key: BlsPublicKey,
+}
+
 #[derive(
     Default, Debug, SimpleSerialize, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize,
 )]
@@ -141,3 +168,175 @@ pub struct BeaconState<
         List<PendingPartialWithdrawal, PENDING_PARTIAL_WITHDRAWALS_LIMIT>,
     pub pending_consolidations: List<PendingConsolidation, PENDING_CONSOLIDATIONS_LIMIT>,
 }
+
+impl<
+        const SLOTS_PER_HISTORICAL_ROOT: usize,
+        const HISTORICAL_ROOTS_LIMIT: usize,
+        const ETH1_DATA_VOTES_BOUND: usize,
+        const VALIDATOR_REGISTRY_LIMIT: usize,
+        const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+        const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+        const MAX_VALIDATORS_PER_COMMITTEE: usize,
+        const SYNC_COMMITTEE_SIZE: usize,
+        const BYTES_PER_LOGS_BLOOM: usize,
+        const MAX_EXTRA_DATA_BYTES: usize,
+        const PENDING_BALANCE_DEPOSITS_LIMIT: usize,
+        const PENDING_PARTIAL_WITHDRAWALS_LIMIT: usize,
+        const PENDING_CONSOLIDATIONS_LIMIT: usize,
+    >
+    BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        PENDING_BALANCE_DEPOSITS_LIMIT,
+        PENDING_PARTIAL_WITHDRAWALS_LIMIT,
+        PENDING_CONSOLIDATIONS_LIMIT,
+    >
+{
+    // Same as `phase0::BeaconState::hash_tree_root_with_cache`.
+    pub fn hash_tree_root_with_cache(
+        &self,
+        cache: &mut ListRootCache<Validator>,
+    ) -> Result<Node, MerkleizationError> {
+        let field_roots = [
+            self.genesis_time.hash_tree_root()?,
+            self.genesis_validators_root.hash_tree_root()?,
+            self.slot.hash_tree_root()?,
+            self.fork.hash_tree_root()?,
+            self.latest_block_header.hash_tree_root()?,
+            self.block_roots.hash_tree_root()?,
+            self.state_roots.hash_tree_root()?,
+            self.historical_roots.hash_tree_root()?,
+            self.eth1_data.hash_tree_root()?,
+            self.eth1_data_votes.hash_tree_root()?,
+            self.eth1_deposit_index.hash_tree_root()?,
+            cache.hash_tree_root(&self.validators)?,
+            self.balances.hash_tree_root()?,
+            self.randao_mixes.hash_tree_root()?,
+            self.slashings.hash_tree_root()?,
+            self.previous_epoch_participation.hash_tree_root()?,
+            self.current_epoch_participation.hash_tree_root()?,
+            self.justification_bits.hash_tree_root()?,
+            self.previous_justified_checkpoint.hash_tree_root()?,
+            self.current_justified_checkpoint.hash_tree_root()?,
+            self.finalized_checkpoint.hash_tree_root()?,
+            self.inactivity_scores.hash_tree_root()?,
+            self.current_sync_committee.hash_tree_root()?,
+            self.next_sync_committee.hash_tree_root()?,
+            self.latest_execution_payload_header.hash_tree_root()?,
+            self.next_withdrawal_index.hash_tree_root()?,
+            self.next_withdrawal_validator_index.hash_tree_root()?,
+            self.historical_summaries.hash_tree_root()?,
+            self.deposit_receipts_start_index.hash_tree_root()?,
+            self.deposit_balance_to_consume.hash_tree_root()?,
+            self.exit_balance_to_consume.hash_tree_root()?,
+            self.earliest_exit_epoch.hash_tree_root()?,
+            self.consolidation_balance_to_consume.hash_tree_root()?,
+            self.earliest_consolidation_epoch.hash_tree_root()?,
+            self.pending_balance_deposits.hash_tree_root()?,
+            self.pending_partial_withdrawals.hash_tree_root()?,
+            self.pending_consolidations.hash_tree_root()?,
+        ];
+        Ok(merkleize_chunks(&field_roots))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        deneb::minimal::{
+            BYTES_PER_LOGS_BLOOM, EPOCHS_PER_HISTORICAL_VECTOR, EPOCHS_PER_SLASHINGS_VECTOR,
+            ETH1_DATA_VOTES_BOUND, HISTORICAL_ROOTS_LIMIT, MAX_EXTRA_DATA_BYTES,
+            MAX_VALIDATORS_PER_COMMITTEE, SLOTS_PER_HISTORICAL_ROOT, SYNC_COMMITTEE_SIZE,
+            VALIDATOR_REGISTRY_LIMIT,
+        },
+        electra::minimal::{
+            PENDING_BALANCE_DEPOSITS_LIMIT, PENDING_CONSOLIDATIONS_LIMIT,
+            PENDING_PARTIAL_WITHDRAWALS_LIMIT,
+        },
+    };
+
+    type BeaconState = super::BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        PENDING_BALANCE_DEPOSITS_LIMIT,
+        PENDING_PARTIAL_WITHDRAWALS_LIMIT,
+        PENDING_CONSOLIDATIONS_LIMIT,
+    >;
+
+    #[test]
+    fn test_deserialize_pending_queues() {
+        let public_key = format!("0x{}", "a1".repeat(48));
+        let signature = format!("0x{}", "b2".repeat(96));
+        let deposits = format!(
+            r#"[{{"pubkey":"{public_key}","withdrawal_credentials":"0x{}","amount":"32000000000","signature":"{signature}","slot":"1234"}}]"#,
+            "01".repeat(32)
+        );
+        let deposits: Vec<PendingDeposit> = serde_json::from_str(&deposits).unwrap();
+        assert_eq!(deposits.len(), 1);
+        assert_eq!(deposits[0].public_key.as_slice(), [0xa1; 48].as_slice());
+        assert_eq!(deposits[0].withdrawal_credentials.as_slice(), [0x01; 32].as_slice());
+        assert_eq!(deposits[0].amount, 32_000_000_000);
+        assert_eq!(deposits[0].slot, 1234);
+
+        let withdrawals = r#"[{"index":"3","amount":"1000000000","withdrawable_epoch":"15"}]"#;
+        let withdrawals: Vec<PendingPartialWithdrawal> = serde_json::from_str(withdrawals).unwrap();
+        assert_eq!(
+            withdrawals,
+            vec![PendingPartialWithdrawal {
+                index: 3,
+                amount: 1_000_000_000,
+                withdrawable_epoch: 15
+            }]
+        );
+
+        let consolidations = r#"[{"source_index":"7","target_index":"9"}]"#;
+        let consolidations: Vec<PendingConsolidation> =
+            serde_json::from_str(consolidations).unwrap();
+        assert_eq!(consolidations, vec![PendingConsolidation { source_index: 7, target_index: 9 }]);
+    }
+
+    #[test]
+    fn test_hash_tree_root_with_cache() {
+        let mut state = BeaconState::default();
+        let mut cache = ListRootCache::new();
+        assert_eq!(
+            state.hash_tree_root_with_cache(&mut cache).unwrap(),
+            state.hash_tree_root().unwrap()
+        );
+
+        for i in 0..10 {
+            state.validators.push(Validator { effective_balance: i, ..Default::default() });
+            state.balances.push(i);
+        }
+        assert_eq!(
+            state.hash_tree_root_with_cache(&mut cache).unwrap(),
+            state.hash_tree_root().unwrap()
+        );
+
+        state.slot = 2;
+        state.validators[7].slashed = true;
+        state.latest_execution_payload_header.blob_gas_used = 4;
+        state.earliest_consolidation_epoch = 5;
+        assert_eq!(
+            state.hash_tree_root_with_cache(&mut cache).unwrap(),
+            state.hash_tree_root().unwrap()
+        );
+    }
+}
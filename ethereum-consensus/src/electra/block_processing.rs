@@ -1,18 +1,19 @@
 use crate::{
     crypto::fast_aggregate_verify,
     electra::{
-        add_flag, compute_consolidation_epoch_and_update_churn, compute_domain,
-        compute_epoch_at_slot, compute_exit_epoch_and_update_churn, compute_signing_root,
-        compute_timestamp_at_slot, decrease_balance, get_attestation_participation_flag_indices,
-        get_attesting_indices, get_base_reward, get_beacon_committee, get_beacon_proposer_index,
-        get_committee_count_per_slot, get_committee_indices, get_consolidation_churn_limit,
-        get_current_epoch, get_indexed_attestation, get_pending_balance_to_withdraw,
-        get_previous_epoch, get_randao_mix, get_validator_max_effective_balance,
-        has_compounding_withdrawal_credential, has_eth1_withdrawal_credential,
+        add_flag, can_migrate_to_compounding, compute_consolidation_epoch_and_update_churn,
+        compute_domain, compute_epoch_at_slot, compute_exit_epoch_and_update_churn,
+        compute_signing_root, compute_timestamp_at_slot, decrease_balance,
+        get_attestation_participation_flag_indices, get_attesting_indices, get_base_reward,
+        get_beacon_committee, get_beacon_proposer_index, get_committee_count_per_slot,
+        get_committee_indices, get_consolidation_churn_limit, get_current_epoch,
+        get_indexed_attestation, get_pending_balance_to_withdraw, get_previous_epoch,
+        get_randao_mix, get_validator_max_effective_balance, has_compounding_withdrawal_credential,
         has_execution_withdrawal_credential, has_flag, increase_balance, initiate_validator_exit,
-        invalid_operation_error, is_active_validator, is_compounding_withdrawal_credential,
-        is_fully_withdrawable_validator, is_partially_withdrawable_validator,
-        is_valid_indexed_attestation, kzg_commitment_to_versioned_hash, process_attester_slashing,
+        invalid_execution_payload_error, invalid_operation_error, is_active_validator,
+        is_compounding_withdrawal_credential, is_fully_withdrawable_validator,
+        is_partially_withdrawable_validator, is_valid_indexed_attestation,
+        kzg_commitment_to_versioned_hash, process_attester_slashing,
         process_bls_to_execution_change, process_deposit, process_proposer_slashing,
         switch_to_compounding_validator, verify_signed_data, Attestation, BeaconBlockBody,
         BeaconState, BlsPublicKey, BlsSignature, Bytes32, DepositMessage, DepositReceipt,
@@ -343,7 +344,9 @@ pub fn process_execution_payload<
         versioned_hashes,
         parent_beacon_block_root: state.latest_block_header.parent_root,
     };
-    execution_engine.verify_and_notify_new_payload(&new_payload_request)?;
+    execution_engine
+        .verify_and_notify_new_payload(&new_payload_request)
+        .map_err(invalid_execution_payload_error)?;
 
     state.latest_execution_payload_header = ExecutionPayloadHeader {
         parent_hash: payload.parent_hash.clone(),
@@ -439,20 +442,16 @@ pub fn process_operations<
 ) -> Result<(), Error> {
     let eth1_deposit_index_limit =
         u64::min(state.eth1_data.deposit_count, state.deposit_receipts_start_index);
-    if state.eth1_deposit_index < eth1_deposit_index_limit {
-        let expected = u64::min(
-            context.max_deposits as u64,
-            eth1_deposit_index_limit - state.eth1_deposit_index,
-        ) as usize;
-        if body.deposits.len() != expected {
-            return Err(invalid_operation_error(InvalidOperation::Deposit(
-                InvalidDeposit::IncorrectCount { expected, count: body.deposits.len() },
-            )));
-        } else if !body.deposits.is_empty() {
-            return Err(invalid_operation_error(InvalidOperation::Deposit(
-                InvalidDeposit::IncorrectCount { expected: 0, count: body.deposits.len() },
-            )));
-        }
+    let expected = if state.eth1_deposit_index < eth1_deposit_index_limit {
+        u64::min(context.max_deposits as u64, eth1_deposit_index_limit - state.eth1_deposit_index)
+            as usize
+    } else {
+        0
+    };
+    if body.deposits.len() != expected {
+        return Err(invalid_operation_error(InvalidOperation::Deposit(
+            InvalidDeposit::IncorrectCount { expected, count: body.deposits.len() },
+        )))
     }
 
     body.proposer_slashings
@@ -667,7 +666,7 @@ pub fn apply_deposit<
 
         // NOTE: if we did not return from signature check, then we know the signature is valid
         if is_compounding_withdrawal_credential(withdrawal_credentials) &&
-            has_eth1_withdrawal_credential(validator)
+            can_migrate_to_compounding(validator)
         {
             switch_to_compounding_validator(state, index, context)?;
         }
@@ -1150,3 +1149,126 @@ pub fn process_consolidation<
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        deneb::minimal::{
+            BYTES_PER_LOGS_BLOOM, EPOCHS_PER_HISTORICAL_VECTOR, EPOCHS_PER_SLASHINGS_VECTOR,
+            ETH1_DATA_VOTES_BOUND, HISTORICAL_ROOTS_LIMIT, MAX_ATTESTATIONS,
+            MAX_ATTESTER_SLASHINGS, MAX_BLOB_COMMITMENTS_PER_BLOCK, MAX_BLS_TO_EXECUTION_CHANGES,
+            MAX_BYTES_PER_TRANSACTION, MAX_DEPOSITS, MAX_EXTRA_DATA_BYTES, MAX_PROPOSER_SLASHINGS,
+            MAX_TRANSACTIONS_PER_PAYLOAD, MAX_VALIDATORS_PER_COMMITTEE, MAX_VOLUNTARY_EXITS,
+            MAX_WITHDRAWALS_PER_PAYLOAD, SLOTS_PER_HISTORICAL_ROOT, SYNC_COMMITTEE_SIZE,
+            VALIDATOR_REGISTRY_LIMIT,
+        },
+        electra::{
+            minimal::{
+                MAX_CONSOLIDATIONS, MAX_DEPOSIT_RECEIPTS_PER_PAYLOAD,
+                MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD, PENDING_BALANCE_DEPOSITS_LIMIT,
+                PENDING_CONSOLIDATIONS_LIMIT, PENDING_PARTIAL_WITHDRAWALS_LIMIT,
+            },
+            Deposit,
+        },
+        error::InvalidBlock,
+    };
+
+    const MAX_COMMITTEES_PER_SLOT: usize = 4;
+
+    type BeaconState = super::BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        PENDING_BALANCE_DEPOSITS_LIMIT,
+        PENDING_PARTIAL_WITHDRAWALS_LIMIT,
+        PENDING_CONSOLIDATIONS_LIMIT,
+    >;
+    type BeaconBlockBody = super::BeaconBlockBody<
+        MAX_PROPOSER_SLASHINGS,
+        { MAX_VALIDATORS_PER_COMMITTEE * MAX_COMMITTEES_PER_SLOT },
+        MAX_COMMITTEES_PER_SLOT,
+        MAX_ATTESTER_SLASHINGS,
+        MAX_ATTESTATIONS,
+        MAX_DEPOSITS,
+        MAX_VOLUNTARY_EXITS,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        MAX_BYTES_PER_TRANSACTION,
+        MAX_TRANSACTIONS_PER_PAYLOAD,
+        MAX_WITHDRAWALS_PER_PAYLOAD,
+        MAX_DEPOSIT_RECEIPTS_PER_PAYLOAD,
+        MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD,
+        MAX_BLS_TO_EXECUTION_CHANGES,
+        MAX_BLOB_COMMITMENTS_PER_BLOCK,
+        MAX_CONSOLIDATIONS,
+    >;
+
+    // Run `process_operations` over a body with `count` deposits, returning the expected count
+    // if it was rejected for the number of deposits. The deposits carry no valid proofs, so
+    // a body with the right count fails on the first proof instead.
+    fn deposit_count_error(state: &BeaconState, count: usize, context: &Context) -> Option<usize> {
+        let mut state = state.clone();
+        let mut body = BeaconBlockBody::default();
+        for _ in 0..count {
+            body.deposits.push(Deposit::default());
+        }
+        let Err(err) = process_operations(&mut state, &body, context) else {
+            assert_eq!(count, 0);
+            return None
+        };
+        let Error::InvalidBlock(invalid_block) = &err else { panic!("unexpected error {err:?}") };
+        match **invalid_block {
+            InvalidBlock::InvalidOperation(InvalidOperation::Deposit(
+                InvalidDeposit::IncorrectCount { expected, count: provided },
+            )) => {
+                assert_eq!(provided, count);
+                Some(expected)
+            }
+            InvalidBlock::InvalidOperation(InvalidOperation::Deposit(
+                InvalidDeposit::InvalidProof { .. },
+            )) => {
+                assert!(count > 0);
+                None
+            }
+            _ => panic!("unexpected error {err:?}"),
+        }
+    }
+
+    #[test]
+    fn test_process_operations_deposit_count() {
+        let context = Context::for_minimal();
+        let mut state = BeaconState::default();
+        state.eth1_data.deposit_count = 20;
+        state.deposit_receipts_start_index = UNSET_DEPOSIT_RECEIPTS_START_INDEX;
+
+        // more deposits are pending than fit in a block
+        assert_eq!(deposit_count_error(&state, MAX_DEPOSITS, &context), None);
+        for count in [0, 1, MAX_DEPOSITS - 1] {
+            assert_eq!(deposit_count_error(&state, count, &context), Some(MAX_DEPOSITS));
+        }
+
+        // only the deposits before the first deposit receipt are expected from eth1
+        state.eth1_deposit_index = 10;
+        state.deposit_receipts_start_index = 12;
+        assert_eq!(deposit_count_error(&state, 2, &context), None);
+        for count in [0, 1, 3, MAX_DEPOSITS] {
+            assert_eq!(deposit_count_error(&state, count, &context), Some(2));
+        }
+
+        // once the eth1 bridge has caught up with the deposit receipts, no deposits are expected
+        for eth1_deposit_index in [12, 15] {
+            state.eth1_deposit_index = eth1_deposit_index;
+            assert_eq!(deposit_count_error(&state, 0, &context), None);
+            assert_eq!(deposit_count_error(&state, 1, &context), Some(0));
+        }
+    }
+}
@@ -2,3 +2,7 @@ use crate::primitives::Gwei;
 
 pub const UNSET_DEPOSIT_RECEIPTS_START_INDEX: u64 = u64::MAX;
 pub const FULL_EXIT_REQUEST_AMOUNT: Gwei = 0;
+
+pub const DEPOSIT_REQUEST_TYPE: u8 = 0x00;
+pub const WITHDRAWAL_REQUEST_TYPE: u8 = 0x01;
+pub const CONSOLIDATION_REQUEST_TYPE: u8 = 0x02;
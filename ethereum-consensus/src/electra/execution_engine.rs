@@ -1,6 +1,15 @@
 use crate::{
-    deneb::blob_sidecar::VersionedHash, electra::ExecutionPayload,
-    execution_engine::PayloadRequest, primitives::Root,
+    deneb::blob_sidecar::VersionedHash,
+    electra::{
+        beacon_state::{ConsolidationRequest, DepositReceipt, ExecutionLayerWithdrawalRequest},
+        constants::{CONSOLIDATION_REQUEST_TYPE, DEPOSIT_REQUEST_TYPE, WITHDRAWAL_REQUEST_TYPE},
+        ExecutionPayload,
+    },
+    execution_engine::PayloadRequest,
+    primitives::Root,
+    ssz::prelude::*,
+    state_transition::Result,
+    Error,
 };
 
 pub struct NewPayloadRequest<
@@ -45,3 +54,157 @@ impl<
     >
 {
 }
+
+#[derive(
+    Default, Debug, SimpleSerialize, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize,
+)]
+pub struct ExecutionRequests<
+    const MAX_DEPOSIT_RECEIPTS_PER_PAYLOAD: usize,
+    const MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD: usize,
+    const MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD: usize,
+> {
+    pub deposits: List<DepositReceipt, MAX_DEPOSIT_RECEIPTS_PER_PAYLOAD>,
+    pub withdrawals: List<ExecutionLayerWithdrawalRequest, MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD>,
+    pub consolidations: List<ConsolidationRequest, MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD>,
+}
+
+// Encode `execution_requests` for the engine API: each non-empty list of requests
+// is SSZ-serialized and prefixed with its request type, in ascending order of type.
+pub fn get_execution_requests_list<
+    const MAX_DEPOSIT_RECEIPTS_PER_PAYLOAD: usize,
+    const MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD: usize,
+    const MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD: usize,
+>(
+    execution_requests: &ExecutionRequests<
+        MAX_DEPOSIT_RECEIPTS_PER_PAYLOAD,
+        MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD,
+        MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD,
+    >,
+) -> Result<Vec<Vec<u8>>> {
+    let encodings = [
+        (DEPOSIT_REQUEST_TYPE, serialize(&execution_requests.deposits)),
+        (WITHDRAWAL_REQUEST_TYPE, serialize(&execution_requests.withdrawals)),
+        (CONSOLIDATION_REQUEST_TYPE, serialize(&execution_requests.consolidations)),
+    ];
+    let mut requests_list = vec![];
+    for (request_type, encoding) in encodings {
+        let encoding = encoding.map_err(SimpleSerializeError::from)?;
+        if !encoding.is_empty() {
+            let mut request = Vec::with_capacity(1 + encoding.len());
+            request.push(request_type);
+            request.extend(encoding);
+            requests_list.push(request);
+        }
+    }
+    Ok(requests_list)
+}
+
+// Decode the engine API encoding produced by `get_execution_requests_list`.
+pub fn get_execution_requests<
+    const MAX_DEPOSIT_RECEIPTS_PER_PAYLOAD: usize,
+    const MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD: usize,
+    const MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD: usize,
+>(
+    requests_list: &[Vec<u8>],
+) -> Result<
+    ExecutionRequests<
+        MAX_DEPOSIT_RECEIPTS_PER_PAYLOAD,
+        MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD,
+        MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD,
+    >,
+> {
+    let mut execution_requests = ExecutionRequests::default();
+    let mut previous_type = None;
+    for request in requests_list {
+        let (&request_type, data) =
+            request.split_first().ok_or(Error::InvalidExecutionRequestsOrder)?;
+        if data.is_empty() || previous_type.is_some_and(|previous| request_type <= previous) {
+            return Err(Error::InvalidExecutionRequestsOrder)
+        }
+        previous_type = Some(request_type);
+
+        match request_type {
+            DEPOSIT_REQUEST_TYPE => {
+                execution_requests.deposits =
+                    deserialize(data).map_err(SimpleSerializeError::from)?;
+            }
+            WITHDRAWAL_REQUEST_TYPE => {
+                execution_requests.withdrawals =
+                    deserialize(data).map_err(SimpleSerializeError::from)?;
+            }
+            CONSOLIDATION_REQUEST_TYPE => {
+                execution_requests.consolidations =
+                    deserialize(data).map_err(SimpleSerializeError::from)?;
+            }
+            request_type => return Err(Error::UnknownExecutionRequestType(request_type)),
+        }
+    }
+    Ok(execution_requests)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type ExecutionRequests = super::ExecutionRequests<4, 2, 2>;
+
+    #[test]
+    fn test_execution_requests_round_trip() {
+        let deposit = DepositReceipt { amount: 32, index: 7, ..Default::default() };
+        let withdrawal = ExecutionLayerWithdrawalRequest { amount: 1, ..Default::default() };
+        let consolidation = ConsolidationRequest::default();
+        let execution_requests = ExecutionRequests {
+            deposits: List::try_from(vec![deposit.clone(), deposit]).unwrap(),
+            withdrawals: List::try_from(vec![withdrawal]).unwrap(),
+            consolidations: List::try_from(vec![consolidation]).unwrap(),
+        };
+
+        let requests_list = get_execution_requests_list(&execution_requests).unwrap();
+        assert_eq!(requests_list.len(), 3);
+        for (request, request_type) in requests_list.iter().zip([
+            DEPOSIT_REQUEST_TYPE,
+            WITHDRAWAL_REQUEST_TYPE,
+            CONSOLIDATION_REQUEST_TYPE,
+        ]) {
+            assert_eq!(request[0], request_type);
+        }
+        assert_eq!(requests_list[0].len(), 1 + 2 * 192);
+        let recovered: ExecutionRequests = get_execution_requests(&requests_list).unwrap();
+        assert_eq!(recovered, execution_requests);
+
+        // empty lists are omitted from the encoding
+        let execution_requests = ExecutionRequests {
+            consolidations: execution_requests.consolidations,
+            ..Default::default()
+        };
+        let requests_list = get_execution_requests_list(&execution_requests).unwrap();
+        assert_eq!(requests_list.len(), 1);
+        assert_eq!(requests_list[0][0], CONSOLIDATION_REQUEST_TYPE);
+        let recovered: ExecutionRequests = get_execution_requests(&requests_list).unwrap();
+        assert_eq!(recovered, execution_requests);
+    }
+
+    #[test]
+    fn test_execution_requests_framing() {
+        let execution_requests = ExecutionRequests {
+            withdrawals: List::try_from(vec![Default::default()]).unwrap(),
+            consolidations: List::try_from(vec![Default::default()]).unwrap(),
+            ..Default::default()
+        };
+        let mut requests_list = get_execution_requests_list(&execution_requests).unwrap();
+
+        requests_list.swap(0, 1);
+        let result: Result<ExecutionRequests> = get_execution_requests(&requests_list);
+        assert!(matches!(result, Err(Error::InvalidExecutionRequestsOrder)));
+
+        let result: Result<ExecutionRequests> = get_execution_requests(&[vec![0x01]]);
+        assert!(matches!(result, Err(Error::InvalidExecutionRequestsOrder)));
+
+        let result: Result<ExecutionRequests> = get_execution_requests(&[vec![0x03, 0x00]]);
+        assert!(matches!(result, Err(Error::UnknownExecutionRequestType(0x03))));
+
+        // truncated request data
+        let result: Result<ExecutionRequests> = get_execution_requests(&[vec![0x01, 0x00]]);
+        assert!(matches!(result, Err(Error::SimpleSerialize(..))));
+    }
+}
@@ -1,6 +1,7 @@
 use crate::{
     altair::{PROPOSER_WEIGHT, WEIGHT_DENOMINATOR},
     capella::has_eth1_withdrawal_credential,
+    crypto::aggregate,
     electra::{
         beacon_state::{BeaconState, PendingBalanceDeposit},
         decrease_balance, get_beacon_committee, get_beacon_proposer_index, get_current_epoch,
@@ -36,6 +37,11 @@ pub fn has_execution_withdrawal_credential(validator: &Validator) -> bool {
     has_compounding_withdrawal_credential(validator) || has_eth1_withdrawal_credential(validator)
 }
 
+// A validator with `0x01` withdrawal credentials can be switched to compounding (`0x02`) ones.
+pub fn can_migrate_to_compounding(validator: &Validator) -> bool {
+    has_eth1_withdrawal_credential(validator)
+}
+
 pub fn is_fully_withdrawable_validator(validator: &Validator, balance: Gwei, epoch: Epoch) -> bool {
     has_execution_withdrawal_credential(validator) &&
         validator.withdrawable_epoch <= epoch &&
@@ -299,18 +305,49 @@ pub fn get_attesting_indices<
 
     let mut indices = HashSet::with_capacity(MAX_VALIDATORS_PER_SLOT);
 
-    for (committee_offset, index) in committee_indices.into_iter().enumerate() {
+    // `aggregation_bits` covers the selected committees concatenated in committee index order
+    let mut committee_offset = 0;
+    for index in committee_indices {
         let committee = get_beacon_committee(state, attestation.data.slot, index, context)?;
         for (i, validator_index) in committee.iter().enumerate() {
             if attestation.aggregation_bits[committee_offset + i] {
                 indices.insert(*validator_index);
             }
         }
+        committee_offset += committee.len();
     }
 
     Ok(indices)
 }
 
+// Merge two aggregates over the same `AttestationData` and committees with disjoint participants.
+pub fn aggregate_attestations<
+    const MAX_VALIDATORS_PER_SLOT: usize,
+    const MAX_COMMITTEES_PER_SLOT: usize,
+>(
+    a: &Attestation<MAX_VALIDATORS_PER_SLOT, MAX_COMMITTEES_PER_SLOT>,
+    b: &Attestation<MAX_VALIDATORS_PER_SLOT, MAX_COMMITTEES_PER_SLOT>,
+) -> Result<Attestation<MAX_VALIDATORS_PER_SLOT, MAX_COMMITTEES_PER_SLOT>, Error> {
+    if a.data != b.data ||
+        a.committee_bits != b.committee_bits ||
+        a.aggregation_bits.len() != b.aggregation_bits.len()
+    {
+        return Err(Error::IncompatibleAttestations)
+    }
+    let overlap = a.aggregation_bits.intersection(&b.aggregation_bits).expect("same length");
+    if overlap.count_ones() != 0 {
+        return Err(Error::OverlappingAggregationBits)
+    }
+    let aggregation_bits = a.aggregation_bits.union(&b.aggregation_bits).expect("same length");
+    let signature = aggregate(&[a.signature.clone(), b.signature.clone()])?;
+    Ok(Attestation {
+        aggregation_bits,
+        data: a.data.clone(),
+        committee_bits: a.committee_bits.clone(),
+        signature,
+    })
+}
+
 pub fn get_indexed_attestation<
     const SLOTS_PER_HISTORICAL_ROOT: usize,
     const HISTORICAL_ROOTS_LIMIT: usize,
@@ -442,7 +479,7 @@ pub fn switch_to_compounding_validator<
     index: ValidatorIndex,
     context: &Context,
 ) -> Result<(), Error> {
-    if has_eth1_withdrawal_credential(&state.validators[index]) {
+    if can_migrate_to_compounding(&state.validators[index]) {
         state.validators[index].withdrawal_credentials[0] = COMPOUNDING_WITHDRAWAL_PREFIX;
         queue_excess_active_balance(state, index, context)?;
     }
@@ -648,6 +685,44 @@ pub fn compute_consolidation_epoch_and_update_churn<
     Ok(state.earliest_consolidation_epoch)
 }
 
+// Return the reward for reporting the slashing of ``slashed_index``
+pub fn get_whistleblower_reward<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+    const PENDING_BALANCE_DEPOSITS_LIMIT: usize,
+    const PENDING_PARTIAL_WITHDRAWALS_LIMIT: usize,
+    const PENDING_CONSOLIDATIONS_LIMIT: usize,
+>(
+    state: &BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        PENDING_BALANCE_DEPOSITS_LIMIT,
+        PENDING_PARTIAL_WITHDRAWALS_LIMIT,
+        PENDING_CONSOLIDATIONS_LIMIT,
+    >,
+    slashed_index: ValidatorIndex,
+    context: &Context,
+) -> Gwei {
+    state.validators[slashed_index].effective_balance /
+        context.whistleblower_reward_quotient_electra
+}
+
 pub fn slash_validator<
     const SLOTS_PER_HISTORICAL_ROOT: usize,
     const HISTORICAL_ROOTS_LIMIT: usize,
@@ -699,11 +774,108 @@ pub fn slash_validator<
     );
     let proposer_index = get_beacon_proposer_index(state, context)?;
     let whistleblower_index = whistleblower_index.unwrap_or(proposer_index);
-    let whistleblower_reward = state.validators[slashed_index].effective_balance /
-        context.whistleblower_reward_quotient_electra;
+    let whistleblower_reward = get_whistleblower_reward(state, slashed_index, context);
     let proposer_reward_scaling_factor = PROPOSER_WEIGHT / WEIGHT_DENOMINATOR;
     let proposer_reward = whistleblower_reward * proposer_reward_scaling_factor;
     increase_balance(state, proposer_index, proposer_reward);
     increase_balance(state, whistleblower_index, whistleblower_reward - proposer_reward);
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        deneb::minimal::{
+            BYTES_PER_LOGS_BLOOM, EPOCHS_PER_HISTORICAL_VECTOR, EPOCHS_PER_SLASHINGS_VECTOR,
+            ETH1_DATA_VOTES_BOUND, HISTORICAL_ROOTS_LIMIT, MAX_EXTRA_DATA_BYTES,
+            MAX_VALIDATORS_PER_COMMITTEE, SLOTS_PER_HISTORICAL_ROOT, SYNC_COMMITTEE_SIZE,
+            VALIDATOR_REGISTRY_LIMIT,
+        },
+        electra::minimal::{
+            PENDING_BALANCE_DEPOSITS_LIMIT, PENDING_CONSOLIDATIONS_LIMIT,
+            PENDING_PARTIAL_WITHDRAWALS_LIMIT,
+        },
+        phase0::AttestationData,
+        primitives::ETH1_ADDRESS_WITHDRAWAL_PREFIX,
+        test_utils::push_active_validators,
+    };
+
+    const MAX_COMMITTEES_PER_SLOT: usize = 4;
+
+    type BeaconState = super::BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        PENDING_BALANCE_DEPOSITS_LIMIT,
+        PENDING_PARTIAL_WITHDRAWALS_LIMIT,
+        PENDING_CONSOLIDATIONS_LIMIT,
+    >;
+    type Attestation = super::Attestation<
+        { MAX_VALIDATORS_PER_COMMITTEE * MAX_COMMITTEES_PER_SLOT },
+        MAX_COMMITTEES_PER_SLOT,
+    >;
+
+    #[test]
+    fn test_switch_to_compounding_validator() {
+        let context = Context::for_minimal();
+        let mut state = BeaconState::default();
+        let mut validator = Validator::default();
+        validator.withdrawal_credentials[0] = ETH1_ADDRESS_WITHDRAWAL_PREFIX;
+        assert!(can_migrate_to_compounding(&validator));
+        state.validators.push(validator);
+        let excess = 5 * 10u64.pow(9);
+        state.balances.push(context.min_activation_balance + excess);
+
+        switch_to_compounding_validator(&mut state, 0, &context).unwrap();
+        assert!(has_compounding_withdrawal_credential(&state.validators[0]));
+        assert!(!can_migrate_to_compounding(&state.validators[0]));
+        assert_eq!(state.balances[0], context.min_activation_balance);
+        assert_eq!(state.pending_balance_deposits.len(), 1);
+        assert_eq!(
+            state.pending_balance_deposits[0],
+            PendingBalanceDeposit { index: 0, amount: excess }
+        );
+
+        // already compounding, so switching again is a no-op
+        switch_to_compounding_validator(&mut state, 0, &context).unwrap();
+        assert_eq!(state.pending_balance_deposits.len(), 1);
+    }
+
+    #[test]
+    fn test_get_attesting_indices_across_committees() {
+        let context = Context::for_minimal();
+        let mut state = BeaconState::default();
+        push_active_validators(&mut state.validators, &mut state.balances, 64, &context);
+
+        // 64 validators give two committees per slot in the minimal preset
+        let slot = 3;
+        let first = get_beacon_committee(&state, slot, 0, &context).unwrap();
+        let second = get_beacon_committee(&state, slot, 1, &context).unwrap();
+        assert!(first.len() > 1 && second.len() > 1);
+
+        let mut committee_bits = Bitvector::default();
+        committee_bits.set(0, true);
+        committee_bits.set(1, true);
+        let mut bits = vec![false; first.len() + second.len()];
+        bits[first.len() - 1] = true;
+        bits[first.len()] = true;
+        bits[first.len() + 1] = true;
+        let attestation = Attestation {
+            aggregation_bits: Bitlist::try_from(bits.as_slice()).unwrap(),
+            data: AttestationData { slot, ..Default::default() },
+            committee_bits,
+            ..Default::default()
+        };
+
+        let indices = get_attesting_indices(&state, &attestation, &context).unwrap();
+        assert_eq!(indices, HashSet::from([first[first.len() - 1], second[0], second[1]]));
+    }
+}
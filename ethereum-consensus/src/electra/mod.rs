@@ -11,6 +11,7 @@ pub mod helpers;
 pub mod operations;
 pub mod presets;
 pub mod spec;
+pub mod validator;
 
 pub use spec::*;
 
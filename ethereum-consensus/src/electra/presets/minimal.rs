@@ -15,6 +15,7 @@ pub const MAX_ATTESTATIONS_ELECTRA: usize = 8;
 pub const MAX_CONSOLIDATIONS: usize = 1;
 pub const MAX_DEPOSIT_RECEIPTS_PER_PAYLOAD: usize = 4;
 pub const MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD: usize = 2;
+pub const MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD: usize = 2;
 pub const MAX_PENDING_PARTIALS_PER_WITHDRAWALS_SWEEP: usize = 1;
 
 pub const PRESET: Preset = Preset {
@@ -30,5 +31,6 @@ pub const PRESET: Preset = Preset {
     max_consolidations: MAX_CONSOLIDATIONS,
     max_deposit_receipts_per_payload: MAX_DEPOSIT_RECEIPTS_PER_PAYLOAD,
     max_withdrawal_requests_per_payload: MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD,
+    max_consolidation_requests_per_payload: MAX_CONSOLIDATION_REQUESTS_PER_PAYLOAD,
     max_pending_partials_per_withdrawals_sweep: MAX_PENDING_PARTIALS_PER_WITHDRAWALS_SWEEP,
 };
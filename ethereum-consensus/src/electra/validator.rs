@@ -0,0 +1,227 @@
+use crate::{
+    crypto::fast_aggregate_verify,
+    electra::{
+        beacon_state::BeaconState, compute_epoch_at_slot, get_beacon_committee, get_domain,
+        helpers::get_committee_indices, is_aggregator, operations::Attestation,
+    },
+    error::InvalidAggregateAndProof,
+    primitives::{BlsSignature, DomainType, ValidatorIndex},
+    signing::{compute_signing_root, verify_signed_data},
+    ssz::prelude::*,
+    state_transition::{Context, Result},
+};
+
+#[derive(Default, Debug, SimpleSerialize, Clone, serde::Serialize, serde::Deserialize)]
+pub struct AggregateAndProof<
+    const MAX_VALIDATORS_PER_SLOT: usize,
+    const MAX_COMMITTEES_PER_SLOT: usize,
+> {
+    #[serde(with = "crate::serde::as_str")]
+    pub aggregator_index: ValidatorIndex,
+    pub aggregate: Attestation<MAX_VALIDATORS_PER_SLOT, MAX_COMMITTEES_PER_SLOT>,
+    pub selection_proof: BlsSignature,
+}
+
+#[derive(Default, Debug, SimpleSerialize, Clone, serde::Serialize, serde::Deserialize)]
+pub struct SignedAggregateAndProof<
+    const MAX_VALIDATORS_PER_SLOT: usize,
+    const MAX_COMMITTEES_PER_SLOT: usize,
+> {
+    pub message: AggregateAndProof<MAX_VALIDATORS_PER_SLOT, MAX_COMMITTEES_PER_SLOT>,
+    pub signature: BlsSignature,
+}
+
+// Checks a `SignedAggregateAndProof` like `phase0::verify_aggregate_and_proof`, except that
+// the committee of the aggregate is the single one set in its `committee_bits`, as
+// `data.index` is always 0 from Electra on.
+pub fn verify_aggregate_and_proof<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+    const PENDING_BALANCE_DEPOSITS_LIMIT: usize,
+    const PENDING_PARTIAL_WITHDRAWALS_LIMIT: usize,
+    const PENDING_CONSOLIDATIONS_LIMIT: usize,
+    const MAX_VALIDATORS_PER_SLOT: usize,
+    const MAX_COMMITTEES_PER_SLOT: usize,
+>(
+    state: &BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        PENDING_BALANCE_DEPOSITS_LIMIT,
+        PENDING_PARTIAL_WITHDRAWALS_LIMIT,
+        PENDING_CONSOLIDATIONS_LIMIT,
+    >,
+    signed_aggregate_and_proof: &SignedAggregateAndProof<
+        MAX_VALIDATORS_PER_SLOT,
+        MAX_COMMITTEES_PER_SLOT,
+    >,
+    context: &Context,
+) -> Result<()> {
+    let aggregate_and_proof = &signed_aggregate_and_proof.message;
+    let aggregator_index = aggregate_and_proof.aggregator_index;
+    let aggregate = &aggregate_and_proof.aggregate;
+    let data = &aggregate.data;
+
+    let committee_indices = get_committee_indices(&aggregate.committee_bits);
+    let [index] = committee_indices[..] else {
+        return Err(InvalidAggregateAndProof::InvalidCommitteeBits(committee_indices.len()).into())
+    };
+    if data.index != 0 {
+        return Err(InvalidAggregateAndProof::NonZeroAttestationIndex(data.index).into())
+    }
+
+    let committee = get_beacon_committee(state, data.slot, index, context)?;
+    if !committee.contains(&aggregator_index) {
+        return Err(InvalidAggregateAndProof::AggregatorNotInCommittee(aggregator_index).into())
+    }
+
+    let selection_proof = &aggregate_and_proof.selection_proof;
+    if !is_aggregator(state, data.slot, index, selection_proof, context)? {
+        return Err(InvalidAggregateAndProof::NotAggregator(aggregator_index).into())
+    }
+
+    let public_key = &state.validators[aggregator_index].public_key;
+    let epoch = compute_epoch_at_slot(data.slot, context);
+    let domain = get_domain(state, DomainType::SelectionProof, Some(epoch), context)?;
+    verify_signed_data(&data.slot, selection_proof, public_key, domain)
+        .map_err(|_| InvalidAggregateAndProof::InvalidSelectionProof(aggregator_index))?;
+
+    let domain = get_domain(state, DomainType::AggregateAndProof, Some(epoch), context)?;
+    verify_signed_data(
+        aggregate_and_proof,
+        &signed_aggregate_and_proof.signature,
+        public_key,
+        domain,
+    )
+    .map_err(|_| InvalidAggregateAndProof::InvalidSignature(aggregator_index))?;
+
+    if aggregate.aggregation_bits.len() != committee.len() {
+        return Err(InvalidAggregateAndProof::AggregationBitsLengthMismatch {
+            expected: committee.len(),
+            provided: aggregate.aggregation_bits.len(),
+        }
+        .into())
+    }
+    let mut public_keys = vec![];
+    for (i, &index) in committee.iter().enumerate() {
+        if aggregate.aggregation_bits[i] {
+            public_keys.push(&state.validators[index].public_key);
+        }
+    }
+    let domain = get_domain(state, DomainType::BeaconAttester, Some(data.target.epoch), context)?;
+    let signing_root = compute_signing_root(data, domain)?;
+    fast_aggregate_verify(&public_keys, signing_root.as_ref(), &aggregate.signature)
+        .map_err(|_| InvalidAggregateAndProof::InvalidAggregateSignature)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        deneb::minimal::{
+            BYTES_PER_LOGS_BLOOM, EPOCHS_PER_HISTORICAL_VECTOR, EPOCHS_PER_SLASHINGS_VECTOR,
+            ETH1_DATA_VOTES_BOUND, HISTORICAL_ROOTS_LIMIT, MAX_EXTRA_DATA_BYTES,
+            MAX_VALIDATORS_PER_COMMITTEE, SLOTS_PER_HISTORICAL_ROOT, SYNC_COMMITTEE_SIZE,
+            VALIDATOR_REGISTRY_LIMIT,
+        },
+        electra::minimal::{
+            PENDING_BALANCE_DEPOSITS_LIMIT, PENDING_CONSOLIDATIONS_LIMIT,
+            PENDING_PARTIAL_WITHDRAWALS_LIMIT,
+        },
+        test_utils::push_active_validators,
+        Error,
+    };
+
+    const MAX_COMMITTEES_PER_SLOT: usize = 4;
+
+    type BeaconState = super::BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        PENDING_BALANCE_DEPOSITS_LIMIT,
+        PENDING_PARTIAL_WITHDRAWALS_LIMIT,
+        PENDING_CONSOLIDATIONS_LIMIT,
+    >;
+    type SignedAggregateAndProof = super::SignedAggregateAndProof<
+        { MAX_VALIDATORS_PER_COMMITTEE * MAX_COMMITTEES_PER_SLOT },
+        MAX_COMMITTEES_PER_SLOT,
+    >;
+
+    #[test]
+    fn test_verify_aggregate_and_proof_uses_committee_bits() {
+        let context = Context::for_minimal();
+        let mut state = BeaconState::default();
+        // four committees of 32 validators per slot
+        push_active_validators(&mut state.validators, &mut state.balances, 1024, &context);
+        let committee = get_beacon_committee(&state, 0, 1, &context).unwrap();
+        let aggregator_index = committee[0];
+        assert!(!get_beacon_committee(&state, 0, 0, &context).unwrap().contains(&aggregator_index));
+        let selection_proof = (0..=u8::MAX)
+            .map(|byte| BlsSignature::try_from([byte; 96].as_ref()).unwrap())
+            .find(|proof| is_aggregator(&state, 0, 1, proof, &context).unwrap())
+            .unwrap();
+
+        let mut signed = SignedAggregateAndProof::default();
+        signed.message.aggregator_index = aggregator_index;
+        signed.message.selection_proof = selection_proof;
+        let verify_with_committees = |signed: &mut SignedAggregateAndProof, indices: &[usize]| {
+            signed.message.aggregate.committee_bits = Bitvector::default();
+            for &index in indices {
+                signed.message.aggregate.committee_bits.set(index, true);
+            }
+            let err = verify_aggregate_and_proof(&state, signed, &context).unwrap_err();
+            let Error::InvalidAggregateAndProof(err) = err else {
+                panic!("unexpected error {err:?}")
+            };
+            err
+        };
+
+        // the committee is the one named by `committee_bits`, not `data.index`
+        assert!(matches!(
+            verify_with_committees(&mut signed, &[1]),
+            InvalidAggregateAndProof::InvalidSelectionProof(index) if index == aggregator_index
+        ));
+        assert!(matches!(
+            verify_with_committees(&mut signed, &[0]),
+            InvalidAggregateAndProof::AggregatorNotInCommittee(index) if index == aggregator_index
+        ));
+
+        assert!(matches!(
+            verify_with_committees(&mut signed, &[]),
+            InvalidAggregateAndProof::InvalidCommitteeBits(0)
+        ));
+        assert!(matches!(
+            verify_with_committees(&mut signed, &[0, 1]),
+            InvalidAggregateAndProof::InvalidCommitteeBits(2)
+        ));
+
+        signed.message.aggregate.data.index = 1;
+        assert!(matches!(
+            verify_with_committees(&mut signed, &[1]),
+            InvalidAggregateAndProof::NonZeroAttestationIndex(1)
+        ));
+    }
+}
@@ -0,0 +1,292 @@
+//! An `ExecutionEngine` that verifies payloads with an execution client over the Engine API.
+//! See: https://github.com/ethereum/execution-apis/tree/main/src/engine
+use crate::{
+    error::ExecutionEngineError,
+    execution_engine::{ExecutionEngine, PayloadRequest},
+    primitives::Hash32,
+    state_transition::Result,
+};
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine as _};
+use hmac::{Hmac, Mac};
+use serde_json::{json, Value};
+use sha2::Sha256;
+use std::{
+    fmt,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+pub const JWT_SECRET_LEN: usize = 32;
+// Timeout the Engine API specifies for `engine_newPayload*` calls.
+const NEW_PAYLOAD_TIMEOUT: Duration = Duration::from_secs(8);
+
+/// A JSON-RPC method and its positional parameters.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EngineApiCall {
+    pub method: &'static str,
+    pub params: Vec<Value>,
+}
+
+/// Encode `value` as an Engine API `QUANTITY`.
+pub fn to_quantity(value: impl fmt::LowerHex) -> String {
+    format!("0x{value:x}")
+}
+
+/// Encode `bytes` as Engine API `DATA`.
+pub fn to_data(bytes: impl AsRef<[u8]>) -> String {
+    format!("0x{}", hex::encode(bytes))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PayloadStatus {
+    Valid,
+    Invalid,
+    Syncing,
+    Accepted,
+    InvalidBlockHash,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PayloadStatusV1 {
+    pub status: PayloadStatus,
+    pub latest_valid_hash: Option<Hash32>,
+    pub validation_error: Option<String>,
+}
+
+impl PayloadStatusV1 {
+    pub fn into_result(self) -> Result<()> {
+        let error = match self.status {
+            PayloadStatus::Valid => return Ok(()),
+            PayloadStatus::Invalid => ExecutionEngineError::InvalidPayloadStatus {
+                latest_valid_hash: self.latest_valid_hash,
+                validation_error: self.validation_error,
+            },
+            PayloadStatus::InvalidBlockHash => ExecutionEngineError::InvalidBlockHash,
+            PayloadStatus::Syncing => ExecutionEngineError::Syncing,
+            PayloadStatus::Accepted => ExecutionEngineError::Accepted,
+        };
+        Err(error.into())
+    }
+}
+
+// Build the HS256 JWT the Engine API expects in the `Authorization` header.
+fn jwt_token(secret: &[u8; JWT_SECRET_LEN], issued_at: u64) -> String {
+    let header = URL_SAFE_NO_PAD.encode(br#"{"alg":"HS256","typ":"JWT"}"#);
+    let claims = URL_SAFE_NO_PAD.encode(format!(r#"{{"iat":{issued_at}}}"#));
+    let message = format!("{header}.{claims}");
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret).expect("HMAC can take a key of any length");
+    mac.update(message.as_bytes());
+    let signature = URL_SAFE_NO_PAD.encode(mac.finalize().into_bytes());
+    format!("{message}.{signature}")
+}
+
+fn engine_api_error(error: impl fmt::Display) -> ExecutionEngineError {
+    ExecutionEngineError::EngineApi(error.to_string())
+}
+
+/// Drives an execution client's authenticated Engine API endpoint.
+/// NOTE: requests are made with a blocking client, as `ExecutionEngine` is synchronous;
+/// do not call from within an async runtime.
+#[derive(Clone)]
+pub struct JsonRpcExecutionEngine {
+    endpoint: String,
+    jwt_secret: [u8; JWT_SECRET_LEN],
+    http: reqwest::blocking::Client,
+}
+
+impl JsonRpcExecutionEngine {
+    pub fn new(endpoint: impl Into<String>, jwt_secret: [u8; JWT_SECRET_LEN]) -> Self {
+        let http = reqwest::blocking::Client::builder()
+            .timeout(NEW_PAYLOAD_TIMEOUT)
+            .build()
+            .expect("can build client");
+        Self { endpoint: endpoint.into(), jwt_secret, http }
+    }
+
+    pub fn call(&self, call: EngineApiCall) -> Result<Value> {
+        let issued_at = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs();
+        let body = json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": call.method,
+            "params": call.params,
+        });
+        let response = self
+            .http
+            .post(&self.endpoint)
+            .bearer_auth(jwt_token(&self.jwt_secret, issued_at))
+            .json(&body)
+            .send()
+            .and_then(|response| response.error_for_status())
+            .map_err(engine_api_error)?;
+        let mut response: Value = response.json().map_err(engine_api_error)?;
+        if let Some(error) = response.get("error") {
+            return Err(engine_api_error(error).into())
+        }
+        match response.get_mut("result") {
+            Some(result) => Ok(result.take()),
+            None => Err(engine_api_error("response is missing `result`").into()),
+        }
+    }
+}
+
+impl ExecutionEngine for JsonRpcExecutionEngine {
+    fn verify_and_notify_new_payload(
+        &self,
+        new_payload_request: &impl PayloadRequest,
+    ) -> Result<()> {
+        if !new_payload_request.is_valid_versioned_hashes() {
+            return Err(ExecutionEngineError::InvalidVersionedHashes.into())
+        }
+        let call = new_payload_request
+            .engine_api_call()
+            .ok_or(ExecutionEngineError::UnsupportedPayloadRequest)?;
+        let result = self.call(call)?;
+        let status: PayloadStatusV1 = serde_json::from_value(result)?;
+        status.into_result()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        crypto::KzgCommitment,
+        deneb::{kzg_commitment_to_versioned_hash, mainnet::ExecutionPayload, NewPayloadRequest},
+        primitives::U256,
+        Error,
+    };
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        thread,
+    };
+
+    // Serve a single request with `result`, returning the JSON-RPC body that was received.
+    fn serve_once(result: Value) -> (String, thread::JoinHandle<(Value, String)>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let endpoint = format!("http://{}", listener.local_addr().unwrap());
+        let handle = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut content_length = 0;
+            let mut authorization = String::new();
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                let line = line.trim_end();
+                if line.is_empty() {
+                    break
+                }
+                let (name, value) = line.split_once(':').unwrap_or((line, ""));
+                match name.to_ascii_lowercase().as_str() {
+                    "content-length" => content_length = value.trim().parse().unwrap(),
+                    "authorization" => authorization = value.trim().to_string(),
+                    _ => {}
+                }
+            }
+            let mut body = vec![0u8; content_length];
+            reader.read_exact(&mut body).unwrap();
+
+            let response = json!({ "jsonrpc": "2.0", "id": 1, "result": result }).to_string();
+            let mut stream = stream;
+            write!(
+                stream,
+                "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                response.len(),
+                response
+            )
+            .unwrap();
+            (serde_json::from_slice(&body).unwrap(), authorization)
+        });
+        (endpoint, handle)
+    }
+
+    fn new_payload_request() -> impl PayloadRequest {
+        let execution_payload = ExecutionPayload {
+            block_number: 26,
+            base_fee_per_gas: U256::from(7),
+            ..Default::default()
+        };
+        let commitment = KzgCommitment::default();
+        NewPayloadRequest {
+            execution_payload,
+            versioned_hashes: vec![kzg_commitment_to_versioned_hash(&commitment)],
+            parent_beacon_block_root: Default::default(),
+            blob_kzg_commitments: vec![commitment],
+        }
+    }
+
+    // Submit a payload to a server responding with `status`, checking the request it received.
+    fn verify_with_status(status: Value) -> Result<()> {
+        let (endpoint, handle) = serve_once(status);
+        let engine = JsonRpcExecutionEngine::new(endpoint, [2u8; JWT_SECRET_LEN]);
+        let outcome = engine.verify_and_notify_new_payload(&new_payload_request());
+        let (body, authorization) = handle.join().unwrap();
+        assert_eq!(body["method"], "engine_newPayloadV3");
+        assert!(authorization.starts_with("Bearer "));
+        outcome
+    }
+
+    #[test]
+    fn test_jwt_token() {
+        let token = jwt_token(&[0u8; JWT_SECRET_LEN], 1_700_000_000);
+        let parts = token.split('.').collect::<Vec<_>>();
+        assert_eq!(parts.len(), 3);
+        assert_eq!(parts[0], "eyJhbGciOiJIUzI1NiIsInR5cCI6IkpXVCJ9");
+        let claims = URL_SAFE_NO_PAD.decode(parts[1]).unwrap();
+        assert_eq!(claims, br#"{"iat":1700000000}"#);
+    }
+
+    #[test]
+    fn test_new_payload_v3_encoding() {
+        let call = new_payload_request().engine_api_call().unwrap();
+        assert_eq!(call.method, "engine_newPayloadV3");
+        assert_eq!(call.params.len(), 3);
+        let payload = &call.params[0];
+        assert_eq!(payload["blockNumber"], "0x1a");
+        assert_eq!(payload["baseFeePerGas"], "0x7");
+        assert_eq!(payload["gasUsed"], "0x0");
+        assert_eq!(payload["feeRecipient"], to_data([0u8; 20]));
+        let versioned_hash = kzg_commitment_to_versioned_hash(&KzgCommitment::default());
+        assert_eq!(call.params[1], json!([to_data(versioned_hash)]));
+        assert_eq!(call.params[2], to_data([0u8; 32]));
+    }
+
+    #[test]
+    fn test_payload_status_mapping() {
+        let latest_valid_hash = to_data([1u8; 32]);
+        let valid = json!({ "status": "VALID", "latestValidHash": latest_valid_hash });
+        assert!(verify_with_status(valid).is_ok());
+
+        let invalid = json!({
+            "status": "INVALID",
+            "latestValidHash": latest_valid_hash,
+            "validationError": "bad block",
+        });
+        match verify_with_status(invalid) {
+            Err(Error::ExecutionEngine(ExecutionEngineError::InvalidPayloadStatus {
+                latest_valid_hash,
+                validation_error,
+            })) => {
+                assert_eq!(latest_valid_hash.unwrap().as_ref(), [1u8; 32]);
+                assert_eq!(validation_error.as_deref(), Some("bad block"));
+            }
+            other => panic!("unexpected outcome {other:?}"),
+        }
+
+        let syncing = json!({ "status": "SYNCING", "latestValidHash": null });
+        assert!(matches!(
+            verify_with_status(syncing),
+            Err(Error::ExecutionEngine(ExecutionEngineError::Syncing))
+        ));
+
+        let accepted = json!({ "status": "ACCEPTED", "latestValidHash": null });
+        assert!(matches!(
+            verify_with_status(accepted),
+            Err(Error::ExecutionEngine(ExecutionEngineError::Accepted))
+        ));
+    }
+}
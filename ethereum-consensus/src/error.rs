@@ -3,8 +3,8 @@ use crate::{
     crypto::{BlsError, KzgError},
     phase0::{AttestationData, BeaconBlockHeader, Checkpoint},
     primitives::{
-        BlsPublicKey, BlsSignature, Bytes32, Epoch, ExecutionAddress, Hash32, Root, Slot,
-        ValidatorIndex,
+        BlsPublicKey, BlsSignature, Bytes32, CommitteeIndex, Epoch, ExecutionAddress, Hash32, Root,
+        Slot, ValidatorIndex,
     },
     ssz::prelude::*,
     Fork,
@@ -21,7 +21,6 @@ pub enum Error {
     Bls(#[from] BlsError),
     #[error("{0}")]
     Kzg(#[from] KzgError),
-    #[cfg(feature = "serde")]
     #[error("{0}")]
     Io(#[from] std::io::Error),
     #[cfg(feature = "serde")]
@@ -46,8 +45,8 @@ pub enum Error {
     InvalidBlock(#[from] Box<InvalidBlock>),
     #[error("an invalid transition to a past slot {requested} from slot {current}")]
     TransitionToPreviousSlot { current: Slot, requested: Slot },
-    #[error("invalid state root")]
-    InvalidStateRoot,
+    #[error("the block commits to state root {expected:?} but the post-state has root {got:?}")]
+    StateRootMismatch { expected: Root, got: Root },
     #[error(
     "the requested epoch {requested} is not in the required current epoch {current} or previous epoch {previous}"
     )]
@@ -56,6 +55,34 @@ pub enum Error {
         "transition requested from a later fork {source_fork:?} to an earlier fork {destination_fork:?}"
     )]
     InvalidForkTransition { source_fork: Fork, destination_fork: Fork },
+    #[error("execution requests must be non-empty and in strictly ascending order of type")]
+    InvalidExecutionRequestsOrder,
+    #[error("unknown execution request type {0}")]
+    UnknownExecutionRequestType(u8),
+    #[error("unknown fork `{0}`")]
+    UnknownFork(String),
+    #[error("fork {0} is not supported for this operation")]
+    UnsupportedFork(Fork),
+    #[error("attestations to aggregate must have the same data and committees")]
+    IncompatibleAttestations,
+    #[error("attestations to aggregate have overlapping aggregation bits")]
+    OverlappingAggregationBits,
+    #[error("sync committee contributions to aggregate must have the same slot and block root")]
+    IncompatibleContributions,
+    #[error("subcommittee index {index} is not less than the subnet count {count}")]
+    InvalidSubcommitteeIndex { index: u64, count: usize },
+    #[error("invalid state snapshot: {0}")]
+    InvalidSnapshot(String),
+    #[error("state snapshot root {expected:?} does not match the computed root {computed:?}")]
+    SnapshotRootMismatch { expected: Root, computed: Root },
+    #[error("invalid state dump: {0}")]
+    InvalidStateDump(String),
+    #[error("could not detect the fork of the block: {0}")]
+    UndetectableFork(String),
+    #[error("invalid aggregate and proof: {0}")]
+    InvalidAggregateAndProof(#[from] InvalidAggregateAndProof),
+    #[error("invalid blob transaction: {0}")]
+    InvalidBlobTransaction(String),
     #[error("genesis time unknown for network {0}")]
     UnknownGenesisTime(String),
     #[cfg(feature = "serde")]
@@ -65,6 +92,26 @@ pub enum Error {
     ExecutionEngine(#[from] ExecutionEngineError),
 }
 
+#[derive(Debug, Error)]
+pub enum InvalidAggregateAndProof {
+    #[error("expected the aggregate to name exactly one committee but found {0}")]
+    InvalidCommitteeBits(usize),
+    #[error("expected the attestation data of the aggregate to have index 0 but found {0}")]
+    NonZeroAttestationIndex(CommitteeIndex),
+    #[error("aggregator {0} is not a member of the committee of the aggregate")]
+    AggregatorNotInCommittee(ValidatorIndex),
+    #[error("the selection proof does not select validator {0} as an aggregator")]
+    NotAggregator(ValidatorIndex),
+    #[error("invalid selection proof from aggregator {0}")]
+    InvalidSelectionProof(ValidatorIndex),
+    #[error("invalid signature from aggregator {0}")]
+    InvalidSignature(ValidatorIndex),
+    #[error("expected {expected} aggregation bits for the committee but found {provided}")]
+    AggregationBitsLengthMismatch { expected: usize, provided: usize },
+    #[error("invalid aggregate signature")]
+    InvalidAggregateSignature,
+}
+
 #[derive(Debug, Error)]
 pub enum InvalidBlock {
     #[error("invalid beacon block header: {0}")]
@@ -254,6 +301,8 @@ pub enum InvalidExecutionPayload {
     InvalidTimestamp { provided: u64, expected: u64 },
     #[error("expected up to {limit} blob commmitments but block has {provided}")]
     InvalidBlobCommitments { provided: usize, limit: usize },
+    #[error("execution engine did not validate the payload: {0}")]
+    ExecutionEngine(#[from] ExecutionEngineError),
 }
 
 pub(crate) fn invalid_header_error(error: InvalidBeaconBlockHeader) -> Error {
@@ -264,6 +313,16 @@ pub(crate) fn invalid_operation_error(error: InvalidOperation) -> Error {
     Error::InvalidBlock(Box::new(InvalidBlock::InvalidOperation(error)))
 }
 
+// Report a failure from the execution engine while processing a block as an invalid payload.
+pub(crate) fn invalid_execution_payload_error(error: Error) -> Error {
+    match error {
+        Error::ExecutionEngine(error) => {
+            invalid_operation_error(InvalidExecutionPayload::ExecutionEngine(error).into())
+        }
+        error => error,
+    }
+}
+
 #[derive(Debug, Error)]
 pub enum ExecutionEngineError {
     #[error("invalid block hash")]
@@ -272,4 +331,17 @@ pub enum ExecutionEngineError {
     InvalidPayload,
     #[error("invalid versioned hashes in payload")]
     InvalidVersionedHashes,
+    #[error(
+        "execution engine reported an invalid payload (latest valid hash {latest_valid_hash:?}): {}",
+        validation_error.as_deref().unwrap_or("no validation error given")
+    )]
+    InvalidPayloadStatus { latest_valid_hash: Option<Hash32>, validation_error: Option<String> },
+    #[error("execution engine is syncing and could not validate the payload")]
+    Syncing,
+    #[error("execution engine accepted the payload without validating it")]
+    Accepted,
+    #[error("payload request has no encoding for the engine API")]
+    UnsupportedPayloadRequest,
+    #[error("engine API request failed: {0}")]
+    EngineApi(String),
 }
@@ -1,11 +1,24 @@
+#[cfg(feature = "engine-api")]
+use crate::engine_api::EngineApiCall;
 use crate::{
     error::{Error, ExecutionEngineError},
     state_transition::Result,
 };
 
 /// `PayloadRequest` abstracts over the data sent to the `ExecutionEngine`.
-/// NOTE: just a "marker" trait for now, until we have more substantial support for execution.
-pub trait PayloadRequest {}
+pub trait PayloadRequest {
+    /// Check that any versioned hashes in the request match the blob commitments they commit to.
+    fn is_valid_versioned_hashes(&self) -> bool {
+        true
+    }
+
+    /// Return the Engine API call that submits this request to an execution client,
+    /// or `None` if the request has no Engine API encoding.
+    #[cfg(feature = "engine-api")]
+    fn engine_api_call(&self) -> Option<EngineApiCall> {
+        None
+    }
+}
 
 /// `ExecutionEngine` abstracts over the interface between consensus and execution client.
 pub trait ExecutionEngine {
@@ -20,7 +33,13 @@ pub trait ExecutionEngine {
 /// A "no-op" implementation that succeeds for `true` or fails for `false`.
 /// Useful for mocking the execution engine behavior.
 impl ExecutionEngine for bool {
-    fn verify_and_notify_new_payload(&self, _: &impl PayloadRequest) -> Result<()> {
+    fn verify_and_notify_new_payload(
+        &self,
+        new_payload_request: &impl PayloadRequest,
+    ) -> Result<()> {
+        if !new_payload_request.is_valid_versioned_hashes() {
+            return Err(Error::ExecutionEngine(ExecutionEngineError::InvalidVersionedHashes))
+        }
         self.then_some(()).ok_or(Error::ExecutionEngine(ExecutionEngineError::InvalidPayload))
     }
 }
@@ -1,4 +1,5 @@
-use std::fmt;
+use crate::{state_transition::Context, Error};
+use std::{fmt, str::FromStr};
 
 // Identifies the fork of the protocol the associated object belongs to.
 #[derive(Debug, Clone, Copy, serde::Serialize, serde::Deserialize)]
@@ -24,3 +25,209 @@ impl fmt::Display for Fork {
         }
     }
 }
+
+impl FromStr for Fork {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "phase0" => Ok(Self::Phase0),
+            "altair" => Ok(Self::Altair),
+            "bellatrix" => Ok(Self::Bellatrix),
+            "capella" => Ok(Self::Capella),
+            "deneb" => Ok(Self::Deneb),
+            "electra" => Ok(Self::Electra),
+            other => Err(Error::UnknownFork(other.to_string())),
+        }
+    }
+}
+
+// Fixed-size prefix of a `BeaconBlock` preceding the offset of its `body`.
+const BEACON_BLOCK_FIXED_LEN: usize = 8 + 8 + 32 + 32;
+// Fixed-size prefix of a `SignedBeaconBlock`: the offset of its `message` and the signature.
+const SIGNED_BEACON_BLOCK_FIXED_LEN: usize = 4 + 96;
+// `randao_reveal`, `eth1_data` and `graffiti` precede the first offset of a `BeaconBlockBody`.
+const BEACON_BLOCK_BODY_PREFIX_LEN: usize = 96 + 72 + 32;
+const PHASE0_BEACON_BLOCK_BODY_FIXED_LEN: usize = BEACON_BLOCK_BODY_PREFIX_LEN + 5 * 4;
+
+// Detect the fork of a bare `BeaconBlock` or `SignedBeaconBlock`, as found in archives without
+// the accompanying version metadata, from either its JSON or its SSZ encoding.
+pub fn detect_fork(block: &[u8], context: &Context) -> Result<Fork, Error> {
+    #[cfg(feature = "serde")]
+    {
+        if block.iter().find(|b| !b.is_ascii_whitespace()) == Some(&b'{') {
+            let value: serde_json::Value = serde_json::from_slice(block)?;
+            return detect_fork_from_json(&value)
+        }
+    }
+    detect_fork_from_ssz(block, context)
+}
+
+// Each fork appends fields to the `BeaconBlockBody`, so the newest field present decides the fork.
+#[cfg(feature = "serde")]
+fn detect_fork_from_json(block: &serde_json::Value) -> Result<Fork, Error> {
+    let message = block.get("message").unwrap_or(block);
+    let body = message
+        .get("body")
+        .and_then(|body| body.as_object())
+        .ok_or_else(|| Error::UndetectableFork("missing block body".to_string()))?;
+    // Electra attestations carry `committee_bits`, which also identifies blocks encoded without
+    // the fields Electra appends to the body.
+    let has_committee_bits = body
+        .get("attestations")
+        .and_then(|attestations| attestations.as_array())
+        .is_some_and(|attestations| {
+            attestations.iter().any(|attestation| attestation.get("committee_bits").is_some())
+        });
+    let fork = if body.contains_key("consolidations") ||
+        body.contains_key("execution_requests") ||
+        has_committee_bits
+    {
+        Fork::Electra
+    } else if body.contains_key("blob_kzg_commitments") {
+        Fork::Deneb
+    } else if body.contains_key("bls_to_execution_changes") {
+        Fork::Capella
+    } else if body.contains_key("execution_payload") {
+        Fork::Bellatrix
+    } else if body.contains_key("sync_aggregate") {
+        Fork::Altair
+    } else if body.contains_key("randao_reveal") {
+        Fork::Phase0
+    } else {
+        return Err(Error::UndetectableFork("unrecognized block body".to_string()))
+    };
+    Ok(fork)
+}
+
+// The first offset in the SSZ encoding of a `BeaconBlockBody` is the length of its fixed-size
+// part, which grows with every fork. Electra changes the attestation types without changing
+// this length, and is only told apart from Deneb by the offset of its trailing
+// `consolidations` list.
+fn detect_fork_from_ssz(block: &[u8], context: &Context) -> Result<Fork, Error> {
+    let read_offset = |at: usize| {
+        block.get(at..at + 4).map(|bytes| u32::from_le_bytes(bytes.try_into().unwrap()) as usize)
+    };
+    let body_offset_at = |message: usize| read_offset(message + BEACON_BLOCK_FIXED_LEN);
+    let message = if read_offset(0) == Some(SIGNED_BEACON_BLOCK_FIXED_LEN) &&
+        body_offset_at(SIGNED_BEACON_BLOCK_FIXED_LEN) == Some(BEACON_BLOCK_FIXED_LEN + 4)
+    {
+        SIGNED_BEACON_BLOCK_FIXED_LEN
+    } else if body_offset_at(0) == Some(BEACON_BLOCK_FIXED_LEN + 4) {
+        0
+    } else {
+        return Err(Error::UndetectableFork("not an SSZ-encoded beacon block".to_string()))
+    };
+    let body = message + BEACON_BLOCK_FIXED_LEN + 4;
+    let body_fixed_len = read_offset(body + BEACON_BLOCK_BODY_PREFIX_LEN)
+        .ok_or_else(|| Error::UndetectableFork("truncated block body".to_string()))?;
+
+    let altair_fixed_len =
+        PHASE0_BEACON_BLOCK_BODY_FIXED_LEN + context.sync_committee_size.div_ceil(8) + 96;
+    let fork = match body_fixed_len {
+        PHASE0_BEACON_BLOCK_BODY_FIXED_LEN => Fork::Phase0,
+        len if len == altair_fixed_len => Fork::Altair,
+        len if len == altair_fixed_len + 4 => Fork::Bellatrix,
+        len if len == altair_fixed_len + 2 * 4 => Fork::Capella,
+        len if len == altair_fixed_len + 3 * 4 => Fork::Deneb,
+        len if len == altair_fixed_len + 4 * 4 => Fork::Electra,
+        len => {
+            return Err(Error::UndetectableFork(format!(
+                "unexpected length {len} for the fixed part of the block body"
+            )))
+        }
+    };
+    Ok(fork)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{capella, deneb, electra, phase0, ssz::prelude::serialize};
+
+    #[test]
+    fn test_fork_from_str() {
+        assert!(matches!("deneb".parse::<Fork>(), Ok(Fork::Deneb)));
+        assert!(matches!("electra".parse::<Fork>(), Ok(Fork::Electra)));
+        assert!(matches!(
+            "fulu".parse::<Fork>(),
+            Err(Error::UnknownFork(fork)) if fork == "fulu"
+        ));
+        for fork in [Fork::Phase0, Fork::Altair, Fork::Bellatrix, Fork::Capella] {
+            assert_eq!(fork.to_string().parse::<Fork>().unwrap().to_string(), fork.to_string());
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_detect_fork() {
+        use deneb::minimal::{
+            BYTES_PER_LOGS_BLOOM, MAX_BLOB_COMMITMENTS_PER_BLOCK, MAX_BLS_TO_EXECUTION_CHANGES,
+            MAX_BYTES_PER_TRANSACTION, MAX_DEPOSITS, MAX_EXTRA_DATA_BYTES, MAX_PROPOSER_SLASHINGS,
+            MAX_TRANSACTIONS_PER_PAYLOAD, MAX_VALIDATORS_PER_COMMITTEE, MAX_VOLUNTARY_EXITS,
+            MAX_WITHDRAWALS_PER_PAYLOAD, SYNC_COMMITTEE_SIZE,
+        };
+        use electra::minimal::{
+            MAX_ATTESTATIONS_ELECTRA, MAX_ATTESTER_SLASHINGS_ELECTRA, MAX_CONSOLIDATIONS,
+            MAX_DEPOSIT_RECEIPTS_PER_PAYLOAD, MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD,
+        };
+        const MAX_COMMITTEES_PER_SLOT: usize = 4;
+        type ElectraSignedBeaconBlock = electra::SignedBeaconBlock<
+            MAX_PROPOSER_SLASHINGS,
+            { MAX_VALIDATORS_PER_COMMITTEE * MAX_COMMITTEES_PER_SLOT },
+            MAX_COMMITTEES_PER_SLOT,
+            MAX_ATTESTER_SLASHINGS_ELECTRA,
+            MAX_ATTESTATIONS_ELECTRA,
+            MAX_DEPOSITS,
+            MAX_VOLUNTARY_EXITS,
+            SYNC_COMMITTEE_SIZE,
+            BYTES_PER_LOGS_BLOOM,
+            MAX_EXTRA_DATA_BYTES,
+            MAX_BYTES_PER_TRANSACTION,
+            MAX_TRANSACTIONS_PER_PAYLOAD,
+            MAX_WITHDRAWALS_PER_PAYLOAD,
+            MAX_DEPOSIT_RECEIPTS_PER_PAYLOAD,
+            MAX_WITHDRAWAL_REQUESTS_PER_PAYLOAD,
+            MAX_BLS_TO_EXECUTION_CHANGES,
+            MAX_BLOB_COMMITMENTS_PER_BLOCK,
+            MAX_CONSOLIDATIONS,
+        >;
+
+        let context = Context::for_minimal();
+
+        let capella = capella::minimal::SignedBeaconBlock::default();
+        let deneb = deneb::minimal::SignedBeaconBlock::default();
+        let capella_json = serde_json::to_vec(&capella).unwrap();
+        let deneb_json = serde_json::to_vec(&deneb).unwrap();
+        assert!(matches!(detect_fork(&capella_json, &context), Ok(Fork::Capella)));
+        assert!(matches!(detect_fork(&deneb_json, &context), Ok(Fork::Deneb)));
+        let unsigned = serde_json::to_vec(&deneb.message).unwrap();
+        assert!(matches!(detect_fork(&unsigned, &context), Ok(Fork::Deneb)));
+
+        let phase0 = serialize(&phase0::minimal::SignedBeaconBlock::default()).unwrap();
+        assert!(matches!(detect_fork(&phase0, &context), Ok(Fork::Phase0)));
+        let capella_ssz = serialize(&capella).unwrap();
+        assert!(matches!(detect_fork(&capella_ssz, &context), Ok(Fork::Capella)));
+        let deneb_ssz = serialize(&deneb.message).unwrap();
+        assert!(matches!(detect_fork(&deneb_ssz, &context), Ok(Fork::Deneb)));
+
+        let mut electra = ElectraSignedBeaconBlock::default();
+        let electra_ssz = serialize(&electra).unwrap();
+        assert!(matches!(detect_fork(&electra_ssz, &context), Ok(Fork::Electra)));
+        let electra_json = serde_json::to_vec(&electra).unwrap();
+        assert!(matches!(detect_fork(&electra_json, &context), Ok(Fork::Electra)));
+        // without the trailing `consolidations`, the attestations still give away an Electra body
+        electra.message.body.attestations.push(Default::default());
+        let mut electra_json = serde_json::to_value(&electra).unwrap();
+        electra_json["message"]["body"].as_object_mut().unwrap().remove("consolidations");
+        let electra_json = serde_json::to_vec(&electra_json).unwrap();
+        assert!(matches!(detect_fork(&electra_json, &context), Ok(Fork::Electra)));
+        let mut deneb = deneb.clone();
+        deneb.message.body.attestations.push(Default::default());
+        let deneb_json = serde_json::to_vec(&deneb).unwrap();
+        assert!(matches!(detect_fork(&deneb_json, &context), Ok(Fork::Deneb)));
+
+        assert!(matches!(detect_fork(b"{}", &context), Err(Error::UndetectableFork(..))));
+        assert!(matches!(detect_fork(&[0u8; 8], &context), Err(Error::UndetectableFork(..))));
+    }
+}
@@ -1,5 +1,6 @@
 pub mod altair;
 pub mod bellatrix;
+pub mod block_body;
 pub mod builder;
 pub mod capella;
 pub mod clock;
@@ -8,19 +9,25 @@ pub mod crypto;
 pub mod deneb;
 pub mod domains;
 pub mod electra;
+#[cfg(feature = "engine-api")]
+pub mod engine_api;
 pub mod error;
 pub mod execution_engine;
 mod fork;
 pub mod networking;
 pub mod networks;
 pub mod phase0;
+pub mod prelude;
 pub mod primitives;
 #[cfg(feature = "serde")]
 pub mod serde;
 pub mod signing;
 pub mod ssz;
 pub mod state_transition;
+#[cfg(test)]
+mod test_utils;
 pub mod types;
 
+pub use capella::withdrawal::Withdrawal;
 pub use error::Error;
-pub use fork::Fork;
+pub use fork::{detect_fork, Fork};
@@ -14,7 +14,7 @@ use crate::{
             get_committee_count_per_slot, get_current_epoch, get_domain, get_indexed_attestation,
             get_previous_epoch, get_randao_mix, increase_balance, initiate_validator_exit,
             is_active_validator, is_slashable_attestation_data, is_slashable_validator,
-            is_valid_indexed_attestation, slash_validator,
+            is_valid_indexed_attestation, slash_validator, verify_merkle_branch,
         },
         operations::{
             Attestation, AttesterSlashing, Deposit, DepositMessage, PendingAttestation,
@@ -430,7 +430,7 @@ pub fn process_deposit<
     let depth = DEPOSIT_CONTRACT_TREE_DEPTH + 1;
     let index = state.eth1_deposit_index as usize;
     let root = state.eth1_data.deposit_root;
-    if is_valid_merkle_branch(leaf, branch, depth, index, root).is_err() {
+    if !verify_merkle_branch(leaf, branch, depth, index, root) {
         return Err(invalid_operation_error(InvalidOperation::Deposit(
             InvalidDeposit::InvalidProof { leaf, branch: branch.to_vec(), depth, index, root },
         )))
@@ -519,6 +519,53 @@ pub fn process_voluntary_exit<
     initiate_validator_exit(state, voluntary_exit.validator_index, context)
 }
 
+// Checks the block was produced by the expected proposer for `state.slot`. This is cheap
+// relative to signature verification, so the state transition runs it first.
+pub fn verify_proposer_index<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const PENDING_ATTESTATIONS_BOUND: usize,
+    const MAX_PROPOSER_SLASHINGS: usize,
+    const MAX_ATTESTER_SLASHINGS: usize,
+    const MAX_ATTESTATIONS: usize,
+    const MAX_DEPOSITS: usize,
+    const MAX_VOLUNTARY_EXITS: usize,
+>(
+    state: &BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        PENDING_ATTESTATIONS_BOUND,
+    >,
+    block: &BeaconBlock<
+        MAX_PROPOSER_SLASHINGS,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        MAX_ATTESTER_SLASHINGS,
+        MAX_ATTESTATIONS,
+        MAX_DEPOSITS,
+        MAX_VOLUNTARY_EXITS,
+    >,
+    context: &Context,
+) -> Result<()> {
+    let proposer_index = get_beacon_proposer_index(state, context)?;
+    if block.proposer_index != proposer_index {
+        return Err(invalid_header_error(InvalidBeaconBlockHeader::ProposerIndexMismatch {
+            block_proposer_index: block.proposer_index,
+            proposer_index,
+        }))
+    }
+    Ok(())
+}
+
 pub fn process_block_header<
     const SLOTS_PER_HISTORICAL_ROOT: usize,
     const HISTORICAL_ROOTS_LIMIT: usize,
@@ -568,13 +615,7 @@ pub fn process_block_header<
         }))
     }
 
-    let proposer_index = get_beacon_proposer_index(state, context)?;
-    if block.proposer_index != proposer_index {
-        return Err(invalid_header_error(InvalidBeaconBlockHeader::ProposerIndexMismatch {
-            block_proposer_index: block.proposer_index,
-            proposer_index,
-        }))
-    }
+    verify_proposer_index(state, block, context)?;
 
     let expected_parent_root = state.latest_block_header.hash_tree_root()?;
     if block.parent_root != expected_parent_root {
@@ -594,7 +635,9 @@ pub fn process_block_header<
 
     let proposer = &state.validators[block.proposer_index];
     if proposer.slashed {
-        return Err(invalid_header_error(InvalidBeaconBlockHeader::ProposerSlashed(proposer_index)))
+        return Err(invalid_header_error(InvalidBeaconBlockHeader::ProposerSlashed(
+            block.proposer_index,
+        )))
     }
 
     Ok(())
@@ -803,3 +846,79 @@ pub fn process_block<
     process_operations(state, &block.body, context)?;
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        error::InvalidBlock,
+        phase0::{minimal, AttestationData, Checkpoint},
+        test_utils::push_active_validators,
+        Error,
+    };
+
+    #[test]
+    fn test_invalid_attestation_error() {
+        let context = Context::for_minimal();
+        let mut state = minimal::BeaconState::default();
+        let attestation = minimal::Attestation {
+            data: AttestationData {
+                target: Checkpoint { epoch: 5, ..Default::default() },
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let err = process_attestation(&mut state, &attestation, &context).unwrap_err();
+        let Error::InvalidBlock(invalid_block) = &err else { panic!("unexpected error {err:?}") };
+        assert!(matches!(
+            **invalid_block,
+            InvalidBlock::InvalidOperation(InvalidOperation::Attestation(
+                InvalidAttestation::InvalidTargetEpoch { target: 5, current: 0 }
+            ))
+        ));
+        assert!(err.to_string().contains("invalid target epoch 5"));
+    }
+
+    #[test]
+    fn test_process_operations_rejects_wrong_deposit_count() {
+        let context = Context::for_minimal();
+        let mut state = minimal::BeaconState::default();
+        state.eth1_data.deposit_count = 2;
+        let body = minimal::BeaconBlockBody::default();
+
+        let err = process_operations(&mut state, &body, &context).unwrap_err();
+        let Error::InvalidBlock(invalid_block) = &err else { panic!("unexpected error {err:?}") };
+        assert!(matches!(
+            **invalid_block,
+            InvalidBlock::InvalidOperation(InvalidOperation::Deposit(
+                InvalidDeposit::IncorrectCount { expected: 2, count: 0 }
+            ))
+        ));
+
+        // every pending deposit has been processed, so none are expected
+        state.eth1_deposit_index = 2;
+        process_operations(&mut state, &body, &context).unwrap();
+    }
+
+    #[test]
+    fn test_verify_proposer_index_rejects_wrong_proposer() {
+        let context = Context::for_minimal();
+        let mut state = minimal::BeaconState::default();
+        push_active_validators(&mut state.validators, &mut state.balances, 8, &context);
+        let proposer_index = get_beacon_proposer_index(&state, &context).unwrap();
+        let mut block = minimal::BeaconBlock { proposer_index, ..Default::default() };
+        verify_proposer_index(&state, &block, &context).unwrap();
+
+        block.proposer_index = (proposer_index + 1) % 8;
+        let err = verify_proposer_index(&state, &block, &context).unwrap_err();
+        let Error::InvalidBlock(invalid_block) = &err else { panic!("unexpected error {err:?}") };
+        assert!(matches!(
+            **invalid_block,
+            InvalidBlock::Header(InvalidBeaconBlockHeader::ProposerIndexMismatch {
+                block_proposer_index,
+                proposer_index: expected,
+            }) if block_proposer_index == block.proposer_index && expected == proposer_index
+        ));
+    }
+}
@@ -1070,3 +1070,113 @@ pub fn process_epoch<
     process_participation_record_updates(state);
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        phase0::{beacon_state::HistoricalBatch, minimal::BeaconState, validator::Validator},
+        primitives::{Bytes32, Root, FAR_FUTURE_EPOCH},
+        test_utils::push_active_validators,
+    };
+
+    const ACTIVE_VALIDATOR_COUNT: usize = 16;
+
+    fn state_with_activation_queue(eligibility_epochs: &[Epoch], context: &Context) -> BeaconState {
+        let mut state = BeaconState::default();
+        state.slot = 10 * context.slots_per_epoch;
+        state.finalized_checkpoint.epoch = 8;
+        push_active_validators(
+            &mut state.validators,
+            &mut state.balances,
+            ACTIVE_VALIDATOR_COUNT,
+            context,
+        );
+        for &activation_eligibility_epoch in eligibility_epochs {
+            state.validators.push(Validator {
+                effective_balance: context.max_effective_balance,
+                activation_eligibility_epoch,
+                activation_epoch: FAR_FUTURE_EPOCH,
+                exit_epoch: FAR_FUTURE_EPOCH,
+                withdrawable_epoch: FAR_FUTURE_EPOCH,
+                ..Default::default()
+            });
+            state.balances.push(context.max_effective_balance);
+        }
+        state
+    }
+
+    #[test]
+    fn test_activation_queue_respects_churn_and_order() {
+        let context = Context::for_minimal();
+        // the last entry is not yet finalized and must not be dequeued
+        let eligibility_epochs = [7, 5, 8, 5, 6, 9];
+        let mut state = state_with_activation_queue(&eligibility_epochs, &context);
+
+        let churn_limit = get_validator_churn_limit(&state, &context);
+        assert_eq!(churn_limit, context.min_per_epoch_churn_limit as usize);
+
+        process_registry_updates(&mut state, &context).unwrap();
+
+        let activation_epoch = compute_activation_exit_epoch(10, &context);
+        let activated = state
+            .validators
+            .iter()
+            .enumerate()
+            .skip(ACTIVE_VALIDATOR_COUNT)
+            .filter(|(_, validator)| validator.activation_epoch == activation_epoch)
+            .map(|(index, _)| index - ACTIVE_VALIDATOR_COUNT)
+            .collect::<Vec<_>>();
+        // both validators eligible in epoch 5, ordered by index
+        assert_eq!(activated, vec![1, 3]);
+    }
+
+    #[test]
+    fn test_randao_mixes_reset() {
+        let context = Context::for_minimal();
+        let mut state = BeaconState::default();
+        // last epoch of the vector so the next mix wraps around to index 0
+        let current_epoch = context.epochs_per_historical_vector - 1;
+        state.slot = current_epoch * context.slots_per_epoch;
+        let mix = Bytes32::try_from([0xab; 32].as_ref()).unwrap();
+        state.randao_mixes[current_epoch as usize] = mix.clone();
+
+        process_randao_mixes_reset(&mut state, &context);
+
+        assert_eq!(state.randao_mixes[0], mix);
+        assert_eq!(state.randao_mixes[current_epoch as usize], mix);
+        assert!(state
+            .randao_mixes
+            .iter()
+            .skip(1)
+            .take(current_epoch as usize - 1)
+            .all(|mix| *mix == Bytes32::default()));
+    }
+
+    #[test]
+    fn test_historical_roots_update() {
+        let context = Context::for_minimal();
+        let epochs_per_historical_root =
+            context.slots_per_historical_root / context.slots_per_epoch;
+        let mut state = BeaconState::default();
+        for i in 0..context.slots_per_historical_root as usize {
+            state.block_roots[i] = Root::try_from([i as u8; 32].as_ref()).unwrap();
+            state.state_roots[i] = Root::try_from([(i + 64) as u8; 32].as_ref()).unwrap();
+        }
+
+        // not at the boundary
+        state.slot = (epochs_per_historical_root - 2) * context.slots_per_epoch;
+        process_historical_roots_update(&mut state, &context).unwrap();
+        assert!(state.historical_roots.is_empty());
+
+        // last epoch before the boundary
+        state.slot += context.slots_per_epoch;
+        process_historical_roots_update(&mut state, &context).unwrap();
+        let historical_batch = HistoricalBatch {
+            block_roots: state.block_roots.clone(),
+            state_roots: state.state_roots.clone(),
+        };
+        assert_eq!(state.historical_roots.len(), 1);
+        assert_eq!(state.historical_roots[0], historical_batch.hash_tree_root().unwrap());
+    }
+}
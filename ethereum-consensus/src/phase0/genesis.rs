@@ -1,4 +1,5 @@
 use crate::{
+    block_body::BlockBody,
     phase0::{
         beacon_block::{BeaconBlock, BeaconBlockBody, BeaconBlockHeader},
         beacon_state::{BeaconState, Fork},
@@ -61,7 +62,7 @@ pub fn initialize_beacon_state_from_eth1<
         MAX_DEPOSITS,
         MAX_VOLUNTARY_EXITS,
     >::default();
-    let body_root = latest_block_body.hash_tree_root()?;
+    let body_root = latest_block_body.body_root()?;
     let latest_block_header = BeaconBlockHeader { body_root, ..Default::default() };
     let randao_mixes = Vector::try_from(
         std::iter::repeat_n(eth1_block_hash, context.epochs_per_historical_vector as usize)
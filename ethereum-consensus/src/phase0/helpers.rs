@@ -1,16 +1,16 @@
 use crate::{
-    crypto::{fast_aggregate_verify, hash},
+    crypto::{aggregate, fast_aggregate_verify, hash},
     error::{
         invalid_operation_error, InvalidAttestation, InvalidIndexedAttestation, InvalidOperation,
     },
     phase0::{
-        beacon_block::SignedBeaconBlock,
+        beacon_block::{SignedBeaconBlock, SignedBeaconBlockHeader},
         beacon_state::{BeaconState, ForkData},
         operations::{Attestation, AttestationData, IndexedAttestation},
         validator::Validator,
     },
     primitives::{
-        Bytes32, CommitteeIndex, Domain, DomainType, Epoch, ForkDigest, Gwei, Root,
+        BlsPublicKey, Bytes32, CommitteeIndex, Domain, DomainType, Epoch, ForkDigest, Gwei, Root,
         ShuffledIndices, Slot, ValidatorIndex, Version, FAR_FUTURE_EPOCH, GENESIS_EPOCH,
     },
     signing::{compute_signing_root, verify_signed_data},
@@ -18,7 +18,10 @@ use crate::{
     state_transition::{Context, Result},
     Error,
 };
-use std::{cmp, collections::HashSet};
+use std::{
+    cmp,
+    collections::{HashMap, HashSet},
+};
 
 pub fn is_active_validator(validator: &Validator, epoch: Epoch) -> bool {
     validator.activation_epoch <= epoch && epoch < validator.exit_epoch
@@ -68,6 +71,30 @@ pub fn is_slashable_attestation_data(data_1: &AttestationData, data_2: &Attestat
     double_vote || surround_vote
 }
 
+// Check that `leaf` is at `index` in a tree of `depth` with `root`, where `branch` holds the
+// sibling of each node on the path from the leaf up to the root.
+pub fn verify_merkle_branch(
+    leaf: Root,
+    branch: &[Root],
+    depth: usize,
+    index: usize,
+    root: Root,
+) -> bool {
+    if branch.len() < depth {
+        return false
+    }
+    let mut value = leaf;
+    let mut input = [0u8; 64];
+    for (i, sibling) in branch.iter().take(depth).enumerate() {
+        let bit = index.checked_shr(i as u32).unwrap_or(0) % 2;
+        let (left, right) = if bit == 1 { (sibling, &value) } else { (&value, sibling) };
+        input[..32].copy_from_slice(left.as_ref());
+        input[32..].copy_from_slice(right.as_ref());
+        value = Root::try_from(hash(input).as_ref()).expect("hash is 32 bytes");
+    }
+    value == root
+}
+
 pub fn is_valid_indexed_attestation<
     const SLOTS_PER_HISTORICAL_ROOT: usize,
     const HISTORICAL_ROOTS_LIMIT: usize,
@@ -187,6 +214,41 @@ pub fn verify_block_signature<
     verify_signed_data(&signed_block.message, &signed_block.signature, public_key, domain)
 }
 
+pub fn verify_block_header_signature<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const PENDING_ATTESTATIONS_BOUND: usize,
+>(
+    state: &BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        PENDING_ATTESTATIONS_BOUND,
+    >,
+    signed_header: &SignedBeaconBlockHeader,
+    context: &Context,
+) -> Result<()> {
+    let proposer_index = signed_header.message.proposer_index;
+    let proposer = state
+        .validators
+        .get(proposer_index)
+        .ok_or(Error::OutOfBounds { requested: proposer_index, bound: state.validators.len() })?;
+    let epoch = compute_epoch_at_slot(signed_header.message.slot, context);
+    let domain = get_domain(state, DomainType::BeaconProposer, Some(epoch), context)?;
+
+    let public_key = &proposer.public_key;
+    verify_signed_data(&signed_header.message, &signed_header.signature, public_key, domain)
+}
+
 pub fn get_domain<
     const SLOTS_PER_HISTORICAL_ROOT: usize,
     const HISTORICAL_ROOTS_LIMIT: usize,
@@ -1170,3 +1232,425 @@ pub fn get_eligible_validator_indices<
         }
     })
 }
+
+// Merge two aggregates over the same `AttestationData` with disjoint participants.
+pub fn aggregate_attestations<const MAX_VALIDATORS_PER_COMMITTEE: usize>(
+    a: &Attestation<MAX_VALIDATORS_PER_COMMITTEE>,
+    b: &Attestation<MAX_VALIDATORS_PER_COMMITTEE>,
+) -> Result<Attestation<MAX_VALIDATORS_PER_COMMITTEE>> {
+    if a.data != b.data || a.aggregation_bits.len() != b.aggregation_bits.len() {
+        return Err(Error::IncompatibleAttestations)
+    }
+    let overlap = a.aggregation_bits.intersection(&b.aggregation_bits).expect("same length");
+    if overlap.count_ones() != 0 {
+        return Err(Error::OverlappingAggregationBits)
+    }
+    let aggregation_bits = a.aggregation_bits.union(&b.aggregation_bits).expect("same length");
+    let signature = aggregate(&[a.signature.clone(), b.signature.clone()])?;
+    Ok(Attestation { aggregation_bits, data: a.data.clone(), signature })
+}
+
+pub fn build_pubkey_index<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const PENDING_ATTESTATIONS_BOUND: usize,
+>(
+    state: &BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        PENDING_ATTESTATIONS_BOUND,
+    >,
+) -> HashMap<BlsPublicKey, ValidatorIndex> {
+    let mut index = HashMap::with_capacity(state.validators.len());
+    update_pubkey_index(&mut index, state);
+    index
+}
+
+// Extend `index` with any validators added to the registry since it was last updated.
+// NOTE: the registry is append-only, so existing entries remain valid across epochs.
+pub fn update_pubkey_index<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const PENDING_ATTESTATIONS_BOUND: usize,
+>(
+    index: &mut HashMap<BlsPublicKey, ValidatorIndex>,
+    state: &BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        PENDING_ATTESTATIONS_BOUND,
+    >,
+) {
+    for (i, validator) in state.validators.iter().enumerate().skip(index.len()) {
+        index.insert(validator.public_key.clone(), i);
+    }
+}
+
+pub fn build_validator_pubkeys<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const PENDING_ATTESTATIONS_BOUND: usize,
+>(
+    state: &BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        PENDING_ATTESTATIONS_BOUND,
+    >,
+) -> HashMap<ValidatorIndex, BlsPublicKey> {
+    let mut pubkeys = HashMap::with_capacity(state.validators.len());
+    update_validator_pubkeys(&mut pubkeys, state);
+    pubkeys
+}
+
+// Extend `pubkeys` with any validators added to the registry since it was last updated.
+pub fn update_validator_pubkeys<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const PENDING_ATTESTATIONS_BOUND: usize,
+>(
+    pubkeys: &mut HashMap<ValidatorIndex, BlsPublicKey>,
+    state: &BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        PENDING_ATTESTATIONS_BOUND,
+    >,
+) {
+    for (i, validator) in state.validators.iter().enumerate().skip(pubkeys.len()) {
+        pubkeys.insert(i, validator.public_key.clone());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        crypto::SecretKey,
+        phase0::{
+            beacon_block::BeaconBlockHeader, minimal::BeaconState, operations::Checkpoint,
+            slot_processing::process_slot,
+        },
+        signing::sign_with_domain,
+        test_utils::push_active_validators,
+    };
+
+    fn validator_with_lifecycle(
+        activation_epoch: Epoch,
+        exit_epoch: Epoch,
+        withdrawable_epoch: Epoch,
+    ) -> Validator {
+        Validator {
+            activation_eligibility_epoch: activation_epoch.saturating_sub(5),
+            activation_epoch,
+            exit_epoch,
+            withdrawable_epoch,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_is_active_validator() {
+        let validator = validator_with_lifecycle(10, 20, 30);
+        assert!(!is_active_validator(&validator, 9));
+        assert!(is_active_validator(&validator, 10));
+        assert!(is_active_validator(&validator, 19));
+        assert!(!is_active_validator(&validator, 20));
+        assert!(!is_active_validator(&validator, 30));
+    }
+
+    #[test]
+    fn test_is_slashable_validator() {
+        let mut validator = validator_with_lifecycle(10, 20, 30);
+        assert!(!is_slashable_validator(&validator, 9));
+        assert!(is_slashable_validator(&validator, 10));
+        // still slashable after exit, until withdrawable
+        assert!(is_slashable_validator(&validator, 20));
+        assert!(is_slashable_validator(&validator, 29));
+        assert!(!is_slashable_validator(&validator, 30));
+
+        validator.slashed = true;
+        assert!(!is_slashable_validator(&validator, 15));
+    }
+
+    #[test]
+    fn test_is_eligible_for_activation() {
+        let mut state = BeaconState::default();
+        state.finalized_checkpoint.epoch = 4;
+
+        let mut validator = validator_with_lifecycle(FAR_FUTURE_EPOCH, FAR_FUTURE_EPOCH, 0);
+        validator.activation_eligibility_epoch = 4;
+        assert!(is_eligible_for_activation(&state, &validator));
+
+        validator.activation_eligibility_epoch = 5;
+        assert!(!is_eligible_for_activation(&state, &validator));
+
+        validator.activation_eligibility_epoch = 3;
+        validator.activation_epoch = 8;
+        assert!(!is_eligible_for_activation(&state, &validator));
+    }
+
+    #[test]
+    fn test_compute_activation_exit_epoch() {
+        // `MAX_SEED_LOOKAHEAD` is 4 in the mainnet preset
+        let context = Context::for_mainnet();
+        assert_eq!(compute_activation_exit_epoch(10, &context), 15);
+    }
+
+    #[test]
+    fn test_compute_proposer_index_matches_beacon_proposer() {
+        let context = Context::for_minimal();
+        let mut state = BeaconState::default();
+        push_active_validators(&mut state.validators, &mut state.balances, 16, &context);
+        for (i, validator) in state.validators.iter_mut().enumerate() {
+            validator.effective_balance -= (i as u64 % 4) * 8 * 10u64.pow(9);
+        }
+
+        let epoch = get_current_epoch(&state, &context);
+        let indices = get_active_validator_indices(&state, epoch);
+        let epoch_seed = get_seed(&state, epoch, DomainType::BeaconProposer, &context);
+        for slot in 0..context.slots_per_epoch {
+            state.slot = slot;
+            let mut input = [0u8; 40];
+            input[..32].copy_from_slice(epoch_seed.as_ref());
+            input[32..40].copy_from_slice(&slot.to_le_bytes());
+            let seed = hash(input);
+
+            let expected = get_beacon_proposer_index(&state, &context).unwrap();
+            assert_eq!(
+                compute_proposer_index(&state, &indices, &seed, &context).unwrap(),
+                expected
+            );
+
+            let candidates = [3, 7, 11];
+            let index = compute_proposer_index(&state, &candidates, &seed, &context).unwrap();
+            assert!(candidates.contains(&index));
+        }
+
+        assert!(matches!(
+            compute_proposer_index(&state, &[], &Bytes32::default(), &context),
+            Err(Error::CollectionCannotBeEmpty)
+        ));
+    }
+
+    #[test]
+    fn test_attestation_data_slashable_votes() {
+        let vote = |source, target| AttestationData {
+            source: Checkpoint { epoch: source, ..Default::default() },
+            target: Checkpoint { epoch: target, ..Default::default() },
+            ..Default::default()
+        };
+
+        let outer = vote(1, 6);
+        let inner = vote(2, 5);
+        assert!(outer.surrounds(&inner));
+        assert!(!inner.surrounds(&outer));
+        assert!(inner.is_surrounded_by(&outer));
+        assert!(!outer.is_surrounded_by(&inner));
+        assert!(!outer.is_double_vote(&inner));
+        assert!(is_slashable_attestation_data(&outer, &inner));
+        assert!(!is_slashable_attestation_data(&inner, &outer));
+
+        // sharing an endpoint is not a surround
+        assert!(!vote(1, 6).surrounds(&vote(1, 5)));
+        assert!(!vote(1, 6).surrounds(&vote(2, 6)));
+
+        let mut other = vote(2, 6);
+        assert!(outer.is_double_vote(&other));
+        assert!(other.is_double_vote(&outer));
+        assert!(is_slashable_attestation_data(&outer, &other));
+        other.source.epoch = outer.source.epoch;
+        assert!(!outer.is_double_vote(&other));
+        assert!(!is_slashable_attestation_data(&outer, &other));
+    }
+
+    #[test]
+    fn test_get_block_root_with_empty_epoch_start_slot() {
+        let context = Context::for_minimal();
+        let advance_to = |state: &mut BeaconState, slot: Slot| {
+            while state.slot < slot {
+                process_slot(state, &context).unwrap();
+                state.slot += 1;
+            }
+        };
+        let mut state = BeaconState::default();
+        // the last block lands just before the start of epoch 2 so its start slot is empty
+        let block_slot = 2 * context.slots_per_epoch - 1;
+        advance_to(&mut state, block_slot);
+        state.latest_block_header =
+            BeaconBlockHeader { slot: block_slot, proposer_index: 1, ..Default::default() };
+        advance_to(&mut state, 2 * context.slots_per_epoch + 3);
+
+        let block_root = state.latest_block_header.hash_tree_root().unwrap();
+        assert_eq!(*get_block_root_at_slot(&state, block_slot).unwrap(), block_root);
+        assert_eq!(*get_block_root(&state, 2, &context).unwrap(), block_root);
+        assert_ne!(*get_block_root(&state, 1, &context).unwrap(), block_root);
+        // no root is available for the start of an epoch that has not yet been reached
+        assert!(get_block_root(&state, 3, &context).is_err());
+    }
+
+    #[test]
+    fn test_verify_block_header_signature() {
+        let context = Context::for_minimal();
+        let mut rng = rand::thread_rng();
+        let secret_key = SecretKey::random(&mut rng).unwrap();
+        let mut state = BeaconState::default();
+        state.validators.push(Validator::default());
+        state
+            .validators
+            .push(Validator { public_key: secret_key.public_key(), ..Default::default() });
+
+        let header = BeaconBlockHeader { slot: 3, proposer_index: 1, ..Default::default() };
+        let epoch = compute_epoch_at_slot(header.slot, &context);
+        let domain = get_domain(&state, DomainType::BeaconProposer, Some(epoch), &context).unwrap();
+        let signature = sign_with_domain(&header, &secret_key, domain).unwrap();
+        let mut signed_header = SignedBeaconBlockHeader { message: header, signature };
+        assert!(verify_block_header_signature(&state, &signed_header, &context).is_ok());
+
+        signed_header.message.slot += 1;
+        assert!(verify_block_header_signature(&state, &signed_header, &context).is_err());
+
+        signed_header.message.slot -= 1;
+        signed_header.message.proposer_index = 0;
+        assert!(verify_block_header_signature(&state, &signed_header, &context).is_err());
+
+        signed_header.message.proposer_index = 2;
+        assert!(matches!(
+            verify_block_header_signature(&state, &signed_header, &context),
+            Err(Error::OutOfBounds { requested: 2, bound: 2 })
+        ));
+    }
+
+    #[test]
+    fn test_build_pubkey_index() {
+        let mut rng = rand::thread_rng();
+        let secret_keys = (0..4).map(|_| SecretKey::random(&mut rng).unwrap()).collect::<Vec<_>>();
+        let mut state = BeaconState::default();
+        for secret_key in &secret_keys[..3] {
+            state
+                .validators
+                .push(Validator { public_key: secret_key.public_key(), ..Default::default() });
+        }
+
+        let mut index = build_pubkey_index(&state);
+        let mut pubkeys = build_validator_pubkeys(&state);
+        assert_eq!(index.len(), 3);
+        assert_eq!(index[&secret_keys[1].public_key()], 1);
+        assert_eq!(pubkeys[&1], secret_keys[1].public_key());
+        assert!(!index.contains_key(&secret_keys[3].public_key()));
+
+        state
+            .validators
+            .push(Validator { public_key: secret_keys[3].public_key(), ..Default::default() });
+        update_pubkey_index(&mut index, &state);
+        update_validator_pubkeys(&mut pubkeys, &state);
+        assert_eq!(index, build_pubkey_index(&state));
+        assert_eq!(pubkeys, build_validator_pubkeys(&state));
+        assert_eq!(index[&secret_keys[3].public_key()], 3);
+        for (public_key, i) in &index {
+            assert_eq!(&pubkeys[i], public_key);
+        }
+    }
+
+    #[test]
+    fn test_aggregate_attestations() {
+        let mut rng = rand::thread_rng();
+        let secret_keys = (0..3).map(|_| SecretKey::random(&mut rng).unwrap()).collect::<Vec<_>>();
+        let data = AttestationData { slot: 5, index: 1, ..Default::default() };
+        let attestation = |bits: &[bool], signers: &[usize]| {
+            let signatures =
+                signers.iter().map(|&i| secret_keys[i].sign(b"attestation")).collect::<Vec<_>>();
+            Attestation::<4> {
+                aggregation_bits: Bitlist::try_from(bits).unwrap(),
+                data: data.clone(),
+                signature: aggregate(&signatures).unwrap(),
+            }
+        };
+
+        let a = attestation(&[true, false, false, true], &[0]);
+        let b = attestation(&[false, true, false, false], &[1, 2]);
+        let merged = aggregate_attestations(&a, &b).unwrap();
+        let bits = merged.aggregation_bits.iter().map(|bit| *bit).collect::<Vec<_>>();
+        assert_eq!(bits, [true, true, false, true]);
+        assert_eq!(merged.data, data);
+        assert_eq!(
+            merged.signature,
+            aggregate(&[a.signature.clone(), b.signature.clone()]).unwrap()
+        );
+
+        let overlapping = attestation(&[false, true, false, true], &[2]);
+        assert!(matches!(
+            aggregate_attestations(&a, &overlapping),
+            Err(Error::OverlappingAggregationBits)
+        ));
+
+        let mut other_data = attestation(&[false, true, false, false], &[1]);
+        other_data.data.slot += 1;
+        assert!(matches!(
+            aggregate_attestations(&a, &other_data),
+            Err(Error::IncompatibleAttestations)
+        ));
+    }
+
+    #[test]
+    fn test_verify_merkle_branch() {
+        let node = |byte: u8| Root::try_from([byte; 32].as_ref()).unwrap();
+        let hash_pair = |left: &Root, right: &Root| {
+            let mut input = [0u8; 64];
+            input[..32].copy_from_slice(left.as_ref());
+            input[32..].copy_from_slice(right.as_ref());
+            Root::try_from(hash(input).as_ref()).unwrap()
+        };
+
+        let leaves = (0..8).map(node).collect::<Vec<_>>();
+        let layer_1 =
+            leaves.chunks(2).map(|pair| hash_pair(&pair[0], &pair[1])).collect::<Vec<_>>();
+        let layer_2 =
+            layer_1.chunks(2).map(|pair| hash_pair(&pair[0], &pair[1])).collect::<Vec<_>>();
+        let root = hash_pair(&layer_2[0], &layer_2[1]);
+
+        // leaf 5 = 0b101: right child, then left child, then right child
+        let branch = [leaves[4], layer_1[3], layer_2[0]];
+        assert!(verify_merkle_branch(leaves[5], &branch, 3, 5, root));
+        assert!(!verify_merkle_branch(leaves[5], &branch, 3, 4, root));
+        assert!(!verify_merkle_branch(leaves[4], &branch, 3, 5, root));
+        assert!(!verify_merkle_branch(leaves[5], &branch[..2], 3, 5, root));
+    }
+}
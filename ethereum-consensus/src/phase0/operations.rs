@@ -29,6 +29,23 @@ pub struct AttestationData {
     pub target: Checkpoint,
 }
 
+impl AttestationData {
+    // Distinct votes for the same target epoch.
+    pub fn is_double_vote(&self, other: &Self) -> bool {
+        self != other && self.target.epoch == other.target.epoch
+    }
+
+    // The source-target span of `self` strictly contains the span of `other`.
+    pub fn surrounds(&self, other: &Self) -> bool {
+        self.source.epoch < other.source.epoch && other.target.epoch < self.target.epoch
+    }
+
+    // The source-target span of `other` strictly contains the span of `self`.
+    pub fn is_surrounded_by(&self, other: &Self) -> bool {
+        other.surrounds(self)
+    }
+}
+
 #[derive(
     Default, Debug, SimpleSerialize, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize,
 )]
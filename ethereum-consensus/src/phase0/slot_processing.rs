@@ -1,5 +1,5 @@
 use crate::{
-    phase0::{beacon_state::BeaconState, epoch_processing::process_epoch},
+    phase0::{beacon_state::BeaconState, epoch_processing::process_epoch, validator::Validator},
     primitives::{Root, Slot},
     ssz::prelude::*,
     state_transition::{Context, Result},
@@ -78,3 +78,151 @@ pub fn process_slot<
 
     Ok(())
 }
+
+// Like `process_slots` but computes each state root with `cache`, which can be kept across
+// calls (e.g. when replaying a chain) so unchanged validators are not rehashed every slot.
+pub fn process_slots_with_cache<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const PENDING_ATTESTATIONS_BOUND: usize,
+>(
+    state: &mut BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        PENDING_ATTESTATIONS_BOUND,
+    >,
+    slot: Slot,
+    cache: &mut ListRootCache<Validator>,
+    context: &Context,
+) -> Result<()> {
+    if state.slot >= slot {
+        return Err(Error::TransitionToPreviousSlot { requested: slot, current: state.slot })
+    }
+    while state.slot < slot {
+        process_slot_with_cache(state, cache, context)?;
+        if (state.slot + 1) % context.slots_per_epoch == 0 {
+            process_epoch(state, context)?;
+        }
+        state.slot += 1;
+    }
+    Ok(())
+}
+
+pub fn process_slot_with_cache<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const PENDING_ATTESTATIONS_BOUND: usize,
+>(
+    state: &mut BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        PENDING_ATTESTATIONS_BOUND,
+    >,
+    cache: &mut ListRootCache<Validator>,
+    context: &Context,
+) -> Result<()> {
+    let previous_state_root = state.hash_tree_root_with_cache(cache)?;
+    let root_index = state.slot % context.slots_per_historical_root;
+    state.state_roots[root_index as usize] = previous_state_root;
+
+    if state.latest_block_header.state_root == Root::default() {
+        state.latest_block_header.state_root = previous_state_root;
+    }
+
+    let previous_block_root = state.latest_block_header.hash_tree_root()?;
+    let root_index = state.slot % context.slots_per_historical_root;
+    state.block_roots[root_index as usize] = previous_block_root;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::phase0::minimal::BeaconState;
+
+    #[test]
+    fn test_process_slots_with_cache() {
+        let context = Context::for_minimal();
+        let mut state = BeaconState::default();
+        for i in 0..10 {
+            let validator = Validator { effective_balance: i, ..Default::default() };
+            state.validators.push(validator);
+            state.balances.push(i);
+        }
+        let mut naive_state = state.clone();
+        let mut cache = ListRootCache::new();
+
+        // stay within the first epoch so that only slot processing is exercised
+        for slot in 1..context.slots_per_epoch {
+            let index = slot as usize % 3;
+            state.validators[index].effective_balance += 1;
+            naive_state.validators[index].effective_balance += 1;
+
+            process_slots_with_cache(&mut state, slot, &mut cache, &context).unwrap();
+            process_slots(&mut naive_state, slot, &context).unwrap();
+            assert_eq!(state.state_roots, naive_state.state_roots);
+            assert_eq!(
+                state.hash_tree_root_with_cache(&mut cache).unwrap(),
+                naive_state.hash_tree_root().unwrap()
+            );
+        }
+    }
+
+    // Compares the time to replay an epoch of empty slots with and without a cache on a
+    // registry of a few thousand validators, a handful of which change every slot.
+    // Run with `cargo test --release -- --ignored --nocapture bench_process_slots_with_cache`.
+    #[test]
+    #[ignore]
+    fn bench_process_slots_with_cache() {
+        use std::time::Instant;
+
+        let context = Context::for_minimal();
+        let mut state = BeaconState::default();
+        for i in 0..4096 {
+            let validator = Validator { effective_balance: i, ..Default::default() };
+            state.validators.push(validator);
+            state.balances.push(i);
+        }
+        let mut naive_state = state.clone();
+        let slots = 1..context.slots_per_epoch;
+
+        let start = Instant::now();
+        for slot in slots.clone() {
+            naive_state.validators[slot as usize].slashed = true;
+            process_slots(&mut naive_state, slot, &context).unwrap();
+        }
+        let without_cache = start.elapsed();
+
+        let start = Instant::now();
+        let mut cache = ListRootCache::new();
+        for slot in slots {
+            state.validators[slot as usize].slashed = true;
+            process_slots_with_cache(&mut state, slot, &mut cache, &context).unwrap();
+        }
+        let with_cache = start.elapsed();
+
+        assert_eq!(state.state_roots, naive_state.state_roots);
+        println!("process_slots: {without_cache:?}, process_slots_with_cache: {with_cache:?}");
+    }
+}
@@ -11,11 +11,11 @@ pub use crate::{
             add_validator_to_registry, apply_deposit, get_validator_from_deposit,
             process_attestation, process_attester_slashing, process_block, process_block_header,
             process_deposit, process_eth1_data, process_operations, process_proposer_slashing,
-            process_randao, process_voluntary_exit, xor,
+            process_randao, process_voluntary_exit, verify_proposer_index, xor,
         },
         constants::{
             BASE_REWARDS_PER_EPOCH, DEPOSIT_CONTRACT_TREE_DEPTH, DEPOSIT_DATA_LIST_BOUND,
-            JUSTIFICATION_BITS_LENGTH,
+            JUSTIFICATION_BITS_LENGTH, TARGET_AGGREGATORS_PER_COMMITTEE,
         },
         epoch_processing::{
             get_attestation_component_deltas, get_attestation_deltas, get_attesting_balance,
@@ -32,6 +32,7 @@ pub use crate::{
         },
         genesis::{get_genesis_block, initialize_beacon_state_from_eth1, is_valid_genesis_state},
         helpers::{
+            aggregate_attestations, build_pubkey_index, build_validator_pubkeys,
             compute_activation_exit_epoch, compute_committee, compute_domain,
             compute_epoch_at_slot, compute_fork_data_root, compute_fork_digest,
             compute_proposer_index, compute_shuffled_index, compute_shuffled_indices,
@@ -43,16 +44,26 @@ pub use crate::{
             get_validator_churn_limit, increase_balance, initiate_validator_exit,
             is_active_validator, is_eligible_for_activation, is_eligible_for_activation_queue,
             is_slashable_attestation_data, is_slashable_validator, is_valid_indexed_attestation,
-            sample_proposer_index, slash_validator, verify_block_signature,
+            sample_proposer_index, slash_validator, update_pubkey_index, update_validator_pubkeys,
+            verify_block_header_signature, verify_block_signature, verify_merkle_branch,
         },
         operations::{
             Attestation, AttestationData, AttesterSlashing, Checkpoint, Deposit, DepositData,
             DepositMessage, Eth1Data, IndexedAttestation, PendingAttestation, ProposerSlashing,
             SignedVoluntaryExit, VoluntaryExit,
         },
-        slot_processing::{process_slot, process_slots},
-        state_transition::{state_transition, state_transition_block_in_slot},
-        validator::{AggregateAndProof, Eth1Block, SignedAggregateAndProof, Validator},
+        slot_processing::{
+            process_slot, process_slot_with_cache, process_slots, process_slots_with_cache,
+        },
+        state_transition::{
+            compute_post_state_root, state_transition, state_transition_block_in_slot,
+            validate_state_root,
+        },
+        validator::{
+            compute_time_at_slot, get_eth1_data, get_eth1_vote, is_aggregator, is_candidate_block,
+            verify_aggregate_and_proof, voting_period_start_time, AggregateAndProof, Eth1Block,
+            SignedAggregateAndProof, Validator,
+        },
     },
     primitives::*,
     signing::*,
@@ -1,14 +1,60 @@
 use crate::{
     phase0::{
-        beacon_block::SignedBeaconBlock, beacon_state::BeaconState,
-        block_processing::process_block, helpers::verify_block_signature,
+        beacon_block::{BeaconBlock, SignedBeaconBlock},
+        beacon_state::BeaconState,
+        block_processing::{process_block, verify_proposer_index},
+        helpers::verify_block_signature,
         slot_processing::process_slots,
     },
+    primitives::Root,
     ssz::prelude::*,
     state_transition::{Context, Result, Validation},
     Error,
 };
 
+// The final step of a validated state transition: the root of the post-state must match
+// the `state_root` committed to in the block.
+pub fn validate_state_root<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const PENDING_ATTESTATIONS_BOUND: usize,
+    const MAX_PROPOSER_SLASHINGS: usize,
+    const MAX_ATTESTER_SLASHINGS: usize,
+    const MAX_ATTESTATIONS: usize,
+    const MAX_DEPOSITS: usize,
+    const MAX_VOLUNTARY_EXITS: usize,
+>(
+    state: &BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        PENDING_ATTESTATIONS_BOUND,
+    >,
+    block: &BeaconBlock<
+        MAX_PROPOSER_SLASHINGS,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        MAX_ATTESTER_SLASHINGS,
+        MAX_ATTESTATIONS,
+        MAX_DEPOSITS,
+        MAX_VOLUNTARY_EXITS,
+    >,
+) -> Result<()> {
+    let root = state.hash_tree_root()?;
+    if block.state_root != root {
+        return Err(Error::StateRootMismatch { expected: block.state_root, got: root })
+    }
+    Ok(())
+}
+
 // `state_transition_block_in_slot` is separated out
 // to facilitate upgrades across forks which take place
 // after the call to `process_slots` in the sub-transition.
@@ -52,16 +98,16 @@ pub fn state_transition_block_in_slot<
         Validation::Enabled => true,
         Validation::Disabled => false,
     };
+    let block = &signed_block.message;
     if validate_result {
+        verify_proposer_index(state, block, context)?;
         verify_block_signature(state, signed_block, context)?;
     }
-    let block = &signed_block.message;
     process_block(state, block, context)?;
-    if validate_result && block.state_root != state.hash_tree_root()? {
-        Err(Error::InvalidStateRoot)
-    } else {
-        Ok(())
+    if validate_result {
+        validate_state_root(state, block)?;
     }
+    Ok(())
 }
 
 pub fn state_transition<
@@ -104,3 +150,128 @@ pub fn state_transition<
 
     state_transition_block_in_slot(state, signed_block, validation, context)
 }
+
+// Applies `signed_block` to a copy of `pre_state` (verifying the proposer and its signature)
+// and returns the root of the resulting state instead of the state itself, for callers that
+// only need to check a block's `state_root`.
+pub fn compute_post_state_root<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const PENDING_ATTESTATIONS_BOUND: usize,
+    const MAX_PROPOSER_SLASHINGS: usize,
+    const MAX_ATTESTER_SLASHINGS: usize,
+    const MAX_ATTESTATIONS: usize,
+    const MAX_DEPOSITS: usize,
+    const MAX_VOLUNTARY_EXITS: usize,
+>(
+    pre_state: &BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        PENDING_ATTESTATIONS_BOUND,
+    >,
+    signed_block: &SignedBeaconBlock<
+        MAX_PROPOSER_SLASHINGS,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        MAX_ATTESTER_SLASHINGS,
+        MAX_ATTESTATIONS,
+        MAX_DEPOSITS,
+        MAX_VOLUNTARY_EXITS,
+    >,
+    context: &Context,
+) -> Result<Root> {
+    let mut state = pre_state.clone();
+    let block = &signed_block.message;
+    process_slots(&mut state, block.slot, context)?;
+    verify_proposer_index(&state, block, context)?;
+    verify_block_signature(&state, signed_block, context)?;
+    process_block(&mut state, block, context)?;
+    Ok(state.hash_tree_root()?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "test-utils")]
+    #[test]
+    fn test_post_state_root() {
+        use crate::{
+            crypto::test_keys,
+            phase0::{get_beacon_proposer_index, get_current_epoch, get_domain, minimal},
+            primitives::DomainType,
+            signing::sign_with_domain,
+            state_transition::minimal::GenesisBuilder,
+            types, Fork,
+        };
+
+        let context = Context::for_minimal();
+        let validator_count = context.min_genesis_active_validator_count;
+        let keys = test_keys(validator_count);
+        let builder = GenesisBuilder::new(validator_count, context).with_fork(Fork::Phase0);
+        let types::BeaconState::Phase0(pre_state) = builder.build().unwrap() else {
+            panic!("expected a phase0 state")
+        };
+        let context = builder.context;
+
+        let mut state = pre_state.clone();
+        process_slots(&mut state, 1, &context).unwrap();
+        let proposer_index = get_beacon_proposer_index(&state, &context).unwrap();
+        let key = &keys[proposer_index];
+        let epoch = get_current_epoch(&state, &context);
+        let randao_reveal = sign_with_domain(
+            &epoch,
+            key,
+            get_domain(&state, DomainType::Randao, None, &context).unwrap(),
+        )
+        .unwrap();
+        let mut block = minimal::BeaconBlock {
+            slot: 1,
+            proposer_index,
+            parent_root: state.latest_block_header.hash_tree_root().unwrap(),
+            body: minimal::BeaconBlockBody { randao_reveal, ..Default::default() },
+            ..Default::default()
+        };
+        let sign = |block: minimal::BeaconBlock| {
+            let domain = get_domain(&state, DomainType::BeaconProposer, None, &context).unwrap();
+            let signature = sign_with_domain(&block, key, domain).unwrap();
+            minimal::SignedBeaconBlock { message: block, signature }
+        };
+
+        // the post-state does not depend on the block's own `state_root`
+        block.state_root =
+            compute_post_state_root(&pre_state, &sign(block.clone()), &context).unwrap();
+        let signed_block = sign(block);
+        let root = compute_post_state_root(&pre_state, &signed_block, &context).unwrap();
+        assert_eq!(root, signed_block.message.state_root);
+
+        let mut state = pre_state.clone();
+        state_transition(&mut state, &signed_block, Validation::Enabled, &context).unwrap();
+        assert_eq!(state.hash_tree_root().unwrap(), root);
+
+        let wrong_root = Root::try_from([1u8; 32].as_ref()).unwrap();
+        let mut block = signed_block.message.clone();
+        block.state_root = wrong_root;
+        let signed_block = sign(block);
+        let mut state = pre_state.clone();
+        let err =
+            state_transition(&mut state, &signed_block, Validation::Enabled, &context).unwrap_err();
+        assert!(matches!(
+            err,
+            Error::StateRootMismatch { expected, got } if expected == wrong_root && got == root
+        ));
+
+        let mut state = pre_state.clone();
+        state_transition(&mut state, &signed_block, Validation::Disabled, &context).unwrap();
+        assert_eq!(state.hash_tree_root().unwrap(), root);
+    }
+}
@@ -1,7 +1,19 @@
 use crate::{
-    phase0::operations::Attestation,
-    primitives::{BlsPublicKey, BlsSignature, Bytes32, Epoch, Gwei, Root, ValidatorIndex},
+    crypto::{fast_aggregate_verify, hash},
+    error::InvalidAggregateAndProof,
+    phase0::{
+        beacon_state::BeaconState,
+        constants::TARGET_AGGREGATORS_PER_COMMITTEE,
+        helpers::{compute_epoch_at_slot, get_beacon_committee, get_domain},
+        operations::{Attestation, Eth1Data},
+    },
+    primitives::{
+        BlsPublicKey, BlsSignature, Bytes32, CommitteeIndex, DomainType, Epoch, Gwei, Hash32, Root,
+        Slot, ValidatorIndex,
+    },
+    signing::{compute_signing_root, verify_signed_data},
     ssz::prelude::*,
+    state_transition::{Context, Result},
 };
 
 #[derive(
@@ -25,6 +37,44 @@ pub struct Validator {
     pub withdrawable_epoch: Epoch,
 }
 
+fn u64_chunk(value: u64) -> Node {
+    let mut chunk = [0u8; 32];
+    chunk[..8].copy_from_slice(&value.to_le_bytes());
+    Node::try_from(chunk.as_ref()).expect("is 32 bytes")
+}
+
+impl Validator {
+    // Compute the `hash_tree_root` of this validator directly from its eight field chunks,
+    // skipping the generic SSZ machinery. Each field takes its own chunk; only the 48-byte
+    // public key spans two chunks and is hashed down to one first.
+    pub fn fast_hash_tree_root(&self) -> Node {
+        let public_key = self.public_key.as_slice();
+        let mut public_key_tail = [0u8; 32];
+        public_key_tail[..16].copy_from_slice(&public_key[32..]);
+        let public_key_root = hash_nodes(
+            &Node::try_from(&public_key[..32]).expect("is 32 bytes"),
+            &Node::try_from(public_key_tail.as_ref()).expect("is 32 bytes"),
+        );
+        let chunks = [
+            public_key_root,
+            Node::try_from(self.withdrawal_credentials.as_slice()).expect("is 32 bytes"),
+            u64_chunk(self.effective_balance),
+            u64_chunk(self.slashed as u64),
+            u64_chunk(self.activation_eligibility_epoch),
+            u64_chunk(self.activation_epoch),
+            u64_chunk(self.exit_epoch),
+            u64_chunk(self.withdrawable_epoch),
+        ];
+        merkleize_chunks(&chunks)
+    }
+}
+
+impl CachedElement for Validator {
+    fn element_root(&self) -> Result<Node, MerkleizationError> {
+        Ok(self.fast_hash_tree_root())
+    }
+}
+
 #[derive(Default, Debug, SimpleSerialize, Clone, serde::Serialize, serde::Deserialize)]
 pub struct Eth1Block {
     pub timestamp: u64,
@@ -45,3 +95,473 @@ pub struct SignedAggregateAndProof<const MAX_VALIDATORS_PER_COMMITTEE: usize> {
     pub message: AggregateAndProof<MAX_VALIDATORS_PER_COMMITTEE>,
     pub signature: BlsSignature,
 }
+
+pub fn compute_time_at_slot<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const PENDING_ATTESTATIONS_BOUND: usize,
+>(
+    state: &BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        PENDING_ATTESTATIONS_BOUND,
+    >,
+    slot: Slot,
+    context: &Context,
+) -> u64 {
+    state.genesis_time + slot * context.seconds_per_slot
+}
+
+pub fn voting_period_start_time<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const PENDING_ATTESTATIONS_BOUND: usize,
+>(
+    state: &BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        PENDING_ATTESTATIONS_BOUND,
+    >,
+    context: &Context,
+) -> u64 {
+    let slots_per_voting_period = context.epochs_per_eth1_voting_period * context.slots_per_epoch;
+    let eth1_voting_period_start_slot = state.slot - state.slot % slots_per_voting_period;
+    compute_time_at_slot(state, eth1_voting_period_start_slot, context)
+}
+
+pub fn is_candidate_block(block: &Eth1Block, period_start: u64, context: &Context) -> bool {
+    let follow_time = context.seconds_per_eth1_block * context.eth1_follow_distance;
+    block.timestamp + follow_time <= period_start &&
+        block.timestamp + follow_time * 2 >= period_start
+}
+
+pub fn get_eth1_data(block: &Eth1Block) -> Result<Eth1Data> {
+    let block_root = block.hash_tree_root()?;
+    Ok(Eth1Data {
+        deposit_root: block.deposit_root,
+        deposit_count: block.deposit_count,
+        block_hash: Hash32::try_from(block_root.as_ref()).expect("root has 32 bytes"),
+    })
+}
+
+pub fn get_eth1_vote<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const PENDING_ATTESTATIONS_BOUND: usize,
+>(
+    state: &BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        PENDING_ATTESTATIONS_BOUND,
+    >,
+    eth1_chain: &[Eth1Block],
+    context: &Context,
+) -> Result<Eth1Data> {
+    let period_start = voting_period_start_time(state, context);
+    let mut votes_to_consider = vec![];
+    for block in eth1_chain {
+        if !is_candidate_block(block, period_start, context) {
+            continue
+        }
+        let eth1_data = get_eth1_data(block)?;
+        if eth1_data.deposit_count >= state.eth1_data.deposit_count {
+            votes_to_consider.push(eth1_data);
+        }
+    }
+
+    let valid_votes = state
+        .eth1_data_votes
+        .iter()
+        .filter(|vote| votes_to_consider.contains(vote))
+        .collect::<Vec<_>>();
+
+    // Select the most frequent valid vote, breaking ties in favor of the earliest one cast
+    let mut selected_vote: Option<(&Eth1Data, usize)> = None;
+    for &vote in &valid_votes {
+        let count = valid_votes.iter().filter(|&&other| other == vote).count();
+        if selected_vote.is_none_or(|(_, selected_count)| count > selected_count) {
+            selected_vote = Some((vote, count));
+        }
+    }
+
+    let vote = match selected_vote {
+        Some((vote, _)) => vote,
+        None => votes_to_consider.last().unwrap_or(&state.eth1_data),
+    };
+    Ok(vote.clone())
+}
+
+pub fn is_aggregator<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const PENDING_ATTESTATIONS_BOUND: usize,
+>(
+    state: &BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        PENDING_ATTESTATIONS_BOUND,
+    >,
+    slot: Slot,
+    index: CommitteeIndex,
+    slot_signature: &BlsSignature,
+    context: &Context,
+) -> Result<bool> {
+    let committee = get_beacon_committee(state, slot, index, context)?;
+    let modulo = usize::max(1, committee.len() / TARGET_AGGREGATORS_PER_COMMITTEE) as u64;
+    let digest: [u8; 8] = hash(slot_signature.as_ref()).as_ref()[..8].try_into().unwrap();
+    Ok(u64::from_le_bytes(digest) % modulo == 0)
+}
+
+// Checks a `SignedAggregateAndProof` in the order of the gossip validation of the
+// `beacon_aggregate_and_proof` topic, leaving the cheaper checks on the attestation data
+// itself to the caller.
+pub fn verify_aggregate_and_proof<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const PENDING_ATTESTATIONS_BOUND: usize,
+>(
+    state: &BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        PENDING_ATTESTATIONS_BOUND,
+    >,
+    signed_aggregate_and_proof: &SignedAggregateAndProof<MAX_VALIDATORS_PER_COMMITTEE>,
+    context: &Context,
+) -> Result<()> {
+    let aggregate_and_proof = &signed_aggregate_and_proof.message;
+    let aggregator_index = aggregate_and_proof.aggregator_index;
+    let aggregate = &aggregate_and_proof.aggregate;
+    let data = &aggregate.data;
+
+    let committee = get_beacon_committee(state, data.slot, data.index, context)?;
+    if !committee.contains(&aggregator_index) {
+        return Err(InvalidAggregateAndProof::AggregatorNotInCommittee(aggregator_index).into())
+    }
+
+    let selection_proof = &aggregate_and_proof.selection_proof;
+    if !is_aggregator(state, data.slot, data.index, selection_proof, context)? {
+        return Err(InvalidAggregateAndProof::NotAggregator(aggregator_index).into())
+    }
+
+    let public_key = &state.validators[aggregator_index].public_key;
+    let epoch = compute_epoch_at_slot(data.slot, context);
+    let domain = get_domain(state, DomainType::SelectionProof, Some(epoch), context)?;
+    verify_signed_data(&data.slot, selection_proof, public_key, domain)
+        .map_err(|_| InvalidAggregateAndProof::InvalidSelectionProof(aggregator_index))?;
+
+    let domain = get_domain(state, DomainType::AggregateAndProof, Some(epoch), context)?;
+    verify_signed_data(
+        aggregate_and_proof,
+        &signed_aggregate_and_proof.signature,
+        public_key,
+        domain,
+    )
+    .map_err(|_| InvalidAggregateAndProof::InvalidSignature(aggregator_index))?;
+
+    if aggregate.aggregation_bits.len() != committee.len() {
+        return Err(InvalidAggregateAndProof::AggregationBitsLengthMismatch {
+            expected: committee.len(),
+            provided: aggregate.aggregation_bits.len(),
+        }
+        .into())
+    }
+    let mut public_keys = vec![];
+    for (i, &index) in committee.iter().enumerate() {
+        if aggregate.aggregation_bits[i] {
+            public_keys.push(&state.validators[index].public_key);
+        }
+    }
+    let domain = get_domain(state, DomainType::BeaconAttester, Some(data.target.epoch), context)?;
+    let signing_root = compute_signing_root(data, domain)?;
+    fast_aggregate_verify(&public_keys, signing_root.as_ref(), &aggregate.signature)
+        .map_err(|_| InvalidAggregateAndProof::InvalidAggregateSignature)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        phase0::{
+            block_processing::process_eth1_data,
+            minimal::{self, BeaconBlockBody, BeaconState},
+        },
+        test_utils::push_active_validators,
+        Error,
+    };
+
+    fn eth1_block(timestamp: u64, deposit_count: u64) -> Eth1Block {
+        Eth1Block { timestamp, deposit_count, ..Default::default() }
+    }
+
+    #[test]
+    fn test_get_eth1_vote() {
+        let context = Context::for_minimal();
+        let mut state = BeaconState {
+            genesis_time: 1_000_000,
+            slot: context.epochs_per_eth1_voting_period * context.slots_per_epoch + 1,
+            ..Default::default()
+        };
+        let period_start = voting_period_start_time(&state, &context);
+        let follow_time = context.seconds_per_eth1_block * context.eth1_follow_distance;
+
+        let too_old = eth1_block(period_start - 2 * follow_time - 1, 1);
+        let older = eth1_block(period_start - 2 * follow_time, 1);
+        let newer = eth1_block(period_start - follow_time, 2);
+        let too_new = eth1_block(period_start - follow_time + 1, 3);
+        let eth1_chain = [too_old.clone(), older.clone(), newer.clone(), too_new.clone()];
+
+        // without votes, the latest candidate block is the default
+        let vote = get_eth1_vote(&state, &eth1_chain, &context).unwrap();
+        assert_eq!(vote, get_eth1_data(&newer).unwrap());
+
+        // the most frequent valid vote wins, ignoring votes for non-candidate blocks
+        for block in [&newer, &older, &older, &too_new, &too_new, &too_new] {
+            state.eth1_data_votes.push(get_eth1_data(block).unwrap());
+        }
+        let vote = get_eth1_vote(&state, &eth1_chain, &context).unwrap();
+        assert_eq!(vote, get_eth1_data(&older).unwrap());
+
+        // blocks with fewer deposits than the current eth1 data are not considered
+        state.eth1_data.deposit_count = 2;
+        let vote = get_eth1_vote(&state, &eth1_chain, &context).unwrap();
+        assert_eq!(vote, get_eth1_data(&newer).unwrap());
+    }
+
+    #[test]
+    fn test_eth1_data_majority_vote() {
+        let context = Context::for_minimal();
+        let mut state = BeaconState::default();
+        let body = BeaconBlockBody {
+            eth1_data: get_eth1_data(&eth1_block(1, 1)).unwrap(),
+            ..Default::default()
+        };
+        let slots_per_voting_period =
+            context.epochs_per_eth1_voting_period * context.slots_per_epoch;
+
+        for _ in 0..slots_per_voting_period / 2 {
+            process_eth1_data(&mut state, &body, &context);
+            assert_eq!(state.eth1_data, Eth1Data::default());
+        }
+        process_eth1_data(&mut state, &body, &context);
+        assert_eq!(state.eth1_data, body.eth1_data);
+    }
+
+    #[test]
+    fn test_validator_fast_hash_tree_root() {
+        let public_key = hex::decode("8af535a44bccf447875fa5372a56a807fbc2c3c0be5579a0a3b0e6e0559195507e1874304659d7d86058717ca84c01f5").unwrap();
+        let mut withdrawal_credentials = [0xab; 32];
+        withdrawal_credentials[0] = 0x01;
+        withdrawal_credentials[1..12].fill(0);
+        let validator = Validator {
+            public_key: BlsPublicKey::try_from(public_key.as_ref()).unwrap(),
+            withdrawal_credentials: Bytes32::try_from(withdrawal_credentials.as_ref()).unwrap(),
+            effective_balance: 32_000_000_000,
+            slashed: true,
+            activation_eligibility_epoch: 5,
+            activation_epoch: 6,
+            exit_epoch: u64::MAX,
+            withdrawable_epoch: u64::MAX,
+        };
+        // computed independently following the SSZ merkleization rules
+        let expected = Node::try_from(
+            hex::decode("5f0d204d8e19dec0b5502f3588b33e921dd3fa1c6af57d7c11c5c91daae46a71")
+                .unwrap()
+                .as_ref(),
+        )
+        .unwrap();
+        assert_eq!(validator.fast_hash_tree_root(), expected);
+        assert_eq!(validator.hash_tree_root().unwrap(), expected);
+        assert_eq!(
+            Validator::default().fast_hash_tree_root(),
+            Validator::default().hash_tree_root().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_verify_aggregate_and_proof_rejects_unselected_aggregator() {
+        let context = Context::for_minimal();
+        let mut state = BeaconState::default();
+        // committees of 32 validators, so not every selection proof selects an aggregator
+        push_active_validators(&mut state.validators, &mut state.balances, 1024, &context);
+        let committee = get_beacon_committee(&state, 0, 0, &context).unwrap();
+        assert_eq!(committee.len(), 2 * TARGET_AGGREGATORS_PER_COMMITTEE);
+
+        let selection_proof = (0..=u8::MAX)
+            .map(|byte| BlsSignature::try_from([byte; 96].as_ref()).unwrap())
+            .find(|proof| !is_aggregator(&state, 0, 0, proof, &context).unwrap())
+            .unwrap();
+        let signed_aggregate_and_proof = SignedAggregateAndProof {
+            message: AggregateAndProof {
+                aggregator_index: committee[0],
+                selection_proof,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let err =
+            verify_aggregate_and_proof(&state, &signed_aggregate_and_proof, &context).unwrap_err();
+        assert!(matches!(
+            err,
+            Error::InvalidAggregateAndProof(InvalidAggregateAndProof::NotAggregator(index))
+                if index == committee[0]
+        ));
+    }
+
+    #[cfg(feature = "test-utils")]
+    #[test]
+    fn test_verify_aggregate_and_proof() {
+        use crate::{
+            crypto::{aggregate, test_keys},
+            phase0::{minimal, AttestationData},
+            signing::sign_with_domain,
+            state_transition::minimal::GenesisBuilder,
+            types, Fork,
+        };
+
+        let context = Context::for_minimal();
+        let validator_count = context.min_genesis_active_validator_count;
+        let keys = test_keys(validator_count);
+        let builder = GenesisBuilder::new(validator_count, context).with_fork(Fork::Phase0);
+        let types::BeaconState::Phase0(state) = builder.build().unwrap() else {
+            panic!("expected a phase0 state")
+        };
+        let context = builder.context;
+
+        // committees are small enough that every member is an aggregator
+        let committee = get_beacon_committee(&state, 0, 0, &context).unwrap();
+        let outsider = (0..validator_count).find(|index| !committee.contains(index)).unwrap();
+        let aggregator_index = committee[0];
+        let domain = |domain_type| get_domain(&state, domain_type, Some(0), &context).unwrap();
+
+        let data = AttestationData::default();
+        let signatures = committee
+            .iter()
+            .map(|&index| sign_with_domain(&data, &keys[index], domain(DomainType::BeaconAttester)))
+            .collect::<Result<Vec<_>>>()
+            .unwrap();
+        let aggregate = minimal::Attestation {
+            aggregation_bits: Bitlist::try_from(vec![true; committee.len()].as_slice()).unwrap(),
+            data,
+            signature: aggregate(&signatures).unwrap(),
+        };
+        let sign = |message: minimal::AggregateAndProof, key: usize| {
+            let signature =
+                sign_with_domain(&message, &keys[key], domain(DomainType::AggregateAndProof))
+                    .unwrap();
+            minimal::SignedAggregateAndProof { message, signature }
+        };
+        let message = minimal::AggregateAndProof {
+            aggregator_index,
+            aggregate,
+            selection_proof: sign_with_domain(
+                &0u64,
+                &keys[aggregator_index],
+                domain(DomainType::SelectionProof),
+            )
+            .unwrap(),
+        };
+        verify_aggregate_and_proof(&state, &sign(message.clone(), aggregator_index), &context)
+            .unwrap();
+
+        let expect_error = |signed: minimal::SignedAggregateAndProof| {
+            let err = verify_aggregate_and_proof(&state, &signed, &context).unwrap_err();
+            let Error::InvalidAggregateAndProof(err) = err else {
+                panic!("unexpected error {err:?}")
+            };
+            err
+        };
+
+        let mut invalid = message.clone();
+        invalid.aggregator_index = outsider;
+        assert!(matches!(
+            expect_error(sign(invalid, outsider)),
+            InvalidAggregateAndProof::AggregatorNotInCommittee(index) if index == outsider
+        ));
+
+        let mut invalid = message.clone();
+        invalid.selection_proof =
+            sign_with_domain(&1u64, &keys[aggregator_index], domain(DomainType::SelectionProof))
+                .unwrap();
+        assert!(matches!(
+            expect_error(sign(invalid, aggregator_index)),
+            InvalidAggregateAndProof::InvalidSelectionProof(..)
+        ));
+
+        assert!(matches!(
+            expect_error(sign(message.clone(), outsider)),
+            InvalidAggregateAndProof::InvalidSignature(..)
+        ));
+
+        let mut invalid = message.clone();
+        invalid.aggregate.aggregation_bits =
+            Bitlist::try_from(vec![true; committee.len() - 1].as_slice()).unwrap();
+        assert!(matches!(
+            expect_error(sign(invalid, aggregator_index)),
+            InvalidAggregateAndProof::AggregationBitsLengthMismatch { expected, provided }
+                if expected == committee.len() && provided == committee.len() - 1
+        ));
+
+        let mut invalid = message;
+        invalid.aggregate.signature = sign_with_domain(
+            &AttestationData { slot: 1, ..Default::default() },
+            &keys[aggregator_index],
+            domain(DomainType::BeaconAttester),
+        )
+        .unwrap();
+        assert!(matches!(
+            expect_error(sign(invalid, aggregator_index)),
+            InvalidAggregateAndProof::InvalidAggregateSignature
+        ));
+    }
+}
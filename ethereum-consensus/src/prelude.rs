@@ -0,0 +1,50 @@
+//! Helpers that do not depend on a particular fork's `BeaconState`, gathered for a single import.
+//! Anything keyed off fork-specific types should still be imported from the relevant fork module.
+pub use crate::{
+    domains::DomainType,
+    phase0::helpers::{
+        compute_activation_exit_epoch, compute_committee, compute_domain, compute_epoch_at_slot,
+        compute_fork_data_root, compute_fork_digest, compute_shuffled_index,
+        compute_shuffled_indices, compute_start_slot_at_epoch, is_active_validator,
+        is_slashable_attestation_data, is_slashable_validator,
+    },
+    primitives::{
+        Domain, Epoch, ForkDigest, Root, Slot, ValidatorIndex, Version, FAR_FUTURE_EPOCH,
+        GENESIS_EPOCH, GENESIS_SLOT,
+    },
+    signing::{compute_signing_root, sign_with_domain, verify_signed_data},
+    state_transition::Context,
+    Error, Fork,
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::phase0::Validator;
+
+    #[test]
+    fn test_prelude_helpers() {
+        let context = Context::for_minimal();
+        let epoch = compute_epoch_at_slot(17, &context);
+        assert_eq!(epoch, 2);
+        assert_eq!(compute_start_slot_at_epoch(epoch, &context), 16);
+        assert_eq!(
+            compute_activation_exit_epoch(GENESIS_EPOCH, &context),
+            1 + context.max_seed_lookahead
+        );
+
+        let validator = Validator { exit_epoch: FAR_FUTURE_EPOCH, ..Default::default() };
+        assert!(is_active_validator(&validator, epoch));
+
+        let domain =
+            compute_domain(DomainType::BeaconProposer, None, Some(Root::default()), &context)
+                .unwrap();
+        let signing_root = compute_signing_root(&validator, domain).unwrap();
+        assert_ne!(signing_root, Root::default());
+
+        let indices = (0..8).collect::<Vec<ValidatorIndex>>();
+        let seed = Default::default();
+        let committee = compute_committee(&indices, &seed, 0, 2, &context).unwrap();
+        assert_eq!(committee.len(), 4);
+    }
+}
@@ -1,9 +1,10 @@
-use crate::ssz::prelude::*;
 pub use crate::{
     crypto::{PublicKey as BlsPublicKey, Signature as BlsSignature},
     domains::DomainType,
     ssz::prelude::U256,
 };
+use crate::{serde::write_bytes_to_lower_hex, ssz::prelude::*};
+use std::{convert::Infallible, fmt, str::FromStr};
 
 pub type Root = Node;
 pub type Slot = u64;
@@ -30,6 +31,61 @@ pub type Bytes32 = ByteVector<32>;
 
 pub type ParticipationFlags = u8;
 
+pub const GRAFFITI_BYTES_LEN: usize = 32;
+
+// `Graffiti` is the free-form data a proposer may set in a block body
+#[derive(Default, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub struct Graffiti(#[serde(with = "crate::serde::as_hex")] pub [u8; GRAFFITI_BYTES_LEN]);
+
+impl Graffiti {
+    // Decode the graffiti as UTF-8, ignoring any trailing zero padding.
+    pub fn to_utf8_lossy(&self) -> String {
+        let end = self.0.iter().rposition(|&b| b != 0).map(|i| i + 1).unwrap_or(0);
+        String::from_utf8_lossy(&self.0[..end]).into_owned()
+    }
+}
+
+impl fmt::Debug for Graffiti {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_bytes_to_lower_hex(f, self.0)
+    }
+}
+
+impl fmt::Display for Graffiti {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_utf8_lossy())
+    }
+}
+
+// Zero-pads short inputs and truncates long inputs to the last `char` boundary that fits.
+impl FromStr for Graffiti {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut end = s.len().min(GRAFFITI_BYTES_LEN);
+        while !s.is_char_boundary(end) {
+            end -= 1;
+        }
+        let mut inner = [0u8; GRAFFITI_BYTES_LEN];
+        inner[..end].copy_from_slice(&s.as_bytes()[..end]);
+        Ok(Self(inner))
+    }
+}
+
+impl From<&Bytes32> for Graffiti {
+    fn from(bytes: &Bytes32) -> Self {
+        let mut inner = [0u8; GRAFFITI_BYTES_LEN];
+        inner.copy_from_slice(bytes.as_ref());
+        Self(inner)
+    }
+}
+
+impl From<Graffiti> for Bytes32 {
+    fn from(graffiti: Graffiti) -> Self {
+        Bytes32::try_from(graffiti.0.as_ref()).expect("input has correct length")
+    }
+}
+
 pub type ShuffledIndices = Vec<usize>;
 
 // Coordinate refers to a unique location in the block tree
@@ -61,4 +117,21 @@ mod tests {
         let bytes_roundtrip: Bytes32 = serde_json::from_str(&json).unwrap();
         assert_eq!(bytes, bytes_roundtrip);
     }
+
+    #[test]
+    fn test_graffiti() {
+        let graffiti: Graffiti = "hello".parse().unwrap();
+        assert_eq!(&graffiti.0[..5], b"hello");
+        assert!(graffiti.0[5..].iter().all(|&b| b == 0));
+        assert_eq!(graffiti.to_utf8_lossy(), "hello");
+
+        let bytes = Bytes32::from(graffiti);
+        assert_eq!(Graffiti::from(&bytes), graffiti);
+
+        // 31 ASCII bytes followed by a 2-byte character that does not fit
+        let long = format!("{}é and more", "a".repeat(31));
+        let graffiti: Graffiti = long.parse().unwrap();
+        assert_eq!(graffiti.to_utf8_lossy(), "a".repeat(31));
+        assert_eq!(graffiti.0[31], 0);
+    }
 }
@@ -148,6 +148,49 @@ pub mod seq_of_str {
     }
 }
 
+pub mod lenient_bool {
+    use serde::de::{Deserializer, Error, Unexpected};
+    use std::fmt;
+
+    pub fn serialize<S>(data: &bool, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_bool(*data)
+    }
+
+    struct Visitor;
+
+    impl<'de> serde::de::Visitor<'de> for Visitor {
+        type Value = bool;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("boolean or quoted boolean")
+        }
+
+        fn visit_bool<E: Error>(self, v: bool) -> Result<Self::Value, E> {
+            Ok(v)
+        }
+
+        fn visit_str<E: Error>(self, v: &str) -> Result<Self::Value, E> {
+            match v {
+                "true" => Ok(true),
+                "false" => Ok(false),
+                _ => Err(E::invalid_value(Unexpected::Str(v), &self)),
+            }
+        }
+    }
+
+    // Some nodes emit booleans (e.g. `execution_optimistic`) as the strings "true"/"false",
+    // so accept either representation.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<bool, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(Visitor)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -235,4 +278,21 @@ mod tests {
         let s = format!("{data}");
         assert_eq!(s, "0x000102");
     }
+
+    #[derive(serde::Deserialize)]
+    struct Meta {
+        #[serde(with = "lenient_bool")]
+        execution_optimistic: bool,
+    }
+
+    #[test]
+    fn test_lenient_bool() {
+        for input in [r#"{"execution_optimistic":true}"#, r#"{"execution_optimistic":"true"}"#] {
+            let meta: Meta = serde_json::from_str(input).unwrap();
+            assert!(meta.execution_optimistic);
+        }
+        let meta: Meta = serde_json::from_str(r#"{"execution_optimistic":"false"}"#).unwrap();
+        assert!(!meta.execution_optimistic);
+        assert!(serde_json::from_str::<Meta>(r#"{"execution_optimistic":"yes"}"#).is_err());
+    }
 }
@@ -0,0 +1,118 @@
+use ssz_rs::prelude::*;
+
+/// Set operations over the bits of a `Bitlist` or `Bitvector`, e.g. the aggregation bits of
+/// attestations or the participation bits of sync aggregates.
+///
+/// The binary operations are only defined for operands of the same length and return `None`
+/// otherwise, so a result always has the length of its operands. For a `Bitlist`, this means
+/// the length committed to by its delimiting bit is unchanged.
+pub trait Bitfield: Clone {
+    /// The number of bits, set or not.
+    fn bit_count(&self) -> usize;
+
+    fn bit(&self, index: usize) -> bool;
+
+    fn set_bit(&mut self, index: usize, value: bool);
+
+    /// Iterate over the indices of the set bits in increasing order.
+    fn iter_ones(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..self.bit_count()).filter(|&i| self.bit(i))
+    }
+
+    fn count_ones(&self) -> usize {
+        self.iter_ones().count()
+    }
+
+    fn union(&self, other: &Self) -> Option<Self> {
+        self.zip_with(other, |a, b| a || b)
+    }
+
+    fn intersection(&self, other: &Self) -> Option<Self> {
+        self.zip_with(other, |a, b| a && b)
+    }
+
+    /// The bits set in `self` but not in `other`.
+    fn difference(&self, other: &Self) -> Option<Self> {
+        self.zip_with(other, |a, b| a && !b)
+    }
+
+    fn zip_with(&self, other: &Self, f: impl Fn(bool, bool) -> bool) -> Option<Self> {
+        if self.bit_count() != other.bit_count() {
+            return None
+        }
+        let mut result = self.clone();
+        for i in 0..self.bit_count() {
+            result.set_bit(i, f(self.bit(i), other.bit(i)));
+        }
+        Some(result)
+    }
+}
+
+impl<const N: usize> Bitfield for Bitlist<N> {
+    fn bit_count(&self) -> usize {
+        self.len()
+    }
+
+    fn bit(&self, index: usize) -> bool {
+        self[index]
+    }
+
+    fn set_bit(&mut self, index: usize, value: bool) {
+        self.set(index, value);
+    }
+}
+
+impl<const N: usize> Bitfield for Bitvector<N> {
+    fn bit_count(&self) -> usize {
+        self.len()
+    }
+
+    fn bit(&self, index: usize) -> bool {
+        self[index]
+    }
+
+    fn set_bit(&mut self, index: usize, value: bool) {
+        self.set(index, value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bitlist(bits: &[bool]) -> Bitlist<8> {
+        Bitlist::try_from(bits).unwrap()
+    }
+
+    #[test]
+    fn test_bitlist_set_operations() {
+        let a = bitlist(&[true, false, false, true, false]);
+        let b = bitlist(&[false, true, false, true, false]);
+        assert_eq!(a.count_ones(), 2);
+        assert_eq!(a.iter_ones().collect::<Vec<_>>(), vec![0, 3]);
+
+        let union = a.union(&b).unwrap();
+        assert_eq!(union, bitlist(&[true, true, false, true, false]));
+        assert_eq!(union.count_ones(), 3);
+        // the trailing unset bit still counts towards the length of the list
+        assert_eq!(
+            serialize(&union).unwrap(),
+            serialize(&bitlist(&[true, true, false, true, false])).unwrap()
+        );
+        assert_eq!(a.intersection(&b).unwrap(), bitlist(&[false, false, false, true, false]));
+        assert_eq!(a.difference(&b).unwrap(), bitlist(&[true, false, false, false, false]));
+
+        assert!(a.union(&bitlist(&[true, false])).is_none());
+    }
+
+    #[test]
+    fn test_bitvector_set_operations() {
+        let mut a = Bitvector::<4>::default();
+        a.set(1, true);
+        let mut b = Bitvector::<4>::default();
+        b.set(2, true);
+        let union = a.union(&b).unwrap();
+        assert_eq!(union.iter_ones().collect::<Vec<_>>(), vec![1, 2]);
+        assert_eq!(a.intersection(&b).unwrap().count_ones(), 0);
+    }
+}
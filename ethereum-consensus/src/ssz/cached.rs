@@ -0,0 +1,108 @@
+use ssz_rs::prelude::*;
+use std::{
+    ops::{Deref, DerefMut},
+    sync::OnceLock,
+};
+
+/// Wraps a value and memoizes its `hash_tree_root`.
+/// Any mutable access to the inner value (via `DerefMut` or `AsMut`) invalidates the cached root.
+#[derive(Debug, Clone, Default)]
+pub struct Cached<T> {
+    inner: T,
+    root: OnceLock<Node>,
+}
+
+impl<T> Cached<T> {
+    pub fn new(inner: T) -> Self {
+        Self { inner, root: OnceLock::new() }
+    }
+
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+
+    pub fn is_cached(&self) -> bool {
+        self.root.get().is_some()
+    }
+
+    pub fn invalidate(&mut self) {
+        self.root.take();
+    }
+}
+
+impl<T: HashTreeRoot> Cached<T> {
+    pub fn hash_tree_root(&self) -> Result<Node, MerkleizationError> {
+        if let Some(root) = self.root.get() {
+            return Ok(*root)
+        }
+        let root = self.inner.hash_tree_root()?;
+        Ok(*self.root.get_or_init(|| root))
+    }
+}
+
+impl<T> From<T> for Cached<T> {
+    fn from(inner: T) -> Self {
+        Self::new(inner)
+    }
+}
+
+impl<T> Deref for Cached<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<T> DerefMut for Cached<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.invalidate();
+        &mut self.inner
+    }
+}
+
+impl<T> AsRef<T> for Cached<T> {
+    fn as_ref(&self) -> &T {
+        &self.inner
+    }
+}
+
+impl<T> AsMut<T> for Cached<T> {
+    fn as_mut(&mut self) -> &mut T {
+        self.invalidate();
+        &mut self.inner
+    }
+}
+
+impl<T: PartialEq> PartialEq for Cached<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.inner == other.inner
+    }
+}
+
+impl<T: Eq> Eq for Cached<T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::phase0::BeaconBlockHeader;
+
+    #[test]
+    fn test_cached_root_invalidates_on_mutation() {
+        let mut header = Cached::new(BeaconBlockHeader::default());
+        assert!(!header.is_cached());
+
+        let root = header.hash_tree_root().unwrap();
+        assert!(header.is_cached());
+        assert_eq!(header.hash_tree_root().unwrap(), root);
+        // reads through `Deref` leave the cache intact
+        assert_eq!(header.slot, 0);
+        assert!(header.is_cached());
+
+        header.slot = 1;
+        assert!(!header.is_cached());
+        let updated_root = header.hash_tree_root().unwrap();
+        assert_ne!(updated_root, root);
+        assert_eq!(updated_root, header.as_ref().hash_tree_root().unwrap());
+    }
+}
@@ -0,0 +1,105 @@
+use super::merkleization::{hash_nodes, zero_hashes};
+use ssz_rs::prelude::*;
+
+/// Computes the `hash_tree_root` of a `List` of composite elements, keeping the
+/// Merkle tree from the previous call so that only elements which changed (or were
+/// appended) since then are rehashed, along with their paths to the root.
+///
+/// Changes are found by comparing against a copy of every element from the last call, so the
+/// cache holds a clone of the list plus about two `Node`s per element. For the mainnet
+/// validator registry this comes to a few hundred bytes per validator.
+#[derive(Debug, Clone, Default)]
+pub struct ListRootCache<T> {
+    elements: Vec<T>,
+    // `layers[0]` holds the roots of `elements`; each later layer holds the parents of the
+    // one before it, without any zero-padding on the right.
+    layers: Vec<Vec<Node>>,
+}
+
+/// An element of a list whose root is kept by a `ListRootCache`.
+pub trait CachedElement: PartialEq + Clone {
+    /// Must equal the element's `hash_tree_root`, e.g. a faster computation of it.
+    fn element_root(&self) -> Result<Node, MerkleizationError>;
+}
+
+impl<T: CachedElement> ListRootCache<T> {
+    pub fn new() -> Self {
+        Self { elements: vec![], layers: vec![] }
+    }
+
+    pub fn hash_tree_root<const N: usize>(
+        &mut self,
+        list: &List<T, N>,
+    ) -> Result<Node, MerkleizationError> {
+        let depth = N.next_power_of_two().trailing_zeros() as usize;
+        if list.len() < self.elements.len() || self.layers.len() != depth + 1 {
+            self.elements.clear();
+            self.layers = vec![vec![]; depth + 1];
+        }
+
+        let mut dirty = vec![];
+        for (i, element) in list.iter().enumerate() {
+            match self.elements.get(i) {
+                Some(cached) if cached == element => continue,
+                Some(_) => {
+                    self.elements[i] = element.clone();
+                    self.layers[0][i] = element.element_root()?;
+                }
+                None => {
+                    self.elements.push(element.clone());
+                    self.layers[0].push(element.element_root()?);
+                }
+            }
+            dirty.push(i);
+        }
+
+        let zero_hashes = zero_hashes(depth);
+        for d in 1..=depth {
+            let width = self.layers[d - 1].len().div_ceil(2);
+            self.layers[d].resize(width, Node::default());
+            dirty.dedup_by_key(|i| *i / 2);
+            for i in dirty.iter_mut() {
+                *i /= 2;
+                let children = &self.layers[d - 1];
+                let right = children.get(2 * *i + 1).unwrap_or(&zero_hashes[d - 1]);
+                self.layers[d][*i] = hash_nodes(&children[2 * *i], right);
+            }
+        }
+
+        let root = self.layers[depth].first().copied().unwrap_or(zero_hashes[depth]);
+        let mut length = [0u8; 32];
+        length[..8].copy_from_slice(&(list.len() as u64).to_le_bytes());
+        Ok(hash_nodes(&root, &Node::try_from(length.as_ref()).expect("is 32 bytes")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::phase0::Checkpoint;
+
+    impl CachedElement for Checkpoint {
+        fn element_root(&self) -> Result<Node, MerkleizationError> {
+            self.hash_tree_root()
+        }
+    }
+
+    #[test]
+    fn test_list_root_cache() {
+        let mut cache = ListRootCache::new();
+        let mut list = List::<Checkpoint, 16>::default();
+        assert_eq!(cache.hash_tree_root(&list).unwrap(), list.hash_tree_root().unwrap());
+
+        for epoch in 0..11 {
+            list.push(Checkpoint { epoch, ..Default::default() });
+            assert_eq!(cache.hash_tree_root(&list).unwrap(), list.hash_tree_root().unwrap());
+        }
+
+        list[3].epoch = 100;
+        list[10].epoch = 200;
+        assert_eq!(cache.hash_tree_root(&list).unwrap(), list.hash_tree_root().unwrap());
+
+        let shorter = List::<Checkpoint, 16>::try_from(list[..5].to_vec()).unwrap();
+        assert_eq!(cache.hash_tree_root(&shorter).unwrap(), shorter.hash_tree_root().unwrap());
+    }
+}
@@ -0,0 +1,110 @@
+use crate::crypto::hash;
+use ssz_rs::prelude::*;
+
+/// A function hashing two concatenated sibling nodes into their parent.
+pub type MerkleHasher = fn(&[u8; 64]) -> [u8; 32];
+
+#[cfg(feature = "test-hasher")]
+thread_local! {
+    static MERKLE_HASHER: std::cell::Cell<Option<MerkleHasher>> = const { std::cell::Cell::new(None) };
+}
+
+/// Run `f` with `hasher` used in place of sha256 by this crate's merkleization routines
+/// (e.g. `merkleize_chunks` and `ListRootCache`) on the current thread.
+/// Useful to check the shape of a tree independently of the hash function.
+///
+/// Roots computed by `ssz_rs`, i.e. through `HashTreeRoot::hash_tree_root`, always use sha256
+/// and are out of scope; build the root of a container from its field roots with
+/// `merkleize_chunks` to see the override.
+#[cfg(feature = "test-hasher")]
+pub fn with_merkle_hasher<R>(hasher: MerkleHasher, f: impl FnOnce() -> R) -> R {
+    struct Restore(Option<MerkleHasher>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            MERKLE_HASHER.with(|cell| cell.set(self.0));
+        }
+    }
+
+    let _restore = Restore(MERKLE_HASHER.with(|cell| cell.replace(Some(hasher))));
+    f()
+}
+
+/// Return the parent of the `left` and `right` nodes in a Merkle tree.
+pub fn hash_nodes(left: &Node, right: &Node) -> Node {
+    let mut input = [0u8; 64];
+    input[..32].copy_from_slice(left.as_ref());
+    input[32..].copy_from_slice(right.as_ref());
+    #[cfg(feature = "test-hasher")]
+    if let Some(hasher) = MERKLE_HASHER.with(|cell| cell.get()) {
+        return Node::try_from(hasher(&input).as_ref()).expect("hash is 32 bytes")
+    }
+    Node::try_from(hash(input).as_ref()).expect("hash is 32 bytes")
+}
+
+pub(crate) fn zero_hashes(depth: usize) -> Vec<Node> {
+    let mut zero_hashes = vec![Node::default()];
+    for _ in 0..depth {
+        let zero_hash = *zero_hashes.last().expect("not empty");
+        zero_hashes.push(hash_nodes(&zero_hash, &zero_hash));
+    }
+    zero_hashes
+}
+
+/// Merkleize `chunks` as the fields of a container, i.e. padded with zero
+/// chunks to the next power of two.
+pub fn merkleize_chunks(chunks: &[Node]) -> Node {
+    let width = chunks.len().max(1).next_power_of_two();
+    let mut layer = chunks.to_vec();
+    layer.resize(width, Node::default());
+    while layer.len() > 1 {
+        layer = layer.chunks(2).map(|pair| hash_nodes(&pair[0], &pair[1])).collect();
+    }
+    layer[0]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::phase0::Checkpoint;
+
+    #[test]
+    fn test_merkleize_chunks() {
+        let checkpoint = Checkpoint { epoch: 7, root: Node::try_from([3u8; 32].as_ref()).unwrap() };
+        let chunks = [checkpoint.epoch.hash_tree_root().unwrap(), checkpoint.root];
+        assert_eq!(merkleize_chunks(&chunks), checkpoint.hash_tree_root().unwrap());
+    }
+
+    #[cfg(feature = "test-hasher")]
+    #[test]
+    fn test_merkle_hasher_tree_shape() {
+        use crate::phase0::Fork;
+
+        // combine only the first byte of each child so the root spells out the tree's shape
+        fn shape_hasher(input: &[u8; 64]) -> [u8; 32] {
+            let mut output = [0u8; 32];
+            output[0] = input[0].wrapping_mul(16).wrapping_add(input[32]);
+            output
+        }
+        let parent = |left: u8, right: u8| left.wrapping_mul(16).wrapping_add(right);
+
+        let fork = Fork { previous_version: [1; 4], current_version: [2; 4], epoch: 3 };
+        let field_roots = [
+            fork.previous_version.hash_tree_root().unwrap(),
+            fork.current_version.hash_tree_root().unwrap(),
+            fork.epoch.hash_tree_root().unwrap(),
+        ];
+        assert_eq!(merkleize_chunks(&field_roots), fork.hash_tree_root().unwrap());
+
+        // the three fields are padded with a zero chunk to a tree of width four
+        let root = with_merkle_hasher(shape_hasher, || merkleize_chunks(&field_roots));
+        assert_eq!(root.as_ref()[0], parent(parent(1, 2), parent(3, 0)));
+        assert!(root.as_ref()[1..].iter().all(|byte| *byte == 0));
+        // `ssz_rs` keeps using sha256 while the hasher is overridden
+        let ssz_rs_root = with_merkle_hasher(shape_hasher, || fork.hash_tree_root().unwrap());
+        assert_eq!(ssz_rs_root, fork.hash_tree_root().unwrap());
+
+        // the default hasher is restored afterwards
+        assert_eq!(merkleize_chunks(&field_roots), fork.hash_tree_root().unwrap());
+    }
+}
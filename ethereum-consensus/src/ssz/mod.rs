@@ -1,7 +1,20 @@
+mod bitfield;
 mod byte_list;
 mod byte_vector;
+mod cached;
+mod list_root_cache;
+mod merkleization;
 
 pub mod prelude {
-    pub use super::{byte_list::ByteList, byte_vector::ByteVector};
+    #[cfg(feature = "test-hasher")]
+    pub use super::merkleization::with_merkle_hasher;
+    pub use super::{
+        bitfield::Bitfield,
+        byte_list::ByteList,
+        byte_vector::ByteVector,
+        cached::Cached,
+        list_root_cache::{CachedElement, ListRootCache},
+        merkleization::{hash_nodes, merkleize_chunks, MerkleHasher},
+    };
     pub use ssz_rs::prelude::*;
 }
@@ -92,6 +92,7 @@ pub struct Context {
     pub max_consolidations: usize,
     pub max_deposit_receipts_per_payload: usize,
     pub max_withdrawal_requests_per_payload: usize,
+    pub max_consolidation_requests_per_payload: usize,
     pub max_pending_partials_per_withdrawals_sweep: usize,
 
     // config
@@ -283,6 +284,8 @@ impl Context {
             max_consolidations: electra_preset.max_consolidations,
             max_deposit_receipts_per_payload: electra_preset.max_deposit_receipts_per_payload,
             max_withdrawal_requests_per_payload: electra_preset.max_withdrawal_requests_per_payload,
+            max_consolidation_requests_per_payload: electra_preset
+                .max_consolidation_requests_per_payload,
             max_pending_partials_per_withdrawals_sweep: electra_preset
                 .max_pending_partials_per_withdrawals_sweep,
             // config
@@ -0,0 +1,395 @@
+use crate::{
+    altair, bellatrix, capella,
+    crypto::{hash, test_keys},
+    deneb,
+    phase0::{
+        self, compute_domain, Deposit, DepositData, DepositMessage, DEPOSIT_CONTRACT_TREE_DEPTH,
+    },
+    primitives::{DomainType, Gwei, Hash32, BLS_WITHDRAWAL_PREFIX},
+    signing::sign_with_domain,
+    ssz::prelude::*,
+    state_transition::{Context, Result},
+    types::{BeaconState, SignedBeaconBlock},
+    Error, Fork,
+};
+use std::marker::PhantomData;
+
+// Return deposits for `deposit_data` where the proof of each deposit is against the deposit root
+// formed by all *prior* deposits and the deposit itself, as required by
+// `initialize_beacon_state_from_eth1`.
+pub fn compute_genesis_deposits(deposit_data: &[DepositData]) -> Result<Vec<Deposit>> {
+    let mut zero_hashes = vec![Node::default()];
+    for _ in 0..DEPOSIT_CONTRACT_TREE_DEPTH {
+        let zero_hash = *zero_hashes.last().expect("not empty");
+        zero_hashes.push(hash_nodes(&zero_hash, &zero_hash));
+    }
+
+    let mut layers = vec![deposit_data
+        .iter()
+        .map(|data| data.hash_tree_root())
+        .collect::<std::result::Result<Vec<_>, _>>()?];
+    for zero_hash in zero_hashes.iter().take(DEPOSIT_CONTRACT_TREE_DEPTH) {
+        let layer = layers.last().expect("not empty");
+        let next_layer = layer
+            .chunks(2)
+            .map(|pair| hash_nodes(&pair[0], pair.get(1).unwrap_or(zero_hash)))
+            .collect();
+        layers.push(next_layer);
+    }
+
+    let mut deposits = Vec::with_capacity(deposit_data.len());
+    for (index, data) in deposit_data.iter().enumerate() {
+        let mut proof = layers
+            .iter()
+            .zip(zero_hashes.iter())
+            .take(DEPOSIT_CONTRACT_TREE_DEPTH)
+            .enumerate()
+            .map(|(depth, (layer, zero_hash))| {
+                let node_index = index >> depth;
+                // Any right sibling only covers later deposits, so it is empty at this point
+                if node_index % 2 == 1 {
+                    layer[node_index - 1]
+                } else {
+                    *zero_hash
+                }
+            })
+            .collect::<Vec<_>>();
+        let mut deposit_count = [0u8; 32];
+        deposit_count[..8].copy_from_slice(&(index as u64 + 1).to_le_bytes());
+        proof.push(Node::try_from(deposit_count.as_ref()).expect("is 32 bytes"));
+
+        let proof = Vector::try_from(proof).map_err(|(_, err)| err)?;
+        deposits.push(Deposit { proof, data: data.clone() });
+    }
+    Ok(deposits)
+}
+
+// Builds a genesis state for tests from deposits of the deterministic keys in `test_keys`.
+pub struct GenesisBuilder<
+    const SLOTS_PER_HISTORICAL_ROOT: usize,
+    const HISTORICAL_ROOTS_LIMIT: usize,
+    const ETH1_DATA_VOTES_BOUND: usize,
+    const VALIDATOR_REGISTRY_LIMIT: usize,
+    const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+    const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+    const MAX_VALIDATORS_PER_COMMITTEE: usize,
+    const PENDING_ATTESTATIONS_BOUND: usize,
+    const SYNC_COMMITTEE_SIZE: usize,
+    const BYTES_PER_LOGS_BLOOM: usize,
+    const MAX_EXTRA_DATA_BYTES: usize,
+    const MAX_BYTES_PER_TRANSACTION: usize,
+    const MAX_TRANSACTIONS_PER_PAYLOAD: usize,
+    const MAX_PROPOSER_SLASHINGS: usize,
+    const MAX_ATTESTER_SLASHINGS: usize,
+    const MAX_ATTESTATIONS: usize,
+    const MAX_DEPOSITS: usize,
+    const MAX_VOLUNTARY_EXITS: usize,
+    const MAX_WITHDRAWALS_PER_PAYLOAD: usize,
+    const MAX_BLS_TO_EXECUTION_CHANGES: usize,
+    const MAX_BLOB_COMMITMENTS_PER_BLOCK: usize,
+> {
+    pub context: Context,
+    pub fork: Fork,
+    pub balances: Vec<Gwei>,
+    pub eth1_block_hash: Hash32,
+    pub eth1_timestamp: u64,
+    _phantom: PhantomData<(
+        BeaconState<
+            SLOTS_PER_HISTORICAL_ROOT,
+            HISTORICAL_ROOTS_LIMIT,
+            ETH1_DATA_VOTES_BOUND,
+            VALIDATOR_REGISTRY_LIMIT,
+            EPOCHS_PER_HISTORICAL_VECTOR,
+            EPOCHS_PER_SLASHINGS_VECTOR,
+            MAX_VALIDATORS_PER_COMMITTEE,
+            PENDING_ATTESTATIONS_BOUND,
+            SYNC_COMMITTEE_SIZE,
+            BYTES_PER_LOGS_BLOOM,
+            MAX_EXTRA_DATA_BYTES,
+        >,
+        SignedBeaconBlock<
+            MAX_PROPOSER_SLASHINGS,
+            MAX_VALIDATORS_PER_COMMITTEE,
+            MAX_ATTESTER_SLASHINGS,
+            MAX_ATTESTATIONS,
+            MAX_DEPOSITS,
+            MAX_VOLUNTARY_EXITS,
+            SYNC_COMMITTEE_SIZE,
+            BYTES_PER_LOGS_BLOOM,
+            MAX_EXTRA_DATA_BYTES,
+            MAX_BYTES_PER_TRANSACTION,
+            MAX_TRANSACTIONS_PER_PAYLOAD,
+            MAX_WITHDRAWALS_PER_PAYLOAD,
+            MAX_BLS_TO_EXECUTION_CHANGES,
+            MAX_BLOB_COMMITMENTS_PER_BLOCK,
+        >,
+    )>,
+}
+
+impl<
+        const SLOTS_PER_HISTORICAL_ROOT: usize,
+        const HISTORICAL_ROOTS_LIMIT: usize,
+        const ETH1_DATA_VOTES_BOUND: usize,
+        const VALIDATOR_REGISTRY_LIMIT: usize,
+        const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+        const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+        const MAX_VALIDATORS_PER_COMMITTEE: usize,
+        const PENDING_ATTESTATIONS_BOUND: usize,
+        const SYNC_COMMITTEE_SIZE: usize,
+        const BYTES_PER_LOGS_BLOOM: usize,
+        const MAX_EXTRA_DATA_BYTES: usize,
+        const MAX_BYTES_PER_TRANSACTION: usize,
+        const MAX_TRANSACTIONS_PER_PAYLOAD: usize,
+        const MAX_PROPOSER_SLASHINGS: usize,
+        const MAX_ATTESTER_SLASHINGS: usize,
+        const MAX_ATTESTATIONS: usize,
+        const MAX_DEPOSITS: usize,
+        const MAX_VOLUNTARY_EXITS: usize,
+        const MAX_WITHDRAWALS_PER_PAYLOAD: usize,
+        const MAX_BLS_TO_EXECUTION_CHANGES: usize,
+        const MAX_BLOB_COMMITMENTS_PER_BLOCK: usize,
+    >
+    GenesisBuilder<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        PENDING_ATTESTATIONS_BOUND,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+        MAX_BYTES_PER_TRANSACTION,
+        MAX_TRANSACTIONS_PER_PAYLOAD,
+        MAX_PROPOSER_SLASHINGS,
+        MAX_ATTESTER_SLASHINGS,
+        MAX_ATTESTATIONS,
+        MAX_DEPOSITS,
+        MAX_VOLUNTARY_EXITS,
+        MAX_WITHDRAWALS_PER_PAYLOAD,
+        MAX_BLS_TO_EXECUTION_CHANGES,
+        MAX_BLOB_COMMITMENTS_PER_BLOCK,
+    >
+{
+    pub fn new(validator_count: usize, context: Context) -> Self {
+        let balances = vec![context.max_effective_balance; validator_count];
+        let eth1_timestamp = context.min_genesis_time;
+        Self {
+            context,
+            fork: Fork::Phase0,
+            balances,
+            eth1_block_hash: Hash32::try_from([0x42u8; 32].as_ref()).expect("is 32 bytes"),
+            eth1_timestamp,
+            _phantom: PhantomData,
+        }
+    }
+
+    pub fn with_fork(mut self, fork: Fork) -> Self {
+        self.fork = fork;
+        self
+    }
+
+    // Set the deposit amount of each validator, one per validator
+    pub fn with_balances(mut self, balances: Vec<Gwei>) -> Self {
+        self.balances = balances;
+        self
+    }
+
+    pub fn with_eth1_timestamp(mut self, eth1_timestamp: u64) -> Self {
+        self.eth1_timestamp = eth1_timestamp;
+        self
+    }
+
+    pub fn deposits(&self) -> Result<Vec<Deposit>> {
+        let domain = compute_domain(DomainType::Deposit, None, None, &self.context)?;
+        let keys = test_keys(self.balances.len());
+        let mut deposit_data = Vec::with_capacity(keys.len());
+        for (secret_key, &amount) in keys.iter().zip(self.balances.iter()) {
+            let public_key = secret_key.public_key();
+            let mut withdrawal_credentials = hash(public_key.as_ref());
+            withdrawal_credentials[0] = BLS_WITHDRAWAL_PREFIX;
+            let message = DepositMessage { public_key, withdrawal_credentials, amount };
+            let signature = sign_with_domain(&message, secret_key, domain)?;
+            deposit_data.push(DepositData {
+                public_key: message.public_key,
+                withdrawal_credentials: message.withdrawal_credentials,
+                amount,
+                signature,
+            });
+        }
+        compute_genesis_deposits(&deposit_data)
+    }
+
+    pub fn build(
+        &self,
+    ) -> Result<
+        BeaconState<
+            SLOTS_PER_HISTORICAL_ROOT,
+            HISTORICAL_ROOTS_LIMIT,
+            ETH1_DATA_VOTES_BOUND,
+            VALIDATOR_REGISTRY_LIMIT,
+            EPOCHS_PER_HISTORICAL_VECTOR,
+            EPOCHS_PER_SLASHINGS_VECTOR,
+            MAX_VALIDATORS_PER_COMMITTEE,
+            PENDING_ATTESTATIONS_BOUND,
+            SYNC_COMMITTEE_SIZE,
+            BYTES_PER_LOGS_BLOOM,
+            MAX_EXTRA_DATA_BYTES,
+        >,
+    > {
+        let deposits = self.deposits()?;
+        let eth1_block_hash = self.eth1_block_hash.clone();
+        let eth1_timestamp = self.eth1_timestamp;
+        let context = &self.context;
+        let state = match self.fork {
+            Fork::Phase0 => {
+                let state = phase0::initialize_beacon_state_from_eth1::<
+                    SLOTS_PER_HISTORICAL_ROOT,
+                    HISTORICAL_ROOTS_LIMIT,
+                    ETH1_DATA_VOTES_BOUND,
+                    VALIDATOR_REGISTRY_LIMIT,
+                    EPOCHS_PER_HISTORICAL_VECTOR,
+                    EPOCHS_PER_SLASHINGS_VECTOR,
+                    MAX_VALIDATORS_PER_COMMITTEE,
+                    PENDING_ATTESTATIONS_BOUND,
+                    MAX_PROPOSER_SLASHINGS,
+                    MAX_ATTESTER_SLASHINGS,
+                    MAX_ATTESTATIONS,
+                    MAX_DEPOSITS,
+                    MAX_VOLUNTARY_EXITS,
+                >(eth1_block_hash, eth1_timestamp, &deposits, context)?;
+                BeaconState::Phase0(state)
+            }
+            Fork::Altair => {
+                let state = altair::initialize_beacon_state_from_eth1::<
+                    SLOTS_PER_HISTORICAL_ROOT,
+                    HISTORICAL_ROOTS_LIMIT,
+                    ETH1_DATA_VOTES_BOUND,
+                    VALIDATOR_REGISTRY_LIMIT,
+                    EPOCHS_PER_HISTORICAL_VECTOR,
+                    EPOCHS_PER_SLASHINGS_VECTOR,
+                    MAX_VALIDATORS_PER_COMMITTEE,
+                    SYNC_COMMITTEE_SIZE,
+                    MAX_PROPOSER_SLASHINGS,
+                    MAX_ATTESTER_SLASHINGS,
+                    MAX_ATTESTATIONS,
+                    MAX_DEPOSITS,
+                    MAX_VOLUNTARY_EXITS,
+                >(eth1_block_hash, eth1_timestamp, &deposits, context)?;
+                BeaconState::Altair(state)
+            }
+            Fork::Bellatrix => {
+                let state = bellatrix::initialize_beacon_state_from_eth1::<
+                    SLOTS_PER_HISTORICAL_ROOT,
+                    HISTORICAL_ROOTS_LIMIT,
+                    ETH1_DATA_VOTES_BOUND,
+                    VALIDATOR_REGISTRY_LIMIT,
+                    EPOCHS_PER_HISTORICAL_VECTOR,
+                    EPOCHS_PER_SLASHINGS_VECTOR,
+                    MAX_VALIDATORS_PER_COMMITTEE,
+                    SYNC_COMMITTEE_SIZE,
+                    MAX_PROPOSER_SLASHINGS,
+                    MAX_ATTESTER_SLASHINGS,
+                    MAX_ATTESTATIONS,
+                    MAX_DEPOSITS,
+                    MAX_VOLUNTARY_EXITS,
+                    BYTES_PER_LOGS_BLOOM,
+                    MAX_EXTRA_DATA_BYTES,
+                    MAX_BYTES_PER_TRANSACTION,
+                    MAX_TRANSACTIONS_PER_PAYLOAD,
+                >(
+                    eth1_block_hash, eth1_timestamp, &deposits, None, context
+                )?;
+                BeaconState::Bellatrix(state)
+            }
+            Fork::Capella => {
+                let state = capella::initialize_beacon_state_from_eth1::<
+                    SLOTS_PER_HISTORICAL_ROOT,
+                    HISTORICAL_ROOTS_LIMIT,
+                    ETH1_DATA_VOTES_BOUND,
+                    VALIDATOR_REGISTRY_LIMIT,
+                    EPOCHS_PER_HISTORICAL_VECTOR,
+                    EPOCHS_PER_SLASHINGS_VECTOR,
+                    MAX_VALIDATORS_PER_COMMITTEE,
+                    SYNC_COMMITTEE_SIZE,
+                    MAX_PROPOSER_SLASHINGS,
+                    MAX_ATTESTER_SLASHINGS,
+                    MAX_ATTESTATIONS,
+                    MAX_DEPOSITS,
+                    MAX_VOLUNTARY_EXITS,
+                    BYTES_PER_LOGS_BLOOM,
+                    MAX_EXTRA_DATA_BYTES,
+                    MAX_BYTES_PER_TRANSACTION,
+                    MAX_TRANSACTIONS_PER_PAYLOAD,
+                    MAX_WITHDRAWALS_PER_PAYLOAD,
+                    MAX_BLS_TO_EXECUTION_CHANGES,
+                >(
+                    eth1_block_hash, eth1_timestamp, &deposits, None, context
+                )?;
+                BeaconState::Capella(state)
+            }
+            Fork::Deneb => {
+                let state = deneb::initialize_beacon_state_from_eth1::<
+                    SLOTS_PER_HISTORICAL_ROOT,
+                    HISTORICAL_ROOTS_LIMIT,
+                    ETH1_DATA_VOTES_BOUND,
+                    VALIDATOR_REGISTRY_LIMIT,
+                    EPOCHS_PER_HISTORICAL_VECTOR,
+                    EPOCHS_PER_SLASHINGS_VECTOR,
+                    MAX_VALIDATORS_PER_COMMITTEE,
+                    SYNC_COMMITTEE_SIZE,
+                    MAX_PROPOSER_SLASHINGS,
+                    MAX_ATTESTER_SLASHINGS,
+                    MAX_ATTESTATIONS,
+                    MAX_DEPOSITS,
+                    MAX_VOLUNTARY_EXITS,
+                    BYTES_PER_LOGS_BLOOM,
+                    MAX_EXTRA_DATA_BYTES,
+                    MAX_BYTES_PER_TRANSACTION,
+                    MAX_TRANSACTIONS_PER_PAYLOAD,
+                    MAX_WITHDRAWALS_PER_PAYLOAD,
+                    MAX_BLS_TO_EXECUTION_CHANGES,
+                    MAX_BLOB_COMMITMENTS_PER_BLOCK,
+                >(
+                    eth1_block_hash, eth1_timestamp, &deposits, None, context
+                )?;
+                BeaconState::Deneb(state)
+            }
+            fork => return Err(Error::UnsupportedFork(fork)),
+        };
+        Ok(state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state_transition::minimal::GenesisBuilder;
+
+    #[test]
+    fn test_genesis_builder() {
+        let context = Context::for_minimal();
+        let validator_count = context.min_genesis_active_validator_count;
+
+        let builder = GenesisBuilder::new(validator_count, context);
+        let state = builder.build().unwrap();
+        let BeaconState::Phase0(state) = state else { panic!("expected a phase0 state") };
+        assert_eq!(state.validators.len(), validator_count);
+        assert!(phase0::is_valid_genesis_state(&state, &builder.context));
+
+        let builder = builder.with_fork(Fork::Altair);
+        let state = builder.build().unwrap();
+        let BeaconState::Altair(state) = state else { panic!("expected an altair state") };
+        assert!(altair::is_valid_genesis_state(&state, &builder.context));
+
+        // a validator below the max effective balance is not active at genesis
+        let mut balances = vec![builder.context.max_effective_balance; validator_count];
+        balances[0] = builder.context.max_effective_balance / 2;
+        let builder = builder.with_balances(balances).with_fork(Fork::Phase0);
+        let BeaconState::Phase0(state) = builder.build().unwrap() else { unreachable!() };
+        assert_eq!(state.validators.len(), validator_count);
+        assert!(!phase0::is_valid_genesis_state(&state, &builder.context));
+    }
+}
@@ -1,9 +1,13 @@
 mod context;
 mod executor;
+#[cfg(feature = "test-utils")]
+mod genesis;
 mod presets;
 
 pub use context::*;
 pub use executor::*;
+#[cfg(feature = "test-utils")]
+pub use genesis::*;
 
 pub type Result<T> = std::result::Result<T, crate::Error>;
 
@@ -41,3 +41,28 @@ pub type Executor = state_transition::Executor<
     MAX_BLS_TO_EXECUTION_CHANGES,
     MAX_BLOB_COMMITMENTS_PER_BLOCK,
 >;
+
+#[cfg(feature = "test-utils")]
+pub type GenesisBuilder = state_transition::GenesisBuilder<
+    SLOTS_PER_HISTORICAL_ROOT,
+    HISTORICAL_ROOTS_LIMIT,
+    ETH1_DATA_VOTES_BOUND,
+    VALIDATOR_REGISTRY_LIMIT,
+    EPOCHS_PER_HISTORICAL_VECTOR,
+    EPOCHS_PER_SLASHINGS_VECTOR,
+    MAX_VALIDATORS_PER_COMMITTEE,
+    PENDING_ATTESTATIONS_BOUND,
+    SYNC_COMMITTEE_SIZE,
+    BYTES_PER_LOGS_BLOOM,
+    MAX_EXTRA_DATA_BYTES,
+    MAX_BYTES_PER_TRANSACTION,
+    MAX_TRANSACTIONS_PER_PAYLOAD,
+    MAX_PROPOSER_SLASHINGS,
+    MAX_ATTESTER_SLASHINGS,
+    MAX_ATTESTATIONS,
+    MAX_DEPOSITS,
+    MAX_VOLUNTARY_EXITS,
+    MAX_WITHDRAWALS_PER_PAYLOAD,
+    MAX_BLS_TO_EXECUTION_CHANGES,
+    MAX_BLOB_COMMITMENTS_PER_BLOCK,
+>;
@@ -0,0 +1,25 @@
+use crate::{
+    phase0::Validator,
+    primitives::{Gwei, FAR_FUTURE_EPOCH},
+    ssz::prelude::*,
+    state_transition::Context,
+};
+
+// Append `count` validators with the maximum effective balance to a state's registry, each
+// active from genesis and never exiting.
+pub(crate) fn push_active_validators<const N: usize>(
+    validators: &mut List<Validator, N>,
+    balances: &mut List<Gwei, N>,
+    count: usize,
+    context: &Context,
+) {
+    for _ in 0..count {
+        validators.push(Validator {
+            effective_balance: context.max_effective_balance,
+            exit_epoch: FAR_FUTURE_EPOCH,
+            withdrawable_epoch: FAR_FUTURE_EPOCH,
+            ..Default::default()
+        });
+        balances.push(context.max_effective_balance);
+    }
+}
@@ -0,0 +1,110 @@
+use crate::{
+    altair::beacon_state as altair,
+    bellatrix::beacon_state as bellatrix,
+    capella::beacon_state as capella,
+    deneb::beacon_state as deneb,
+    phase0::beacon_state as phase0,
+    primitives::{GENESIS_EPOCH, GENESIS_SLOT},
+    state_transition::Context,
+    types::BeaconState,
+    Error, Fork,
+};
+
+impl<
+        const SLOTS_PER_HISTORICAL_ROOT: usize,
+        const HISTORICAL_ROOTS_LIMIT: usize,
+        const ETH1_DATA_VOTES_BOUND: usize,
+        const VALIDATOR_REGISTRY_LIMIT: usize,
+        const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+        const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+        const MAX_VALIDATORS_PER_COMMITTEE: usize,
+        const PENDING_ATTESTATIONS_BOUND: usize,
+        const SYNC_COMMITTEE_SIZE: usize,
+        const BYTES_PER_LOGS_BLOOM: usize,
+        const MAX_EXTRA_DATA_BYTES: usize,
+    >
+    BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        PENDING_ATTESTATIONS_BOUND,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+    >
+{
+    /// Construct a state at genesis for `fork` with no validators, useful as a scaffold in tests.
+    /// The fork data and genesis time are taken from `context`; every other field is empty.
+    pub fn empty(context: &Context, fork: Fork) -> Result<Self, Error> {
+        let version = context.fork_version_for(fork);
+        let fork_data = phase0::Fork {
+            previous_version: version,
+            current_version: version,
+            epoch: GENESIS_EPOCH,
+        };
+        let genesis_time = context.min_genesis_time;
+        let slot = GENESIS_SLOT;
+        let state = match fork {
+            Fork::Phase0 => Self::Phase0(phase0::BeaconState {
+                genesis_time,
+                slot,
+                fork: fork_data,
+                ..Default::default()
+            }),
+            Fork::Altair => Self::Altair(altair::BeaconState {
+                genesis_time,
+                slot,
+                fork: fork_data,
+                ..Default::default()
+            }),
+            Fork::Bellatrix => Self::Bellatrix(bellatrix::BeaconState {
+                genesis_time,
+                slot,
+                fork: fork_data,
+                ..Default::default()
+            }),
+            Fork::Capella => Self::Capella(capella::BeaconState {
+                genesis_time,
+                slot,
+                fork: fork_data,
+                ..Default::default()
+            }),
+            Fork::Deneb => Self::Deneb(deneb::BeaconState {
+                genesis_time,
+                slot,
+                fork: fork_data,
+                ..Default::default()
+            }),
+            Fork::Electra => return Err(Error::UnsupportedFork(fork)),
+        };
+        Ok(state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ssz::prelude::*, types::minimal};
+
+    #[test]
+    fn test_empty_state() {
+        let context = Context::for_minimal();
+        for fork in [Fork::Phase0, Fork::Altair, Fork::Bellatrix, Fork::Capella, Fork::Deneb] {
+            let state = minimal::BeaconState::empty(&context, fork).unwrap();
+            assert_eq!(state.slot(), GENESIS_SLOT);
+            assert_eq!(state.version().to_string(), fork.to_string());
+            assert_eq!(state.fork().current_version, context.fork_version_for(fork));
+            assert_eq!(state.genesis_time(), context.min_genesis_time);
+            assert!(state.validators().is_empty());
+            assert!(state.hash_tree_root().is_ok());
+        }
+        assert!(matches!(
+            minimal::BeaconState::empty(&context, Fork::Electra),
+            Err(Error::UnsupportedFork(Fork::Electra))
+        ));
+    }
+}
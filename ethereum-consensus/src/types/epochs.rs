@@ -0,0 +1,76 @@
+use crate::{
+    phase0::compute_epoch_at_slot,
+    primitives::{Epoch, GENESIS_EPOCH},
+    state_transition::Context,
+    types::BeaconState,
+};
+
+impl<
+        const SLOTS_PER_HISTORICAL_ROOT: usize,
+        const HISTORICAL_ROOTS_LIMIT: usize,
+        const ETH1_DATA_VOTES_BOUND: usize,
+        const VALIDATOR_REGISTRY_LIMIT: usize,
+        const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+        const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+        const MAX_VALIDATORS_PER_COMMITTEE: usize,
+        const PENDING_ATTESTATIONS_BOUND: usize,
+        const SYNC_COMMITTEE_SIZE: usize,
+        const BYTES_PER_LOGS_BLOOM: usize,
+        const MAX_EXTRA_DATA_BYTES: usize,
+    >
+    BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        PENDING_ATTESTATIONS_BOUND,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+    >
+{
+    /// The epoch of the state's current slot, as in `get_current_epoch`.
+    pub fn current_epoch(&self, context: &Context) -> Epoch {
+        compute_epoch_at_slot(self.slot(), context)
+    }
+
+    /// The epoch before `current_epoch`, as in `get_previous_epoch`.
+    /// At genesis there is no earlier epoch, so this is `GENESIS_EPOCH`.
+    pub fn previous_epoch(&self, context: &Context) -> Epoch {
+        let current_epoch = self.current_epoch(context);
+        if current_epoch == GENESIS_EPOCH {
+            GENESIS_EPOCH
+        } else {
+            current_epoch - 1
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        phase0::{get_current_epoch, get_previous_epoch},
+        primitives::GENESIS_EPOCH,
+        state_transition::Context,
+        types::minimal,
+        Fork,
+    };
+
+    #[test]
+    fn test_state_epochs() {
+        let context = Context::for_minimal();
+        let mut state = minimal::BeaconState::empty(&context, Fork::Phase0).unwrap();
+        assert_eq!(state.current_epoch(&context), GENESIS_EPOCH);
+        assert_eq!(state.previous_epoch(&context), GENESIS_EPOCH);
+
+        *state.slot_mut() = 3 * context.slots_per_epoch + 1;
+        assert_eq!(state.current_epoch(&context), 3);
+        assert_eq!(state.previous_epoch(&context), 2);
+        let inner = state.phase0().unwrap();
+        assert_eq!(get_current_epoch(inner, &context), 3);
+        assert_eq!(get_previous_epoch(inner, &context), 2);
+    }
+}
@@ -0,0 +1,131 @@
+use crate::{
+    primitives::Slot, ssz::prelude::*, state_transition::Context, types::BeaconState, Error, Fork,
+};
+use std::{io::Read, path::Path};
+
+/// The stream identifier chunk which opens every payload in the snappy framing format.
+pub const SNAPPY_STREAM_IDENTIFIER: [u8; 10] = *b"\xff\x06\x00\x00sNaPpY";
+
+/// Returns `true` if `bytes` start with the snappy framing format's stream identifier.
+pub fn is_snappy_framed(bytes: &[u8]) -> bool {
+    bytes.starts_with(&SNAPPY_STREAM_IDENTIFIER)
+}
+
+impl<
+        const SLOTS_PER_HISTORICAL_ROOT: usize,
+        const HISTORICAL_ROOTS_LIMIT: usize,
+        const ETH1_DATA_VOTES_BOUND: usize,
+        const VALIDATOR_REGISTRY_LIMIT: usize,
+        const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+        const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+        const MAX_VALIDATORS_PER_COMMITTEE: usize,
+        const PENDING_ATTESTATIONS_BOUND: usize,
+        const SYNC_COMMITTEE_SIZE: usize,
+        const BYTES_PER_LOGS_BLOOM: usize,
+        const MAX_EXTRA_DATA_BYTES: usize,
+    >
+    BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        PENDING_ATTESTATIONS_BOUND,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+    >
+{
+    /// Decode a state dump as written by other clients (e.g. Lighthouse or Prysm), either as
+    /// plain SSZ or as SSZ wrapped in the snappy framing format. The fork is the one `context`
+    /// schedules at `slot`, which must also match the slot of the decoded state.
+    pub fn from_ssz_dump(bytes: &[u8], slot: Slot, context: &Context) -> Result<Self, Error> {
+        let mut decompressed = vec![];
+        let encoding = if is_snappy_framed(bytes) {
+            snap::read::FrameDecoder::new(bytes).read_to_end(&mut decompressed)?;
+            decompressed.as_slice()
+        } else {
+            bytes
+        };
+
+        let state = match context.fork_for(slot) {
+            Fork::Phase0 => {
+                Self::Phase0(deserialize(encoding).map_err(SimpleSerializeError::from)?)
+            }
+            Fork::Altair => {
+                Self::Altair(deserialize(encoding).map_err(SimpleSerializeError::from)?)
+            }
+            Fork::Bellatrix => {
+                Self::Bellatrix(deserialize(encoding).map_err(SimpleSerializeError::from)?)
+            }
+            Fork::Capella => {
+                Self::Capella(deserialize(encoding).map_err(SimpleSerializeError::from)?)
+            }
+            Fork::Deneb => Self::Deneb(deserialize(encoding).map_err(SimpleSerializeError::from)?),
+            Fork::Electra => return Err(Error::UnsupportedFork(Fork::Electra)),
+        };
+
+        if state.slot() != slot {
+            return Err(Error::InvalidStateDump(format!(
+                "expected a state at slot {slot} but found slot {}",
+                state.slot()
+            )))
+        }
+        Ok(state)
+    }
+
+    /// Read a `.ssz` or `.ssz_snappy` state dump from `path`; see `from_ssz_dump`.
+    pub fn read_ssz_dump<P: AsRef<Path>>(
+        path: P,
+        slot: Slot,
+        context: &Context,
+    ) -> Result<Self, Error> {
+        let bytes = std::fs::read(path)?;
+        Self::from_ssz_dump(&bytes, slot, context)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{altair, types::minimal};
+    use std::io::Write;
+
+    #[test]
+    fn test_read_raw_and_snappy_framed_dumps() {
+        let mut context = Context::for_minimal();
+        context.altair_fork_epoch = 2;
+        let slot = 2 * context.slots_per_epoch + 3;
+        let inner =
+            altair::minimal::BeaconState { genesis_time: 1606824023, slot, ..Default::default() };
+        let encoding = serialize(&inner).unwrap();
+
+        let mut encoder = snap::write::FrameEncoder::new(vec![]);
+        encoder.write_all(&encoding).unwrap();
+        let framed = encoder.into_inner().unwrap();
+        assert!(is_snappy_framed(&framed));
+        assert!(!is_snappy_framed(&encoding));
+
+        let dir = std::env::temp_dir();
+        let raw_path = dir.join(format!("interop-{}-state.ssz", std::process::id()));
+        let framed_path = dir.join(format!("interop-{}-state.ssz_snappy", std::process::id()));
+        std::fs::write(&raw_path, &encoding).unwrap();
+        std::fs::write(&framed_path, &framed).unwrap();
+
+        let raw = minimal::BeaconState::read_ssz_dump(&raw_path, slot, &context);
+        let snappy = minimal::BeaconState::read_ssz_dump(&framed_path, slot, &context);
+        std::fs::remove_file(raw_path).unwrap();
+        std::fs::remove_file(framed_path).unwrap();
+
+        let raw = raw.unwrap();
+        assert_eq!(raw, snappy.unwrap());
+        assert_eq!(raw, minimal::BeaconState::Altair(inner));
+
+        assert!(matches!(
+            minimal::BeaconState::from_ssz_dump(&encoding, slot + 1, &context),
+            Err(Error::InvalidStateDump(..))
+        ));
+    }
+}
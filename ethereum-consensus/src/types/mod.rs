@@ -9,11 +9,17 @@ mod beacon_block_body;
 mod beacon_state;
 mod blinded_beacon_block;
 mod blinded_beacon_block_body;
+mod empty_state;
+mod epochs;
 mod execution_payload;
 mod execution_payload_header;
+#[cfg(feature = "interop")]
+mod interop;
 mod presets;
 mod signed_beacon_block;
 mod signed_blinded_beacon_block;
+mod snapshot;
+mod upgrade;
 
 pub use beacon_block::*;
 pub use beacon_block_body::*;
@@ -22,7 +28,10 @@ pub use blinded_beacon_block::*;
 pub use blinded_beacon_block_body::*;
 pub use execution_payload::*;
 pub use execution_payload_header::*;
+#[cfg(feature = "interop")]
+pub use interop::{is_snappy_framed, SNAPPY_STREAM_IDENTIFIER};
 pub use signed_beacon_block::*;
 pub use signed_blinded_beacon_block::*;
+pub use snapshot::{SNAPSHOT_FORMAT_VERSION, SNAPSHOT_MAGIC};
 
 pub use presets::{mainnet, minimal};
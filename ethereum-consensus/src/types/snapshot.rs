@@ -0,0 +1,180 @@
+use crate::{
+    primitives::{Root, Slot},
+    ssz::prelude::*,
+    types::BeaconState,
+    Error, Fork,
+};
+use std::io::{Read, Write};
+
+pub const SNAPSHOT_MAGIC: [u8; 4] = *b"ECSS";
+pub const SNAPSHOT_FORMAT_VERSION: u8 = 1;
+
+// magic, format version, fork, slot, state root, length of the SSZ payload
+const HEADER_LEN: usize = 4 + 1 + 1 + 8 + 32 + 8;
+
+fn fork_to_byte(fork: Fork) -> u8 {
+    match fork {
+        Fork::Phase0 => 0,
+        Fork::Altair => 1,
+        Fork::Bellatrix => 2,
+        Fork::Capella => 3,
+        Fork::Deneb => 4,
+        Fork::Electra => 5,
+    }
+}
+
+fn invalid_snapshot(reason: impl Into<String>) -> Error {
+    Error::InvalidSnapshot(reason.into())
+}
+
+impl<
+        const SLOTS_PER_HISTORICAL_ROOT: usize,
+        const HISTORICAL_ROOTS_LIMIT: usize,
+        const ETH1_DATA_VOTES_BOUND: usize,
+        const VALIDATOR_REGISTRY_LIMIT: usize,
+        const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+        const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+        const MAX_VALIDATORS_PER_COMMITTEE: usize,
+        const PENDING_ATTESTATIONS_BOUND: usize,
+        const SYNC_COMMITTEE_SIZE: usize,
+        const BYTES_PER_LOGS_BLOOM: usize,
+        const MAX_EXTRA_DATA_BYTES: usize,
+    >
+    BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        PENDING_ATTESTATIONS_BOUND,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+    >
+{
+    /// Write the state to `writer` as SSZ prefixed with a header carrying the fork, slot and
+    /// state root so that `read_snapshot` can verify the integrity of the data.
+    /// Returns the number of bytes written.
+    pub fn write_snapshot<W: Write>(&self, mut writer: W) -> Result<usize, Error> {
+        let state_root = self.hash_tree_root()?;
+        let encoding = serialize(self).map_err(SimpleSerializeError::from)?;
+
+        let mut header = Vec::with_capacity(HEADER_LEN);
+        header.extend_from_slice(&SNAPSHOT_MAGIC);
+        header.push(SNAPSHOT_FORMAT_VERSION);
+        header.push(fork_to_byte(self.version()));
+        header.extend_from_slice(&self.slot().to_le_bytes());
+        header.extend_from_slice(state_root.as_ref());
+        header.extend_from_slice(&(encoding.len() as u64).to_le_bytes());
+
+        writer.write_all(&header)?;
+        writer.write_all(&encoding)?;
+        writer.flush()?;
+        Ok(header.len() + encoding.len())
+    }
+
+    /// Read a state written by `write_snapshot`, rejecting it if the recomputed state root or
+    /// slot do not match those recorded in the header.
+    pub fn read_snapshot<R: Read>(mut reader: R) -> Result<Self, Error> {
+        let mut header = [0u8; HEADER_LEN];
+        reader.read_exact(&mut header)?;
+        if header[..4] != SNAPSHOT_MAGIC {
+            return Err(invalid_snapshot("missing snapshot magic bytes"))
+        }
+        let format_version = header[4];
+        if format_version != SNAPSHOT_FORMAT_VERSION {
+            return Err(invalid_snapshot(format!("unsupported format version {format_version}")))
+        }
+        let fork = header[5];
+        let slot = Slot::from_le_bytes(header[6..14].try_into().expect("correct length"));
+        let expected_root = Root::try_from(&header[14..46]).expect("correct length");
+        let len = u64::from_le_bytes(header[46..54].try_into().expect("correct length"));
+
+        let mut encoding = vec![];
+        reader.take(len).read_to_end(&mut encoding)?;
+        if encoding.len() as u64 != len {
+            return Err(invalid_snapshot(format!(
+                "expected {len} bytes of state but only found {}",
+                encoding.len()
+            )))
+        }
+
+        let state = match fork {
+            0 => Self::Phase0(deserialize(&encoding).map_err(SimpleSerializeError::from)?),
+            1 => Self::Altair(deserialize(&encoding).map_err(SimpleSerializeError::from)?),
+            2 => Self::Bellatrix(deserialize(&encoding).map_err(SimpleSerializeError::from)?),
+            3 => Self::Capella(deserialize(&encoding).map_err(SimpleSerializeError::from)?),
+            4 => Self::Deneb(deserialize(&encoding).map_err(SimpleSerializeError::from)?),
+            5 => return Err(Error::UnsupportedFork(Fork::Electra)),
+            other => return Err(invalid_snapshot(format!("unknown fork {other}"))),
+        };
+
+        let computed_root = state.hash_tree_root()?;
+        if computed_root != expected_root {
+            return Err(Error::SnapshotRootMismatch {
+                expected: expected_root,
+                computed: computed_root,
+            })
+        }
+        if state.slot() != slot {
+            return Err(invalid_snapshot(format!(
+                "header slot {slot} does not match state slot {}",
+                state.slot()
+            )))
+        }
+        Ok(state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{phase0, types::minimal};
+
+    fn snapshot() -> (minimal::BeaconState, Vec<u8>) {
+        let mut inner = phase0::minimal::BeaconState { slot: 42, ..Default::default() };
+        inner.genesis_time = 1606824023;
+        let state = minimal::BeaconState::Phase0(inner);
+        let mut buffer = vec![];
+        let written = state.write_snapshot(&mut buffer).unwrap();
+        assert_eq!(written, buffer.len());
+        (state, buffer)
+    }
+
+    #[test]
+    fn test_snapshot_round_trip() {
+        let (state, buffer) = snapshot();
+        let recovered = minimal::BeaconState::read_snapshot(buffer.as_slice()).unwrap();
+        assert_eq!(recovered, state);
+        assert!(matches!(recovered.version(), Fork::Phase0));
+        assert_eq!(recovered.slot(), 42);
+    }
+
+    #[test]
+    fn test_snapshot_rejects_corruption() {
+        let (_, buffer) = snapshot();
+
+        // `genesis_time` is the first field of the SSZ payload
+        let mut corrupted = buffer.clone();
+        corrupted[HEADER_LEN] ^= 0xff;
+        assert!(matches!(
+            minimal::BeaconState::read_snapshot(corrupted.as_slice()),
+            Err(Error::SnapshotRootMismatch { .. })
+        ));
+
+        let mut corrupted = buffer.clone();
+        corrupted[0] = 0;
+        assert!(matches!(
+            minimal::BeaconState::read_snapshot(corrupted.as_slice()),
+            Err(Error::InvalidSnapshot(..))
+        ));
+
+        let truncated = &buffer[..buffer.len() - 1];
+        assert!(matches!(
+            minimal::BeaconState::read_snapshot(truncated),
+            Err(Error::InvalidSnapshot(..))
+        ));
+    }
+}
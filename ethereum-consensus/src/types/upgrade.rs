@@ -0,0 +1,102 @@
+use crate::{
+    altair::fork as altair, bellatrix::fork as bellatrix, capella::fork as capella,
+    deneb::fork as deneb, state_transition::Context, types::BeaconState, Error, Fork,
+};
+
+// Position of `fork` in the sequence of forks a `BeaconState` can represent.
+fn fork_ordinal(fork: Fork) -> Result<usize, Error> {
+    let ordinal = match fork {
+        Fork::Phase0 => 0,
+        Fork::Altair => 1,
+        Fork::Bellatrix => 2,
+        Fork::Capella => 3,
+        Fork::Deneb => 4,
+        Fork::Electra => return Err(Error::UnsupportedFork(fork)),
+    };
+    Ok(ordinal)
+}
+
+impl<
+        const SLOTS_PER_HISTORICAL_ROOT: usize,
+        const HISTORICAL_ROOTS_LIMIT: usize,
+        const ETH1_DATA_VOTES_BOUND: usize,
+        const VALIDATOR_REGISTRY_LIMIT: usize,
+        const EPOCHS_PER_HISTORICAL_VECTOR: usize,
+        const EPOCHS_PER_SLASHINGS_VECTOR: usize,
+        const MAX_VALIDATORS_PER_COMMITTEE: usize,
+        const PENDING_ATTESTATIONS_BOUND: usize,
+        const SYNC_COMMITTEE_SIZE: usize,
+        const BYTES_PER_LOGS_BLOOM: usize,
+        const MAX_EXTRA_DATA_BYTES: usize,
+    >
+    BeaconState<
+        SLOTS_PER_HISTORICAL_ROOT,
+        HISTORICAL_ROOTS_LIMIT,
+        ETH1_DATA_VOTES_BOUND,
+        VALIDATOR_REGISTRY_LIMIT,
+        EPOCHS_PER_HISTORICAL_VECTOR,
+        EPOCHS_PER_SLASHINGS_VECTOR,
+        MAX_VALIDATORS_PER_COMMITTEE,
+        PENDING_ATTESTATIONS_BOUND,
+        SYNC_COMMITTEE_SIZE,
+        BYTES_PER_LOGS_BLOOM,
+        MAX_EXTRA_DATA_BYTES,
+    >
+{
+    /// Bring the state to `fork` by applying each intermediate fork's upgrade function in turn.
+    /// No slots are processed, and a state already at `fork` is returned unchanged.
+    pub fn upgrade_to(self, fork: Fork, context: &Context) -> Result<Self, Error> {
+        let target = fork_ordinal(fork)?;
+        let mut state = self;
+        loop {
+            let version = state.version();
+            let current = fork_ordinal(version)?;
+            if current == target {
+                return Ok(state)
+            }
+            if current > target {
+                return Err(Error::InvalidForkTransition {
+                    source_fork: version,
+                    destination_fork: fork,
+                })
+            }
+            state = match state {
+                Self::Phase0(state) => Self::Altair(altair::upgrade_to_altair(&state, context)?),
+                Self::Altair(state) => {
+                    Self::Bellatrix(bellatrix::upgrade_to_bellatrix(&state, context))
+                }
+                Self::Bellatrix(state) => {
+                    Self::Capella(capella::upgrade_to_capella(&state, context))
+                }
+                Self::Capella(state) => Self::Deneb(deneb::upgrade_to_deneb(&state, context)),
+                Self::Deneb(_) => return Err(Error::UnsupportedFork(fork)),
+            };
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ssz::prelude::*, types::minimal};
+
+    #[test]
+    fn test_upgrade_to() {
+        let context = Context::for_minimal();
+        let state = minimal::BeaconState::empty(&context, Fork::Altair).unwrap();
+
+        let state = state.upgrade_to(Fork::Deneb, &context).unwrap();
+        assert_eq!(state.version().to_string(), Fork::Deneb.to_string());
+        assert_eq!(state.fork().previous_version, context.capella_fork_version);
+        assert_eq!(state.fork().current_version, context.deneb_fork_version);
+        assert!(state.hash_tree_root().is_ok());
+
+        let upgraded = state.clone().upgrade_to(Fork::Deneb, &context).unwrap();
+        assert_eq!(upgraded, state);
+
+        assert!(matches!(
+            state.upgrade_to(Fork::Capella, &context),
+            Err(Error::InvalidForkTransition { .. })
+        ));
+    }
+}
@@ -104,6 +104,7 @@ impl Fork {
                 "genesis",
                 "helpers",
                 "operations",
+                "validator",
             ],
         }
     }
@@ -137,7 +138,7 @@ impl Fork {
             Fork::Phase0 => vec![],
             Fork::Altair => {
                 let fragment: syn::File = parse_quote! {
-                    use std::collections::HashSet;
+                    use std::collections::{HashSet, HashMap};
                     use crate::ssz::prelude::*;
                     use crate::crypto::{hash, fast_aggregate_verify};
 
@@ -18,6 +18,8 @@ mod test_meta;
 mod test_utils;
 
 const SPEC_TESTS_ROOT_PATH: &str = "consensus-spec-tests/tests";
+// Overrides `SPEC_TESTS_ROOT_PATH`, e.g. to run against a reduced set of vectors.
+const SPEC_TESTS_ROOT_ENV: &str = "SPEC_TESTS_ROOT";
 
 fn str_from_path_component<'a>(component: &'a Component) -> &'a str {
     component.as_os_str().to_str().unwrap()
@@ -122,6 +124,8 @@ fn main() -> Result<(), Box<dyn Error>> {
         mainnet: state_transition::Context::for_mainnet(),
         minimal: state_transition::Context::for_minimal(),
     });
-    let tests = collect_tests(SPEC_TESTS_ROOT_PATH, context)?;
+    let spec_tests_root =
+        env::var(SPEC_TESTS_ROOT_ENV).unwrap_or_else(|_| SPEC_TESTS_ROOT_PATH.to_string());
+    let tests = collect_tests(spec_tests_root, context)?;
     libtest_mimic::run(&args, tests).exit()
 }